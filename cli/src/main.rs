@@ -1,5 +1,8 @@
 use std::{sync::mpsc::{Receiver, self, Sender}, thread};
+use std::path::PathBuf;
 
+use assetchunk::{AssetKind, AssetServer, AssetSource, AssetType, PackConfig, PROTOCOL_VERSION};
+use clap::{Parser, Subcommand};
 use crossterm::event;
 use term::{carat_blinker, InteractiveTermRecievers};
 
@@ -9,8 +12,130 @@ mod pack;
 mod term;
 
 
+/**
+ * Running Without A Subcommand Opens The Interactive Terminal
+ */
+#[derive(Parser)]
+#[command(name = "assetchunk", about = "Pack game assets into a chunk + manifest pair")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Pack every file in a directory into <OUTPUT>.chunk.asset and <OUTPUT>.manifest.asset
+    Pack {
+        input_dir: PathBuf,
+        output: PathBuf,
+        /// Pack config (TOML) with type overrides, custom kinds and importer settings
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Override the detected type of an asset, e.g. --type event_script=Script:LUA
+        #[arg(long = "type", value_name = "NAME=KIND:FORMAT", value_parser = parse_type_override)]
+        type_overrides: Vec<(String, AssetType)>,
+        /// Register a custom asset kind by its extensions, e.g. --kind Dialogue=dlg,dialogue
+        #[arg(long = "kind", value_name = "NAME=EXT,EXT", value_parser = parse_custom_kind)]
+        custom_kinds: Vec<(String, Vec<String>)>,
+    },
+    /// List the assets described by a manifest
+    List {
+        manifest: PathBuf,
+    },
+    /// Serve assets to running games over TCP, from a source directory or a packed <CHUNK>.chunk.asset + manifest
+    Serve {
+        source: PathBuf,
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:7878")]
+        address: String,
+        /// Pack config (TOML) used to import source files
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Generate named asset ID constants (e.g. ASSET_TILESET_TERRAIN1) from a manifest
+    Codegen {
+        manifest: PathBuf,
+        /// Write a Rust module of `AssetId` constants
+        #[arg(long)]
+        rust: Option<PathBuf>,
+        /// Write a C header of `uint32_t` constants
+        #[arg(long)]
+        c: Option<PathBuf>,
+    },
+}
+
+
+fn parse_type_override(s:&str) -> Result<(String, AssetType), String> {
+    let (name, asset_type) = s.split_once('=').ok_or(format!("Expected NAME=KIND:FORMAT, Got {:?}", s))?;
+    Ok((name.to_string(), asset_type.parse()?))
+}
+
+
+fn parse_custom_kind(s:&str) -> Result<(String, Vec<String>), String> {
+    let (name, extensions) = s.split_once('=').ok_or(format!("Expected NAME=EXT,EXT, Got {:?}", s))?;
+    Ok((name.to_string(), extensions.split(',').map(|ext| ext.trim().to_string()).collect()))
+}
+
+
 fn main() -> std::io::Result<()> {
 
+    match Cli::parse().command {
+        Some(Command::Pack { input_dir, output, config, type_overrides, custom_kinds }) => {
+            let config = match config {
+                Some(path) => PackConfig::load(path.to_str().unwrap()),
+                None => Ok(PackConfig::default()),
+            };
+            let mut options = match config.and_then(pack::PackOptions::from_config) {
+                Ok(options) => options,
+                Err(e) => {
+                    eprintln!("[-] Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            // Command Line Flags Win Over The Config File
+            options.type_overrides.extend(type_overrides);
+            for (name, extensions) in custom_kinds {
+                let extensions:Vec<&str> = extensions.iter().map(String::as_str).collect();
+                options.registry.register(AssetKind::new(&name).with_extensions(&extensions));
+            }
+            if let Err(e) = pack::pack(Some(input_dir), Some(output), &options) {
+                eprintln!("[-] Error: {}", e);
+                std::process::exit(1);
+            }
+            return Ok(());
+        },
+        Some(Command::List { manifest }) => {
+            pack::print_manifest_contents(manifest);
+            return Ok(());
+        },
+        Some(Command::Serve { source, address, config }) => {
+            let config = match config {
+                Some(path) => PackConfig::load(path.to_str().unwrap()),
+                None => Ok(PackConfig::default()),
+            };
+            let server = config.and_then(|config| match source.is_dir() {
+                true => AssetSource::directory(&source, config),
+                false => {
+                    let source = source.to_str().unwrap();
+                    AssetSource::chunk(&format!("{}.chunk.asset", source), &format!("{}.manifest.asset", source))
+                },
+            }).and_then(|source| AssetServer::bind(&address, source));
+            match server {
+                Ok(server) => {
+                    println!("[+] Serving {:?} On {} (Protocol Version {})", source, address, PROTOCOL_VERSION);
+                    server.run();
+                },
+                Err(e) => eprintln!("[-] Error: {}", e),
+            }
+            return Ok(());
+        },
+        Some(Command::Codegen { manifest, rust, c }) => {
+            pack::write_asset_ids(manifest, rust, c);
+            return Ok(());
+        },
+        None => {},
+    }
+
     let mut stdout = std::io::stdout();
 
     // Carat Blinker Thread
@@ -2,33 +2,129 @@
 use assetchunk::Asset;
 use assetchunk::AssetType;
 use assetchunk::AssetManifest;
+use assetchunk::AssetRegistry;
+use assetchunk::build_atlases;
+use assetchunk::check_audio_rules;
+use assetchunk::detect_animations;
+use assetchunk::remap_animations_to_atlas;
+use assetchunk::remap_dependencies_to_atlas;
+use assetchunk::assign_groups;
+use assetchunk::sort_assets_by_group;
+use assetchunk::group_ranges;
+use assetchunk::check_asset_ids;
+use assetchunk::generate_c_ids;
+use assetchunk::generate_rust_ids;
+use assetchunk::AnimationConfig;
+use assetchunk::ImportPipeline;
+use assetchunk::convert_assets;
+use assetchunk::PackConfig;
 
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
-use std::fs::write;
+use std::fs::{read, write};
+
+
+/**
+ * Everything that controls how assets are identified and imported while packing.
+ */
+pub struct PackOptions {
+      pub config: PackConfig,
+      pub type_overrides: HashMap<String, AssetType>, // Asset names mapped to a type that skips identification
+      pub registry: AssetRegistry, // Kinds tried before the built-in detector, their validators must pass
+      pub pipeline: ImportPipeline,
+}
+
+impl PackOptions {
+
+      /**
+       * Build pack options from a pack config, with the built-in importers registered.
+       */
+      pub fn from_config(config:PackConfig) -> Result<Self, String> {
+            let mut type_overrides = HashMap::new();
+            for (name, asset_type) in config.types.iter() {
+                  type_overrides.insert(name.clone(), asset_type.parse()?);
+            }
+            let registry = config.registry();
+            Ok(PackOptions {
+                  config,
+                  type_overrides,
+                  registry,
+                  pipeline: ImportPipeline::with_defaults(),
+            })
+      }
+}
+
 
 /**
  * Pack assets from a directory into a chunk, then map the chunk to a manifest file.
  * and export the chunk and manifest to a specified output directory.
+ * Asset types are identified and imported here, imports from a previous pack at the same output are reused.
  */
-pub fn pack(input_dir:Option<PathBuf>, output_path:Option<PathBuf>) {
+pub fn pack(input_dir:Option<PathBuf>, output_path:Option<PathBuf>, options:&PackOptions) -> Result<(), String> {
       
       println!("[+] Packing Assets Into Chunk...");
       println!("[+] Input Directory: {:?}", input_dir);
       println!("[|]---------------------------------------------[|]");
       // Load the assets from the input directory into an array of assets
       println!("[+] Loading Assets...");
-      let assets = load_assets(input_dir);
-      if assets.is_none() {
-            eprintln!("[-] Error: Failed To Load Assets.");
-            return;
-      }
-      let assets = assets.unwrap();
+      let mut assets = load_assets(input_dir.clone(), &options.type_overrides, &options.registry, options.config.group_by_directory)
+            .ok_or("Failed To Load Assets.")?;
       println!("[+] Loaded {} Assets", assets.len());
+
+      // Put assets into the groups named in the config, these win over directory groups
+      let count = assign_groups(&mut assets, &options.config.groups)?;
+      println!("[+] Grouped {} Assets", count);
+
+      // Check audio against the format the project requires
+      check_audio_rules(&assets, &options.config.audio)?;
+
+      let chunk_path = output_path.clone().unwrap();
+      let manifest_path = output_path.clone().unwrap();
+
+      let mut filename = String::from(chunk_path.file_name().unwrap().to_str().unwrap());
+      filename.push_str(".chunk.asset");
+      let new_chunk_path = chunk_path.with_file_name(filename);
+
+      filename = String::from(manifest_path.file_name().unwrap().to_str().unwrap());
+      filename.push_str(".manifest.asset");
+      let new_manifest_path = manifest_path.with_file_name(filename);
+
+      // Find the animations, before their sprites get packed away into atlases
+      let mut definitions = load_animation_sidecars(input_dir.as_ref().unwrap())?;
+      definitions.extend(options.config.animations.clone()); // The pack config wins over sidecars
+      let mut animations = detect_animations(&assets, &definitions).map_err(|e| format!("Failed To Build Animations: {}", e))?;
+      println!("[+] Found {} Animations", animations.len());
+
+      // Convert Tiled maps and OBJ models, then check and compile the scripts.
+      // Shared with hot reload and the asset server, so a reloaded asset matches a packed one
+      convert_assets(&mut assets, &options.config)?;
+
+      // Pack the configured sprites into atlas pages
+      let sprites = build_atlases(&mut assets, &options.config.atlases).map_err(|e| format!("Failed To Build Atlases: {}", e))?;
+      remap_animations_to_atlas(&mut animations, &sprites);
+      remap_dependencies_to_atlas(&mut assets, &sprites);
+
+      // Lay each group out back to back, so it can be loaded with a single read,
+      // with every asset after its dependencies, so the manifest order is a valid load order
+      sort_assets_by_group(&mut assets)?;
+
+      // Run the importers, reusing the output of the last pack where nothing changed.
+      // Runs after atlases are built so pages get imported and sprites are still PNGs when packed
+      println!("[+] Importing Assets...");
+      let previous = load_previous_pack(&new_chunk_path, &new_manifest_path);
+      if !import_assets(&mut assets, options, previous.as_ref()) {
+            return Err("Failed To Import Assets.".to_string());
+      }
       
+      // Asset IDs are hashes of the names, make sure no two assets ended up with the same one
+      check_asset_ids(&assets)?;
+
       // Create a new asset manifest from asset array
       println!("[+] Creating Asset Manifest...");
       let mut manifest = AssetManifest::new(assets);
+      manifest.set_sprites(sprites);
+      manifest.set_animations(animations);
 
 
       // Pack the assets into a contiguous chunk of memory
@@ -38,70 +134,174 @@ pub fn pack(input_dir:Option<PathBuf>, output_path:Option<PathBuf>) {
 
 
       if chunk.is_empty() {
-            println!("Error: Chunk is empty after packing assets.");
-            return;
+            return Err("Chunk is empty after packing assets.".to_string());
       }
 
+      let groups = group_ranges(manifest.get_assets())?;
+      for group in groups.iter() {
+            println!("[+] Group {}: {} Assets, {} Bytes At Offset {}", group.name, group.assets.len(), group.size, group.offset);
+      }
+      manifest.set_groups(groups);
+
       println!("[+] Chunk Size: {} Bytes / {} KB / {} MB", chunk.len(), chunk.len() / 1024, chunk.len() / (1024 * 1024));
       println!("[+] Chunk Packed Successfully!");
 
-      let chunk_path = PathBuf::from(output_path.clone().unwrap());
-      let manifest_path = PathBuf::from(output_path.clone().unwrap());
+      //println!("[+] Writing Chunk To File: {:?}", );
+      write( new_chunk_path, chunk).map_err(|e| format!("Failed To Write Chunk To File: {:?}", e))?;
+      println!("[+] Chunk Written Successfully!");
+      // Write The Manifest To Disk
+      //println!("[+] Writing Manifest To File: {:?}", );
+      manifest.write(new_manifest_path.to_str().unwrap());
 
-      let mut filename = String::from(chunk_path.file_name().unwrap().to_str().unwrap());
-      filename.push_str(".chunk.asset");
-      let new_chunk_path = chunk_path.with_file_name(filename);
+      println!("[+] Assets Packed Successfully!");
+      Ok(())
 
-      filename = String::from(manifest_path.file_name().unwrap().to_str().unwrap());
-      filename.push_str(".manifest.asset");
-      let new_manifest_path = manifest_path.with_file_name(filename);
+}
 
+/**
+ * Load the chunk and manifest written by a previous pack, if there is one.
+ */
+pub fn load_previous_pack(chunk_path:&PathBuf, manifest_path:&PathBuf) -> Option<(AssetManifest, Vec<u8>)> {
+      let manifest_json = std::fs::read_to_string(manifest_path).ok()?;
+      let chunk = read(chunk_path).ok()?;
+      let manifest = AssetManifest::try_from_json(&manifest_json).ok()?;
+      Some((manifest, chunk))
+}
 
 
-      //println!("[+] Writing Chunk To File: {:?}", );
-      let write_result = write( new_chunk_path, chunk);
-      match write_result {
-            Ok(_) => {
-               println!("[+] Chunk Written Successfully!")
-            },
-            Err(e) => {
-                  println!("[-] Error: Failed To Write Chunk To File: {:?}", e);
-                  return;
+/**
+ * Run the import pipeline on every asset.
+ * An asset whose import record (source hash, importer versions and settings) matches the previous pack
+ * takes its imported data from the previous chunk instead of being imported again.
+ */
+pub fn import_assets(assets:&mut [Asset], options:&PackOptions, previous:Option<&(AssetManifest, Vec<u8>)>) -> bool {
+      for asset in assets.iter_mut() {
+            let record = options.pipeline.plan(asset, &options.config);
+            if record.is_none() {
+                  continue;
+            }
+
+            let cached = previous.and_then(|(manifest, chunk)| {
+                  manifest.get_assets().iter()
+                        .find(|cached| cached.get_name() == asset.get_name() && *cached.get_import_record() == record)
+                        .and_then(|cached| chunk.get(cached.get_chunk_location()..cached.get_chunk_location() + cached.get_size()).map(|data| (cached, data)))
+            });
+
+            match cached {
+                  Some((cached, data)) => {
+                        println!("[+] Reusing Cached Import: {}", asset.get_name());
+                        asset.set_type(cached.get_type().clone());
+                        for (key, value) in cached.get_metadata() {
+                              asset.set_metadata(key, value);
+                        }
+                        asset.set_data(data.to_vec());
+                        asset.set_import_record(record);
+                  },
+                  None => {
+                        println!("[+] Importing: {}", asset.get_name());
+                        if let Err(e) = options.pipeline.run(asset, &options.config) {
+                              eprintln!("[-] Error: {}", e);
+                              return false;
+                        }
+                  }
             }
       }
-      // Write The Manifest To Disk
-      //println!("[+] Writing Manifest To File: {:?}", );
-      manifest.write(new_manifest_path.to_str().unwrap());
+      true
+}
 
-      println!("[+] Assets Packed Successfully!");
+
+/**
+ * Animation sidecars (`<asset>.anim.toml`) describe an image next to them, they aren't assets themselves.
+ */
+fn is_animation_sidecar(path:&std::path::Path) -> bool {
+      path.to_str().is_some_and(|path| path.ends_with(".anim.toml"))
+}
 
 
+/**
+ * Load the animation sidecars from a directory, keyed by the name of the image asset they describe.
+ */
+pub fn load_animation_sidecars(input_dir:&PathBuf) -> Result<BTreeMap<String, AnimationConfig>, String> {
+      let mut definitions = BTreeMap::new();
+      let entries = std::fs::read_dir(input_dir).map_err(|e| format!("Failed To Read Input Directory: {}", e))?;
+      for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).filter(|path| is_animation_sidecar(path)) {
+            let file_name = path.file_name().unwrap().to_str().unwrap();
+            let asset_name = file_name.trim_end_matches(".anim.toml").to_string();
+            let text = std::fs::read_to_string(&path).map_err(|e| format!("Failed To Read {}: {}", file_name, e))?;
+            let definition = AnimationConfig::from_toml(&text).map_err(|e| format!("{}: {}", file_name, e))?;
+            definitions.insert(asset_name, definition);
+      }
+      Ok(definitions)
 }
 
+
 /**
- * Load assets from a directory into an array of assets
+ * Load assets from a directory into an array of assets, identifying the type of each one.
  */
-pub fn load_assets(input_dir:Option<PathBuf>) -> Option<Vec<Asset>> {
+pub fn load_assets(input_dir:Option<PathBuf>, type_overrides:&HashMap<String, AssetType>, registry:&AssetRegistry, group_by_directory:bool) -> Option<Vec<Asset>> {
       let mut assets:Vec<Asset> = Vec::new();
       let input_dir = input_dir.unwrap();
-      if input_dir.is_dir() == false {
+      if !input_dir.is_dir() {
             eprintln!("[-] Error: Input Directory Does Not Exist. {:?}", input_dir);
             eprintln!("[-] Current Working Directory: {:?}", std::env::current_dir().unwrap());
             return None;
       }
-      for entry in std::fs::read_dir(input_dir).unwrap() {
-            let entry = entry.unwrap();
-            let path = entry.path();
+      let mut files:Vec<(PathBuf, Option<String>)> = Vec::new();
+      collect_files(&input_dir, None, &mut files);
+      for (path, directory) in files {
+            if is_animation_sidecar(&path) {
+                  continue;
+            }
             // We only want the file name not its extension
             let file_name = path.file_stem().unwrap().to_str().unwrap();
-            let asset_type = AssetType::Other("UNIDENTIFIED".to_string()); // Default Asset Type
-            let asset = Asset::load(path.to_str().unwrap(), file_name, asset_type);
+            let path_str = path.to_str().unwrap();
+            let mut asset = Asset::load(path_str, file_name, AssetType::Other("UNIDENTIFIED".to_string()));
+
+            let asset_type = match type_overrides.get(file_name) {
+                  Some(asset_type) => {
+                        println!("[+] Asset Type Overridden: {} -> {:?}", file_name, asset_type);
+                        asset_type.clone()
+                  },
+                  None => {
+                        let identification = registry.identify(Some(path_str), asset.get_data().as_deref().unwrap());
+                        println!("[+] Identified Asset: {} -> {:?} (Confidence: {:.2})", file_name, identification.asset_type, identification.confidence);
+                        if identification.confidence < 0.5 {
+                              println!("[!] Warning: Low Confidence Identifying {}, Consider Overriding Its Type.", file_name);
+                        }
+                        identification.asset_type
+                  }
+            };
+            if let Err(e) = registry.validate(&asset_type, asset.get_data().as_deref().unwrap()) {
+                  eprintln!("[-] Error: Asset {} Failed Validation As {:?}: {}", file_name, asset_type, e);
+                  return None;
+            }
+            asset.set_type(asset_type);
+            asset.extract_metadata();
+            if group_by_directory {
+                  asset.set_group(directory);
+            }
             assets.push(asset);
       }
       Some(assets)
 }
 
 
+/**
+ * Collect the files under a directory, along with the top level subdirectory each one is in.
+ */
+fn collect_files(dir:&PathBuf, directory:Option<&str>, files:&mut Vec<(PathBuf, Option<String>)>) {
+      for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                  let name = path.file_name().unwrap().to_str().unwrap().to_string();
+                  collect_files(&path, Some(directory.unwrap_or(&name)), files);
+            } else {
+                  files.push((path, directory.map(str::to_string)));
+            }
+      }
+}
+
+
 
 /**
  * Print A List Of The Contents Of The Chunk
@@ -131,12 +331,27 @@ pub fn print_manifest_contents(manifest_path:PathBuf) {
       println!("[|]------------------------------------------------------------[|]");
       for asset in assets {
             println!("[|]- Asset Name: {}", asset.get_name());
+            println!("[|]- Asset ID: {}", asset.get_id());
             println!("[|]- Asset Type: {:?}", asset.get_type());
-            println!("[|]- Asset Size: {} Bytes / {} MB", asset.get_size(), (asset.get_size() as f32 / (1024 * 1024) as f32) as f32);
+            println!("[|]- Asset Size: {} Bytes / {} MB", asset.get_size(), asset.get_size() as f32 / (1024 * 1024) as f32);
             println!("[|]- Asset Offset: {} Bytes", asset.get_chunk_location());
             println!("[|]- Asset Data: {:?}", asset.get_data());
             println!("[|]------------------------------------------------------------[|]");
       }
+
+      if !manifest.get_animations().is_empty() {
+            println!("[+]Animations:");
+            for animation in manifest.get_animations() {
+                  println!("[|]- {} On {}: {} Frames, {:?}", animation.name, animation.image, animation.frames.len(), animation.loop_mode);
+            }
+      }
+
+      if !manifest.get_sprites().is_empty() {
+            println!("[+]Atlas Sprites:");
+            for sprite in manifest.get_sprites() {
+                  println!("[|]- {} In {} At ({}, {}) {}x{}", sprite.name, sprite.atlas, sprite.x, sprite.y, sprite.width, sprite.height);
+            }
+      }
 }
 
 
@@ -144,7 +359,7 @@ pub fn print_manifest_contents(manifest_path:PathBuf) {
 /**
  * Pack assets into a contiguous chunk of memory
  */
-pub fn pack_assets(assets: &mut Vec<Asset>) -> Vec<u8> {
+pub fn pack_assets(assets: &mut [Asset]) -> Vec<u8> {
       println!("[+] Packing Assets Into Chunk...");
       let mut chunk:Vec<u8> = Vec::new();
       let mut offset:usize = 0;
@@ -161,9 +376,40 @@ pub fn pack_assets(assets: &mut Vec<Asset>) -> Vec<u8> {
             }
             // Extend the asset chunk using extend/into_iter
             let asset_data_slice = asset_data.as_ref().unwrap().as_slice(); // Finegling the bagel
-            chunk.extend(asset_data_slice.into_iter());
+            chunk.extend(asset_data_slice);
             offset += asset_size; // Pointer arithmetic to get the offset of the next asset in the chunk
             println!("[+] Next Asset Offset: {}", offset);     
       }
       chunk
+}
+
+
+/**
+ * Generate A Rust Module And/Or C Header Of Asset ID Constants From A Manifest
+ */
+pub fn write_asset_ids(manifest_path:PathBuf, rust_path:Option<PathBuf>, c_path:Option<PathBuf>) {
+
+      let manifest = match std::fs::read_to_string(&manifest_path).map_err(|e| e.to_string()).and_then(|json| AssetManifest::try_from_json(&json)) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                  eprintln!("[-] Error: Failed To Load Manifest {:?}: {}", manifest_path, e);
+                  return;
+            }
+      };
+
+      if rust_path.is_none() && c_path.is_none() {
+            println!("[!] Warning: Nothing To Generate, Pass --rust And/Or --c");
+            return;
+      }
+
+      let outputs = [(rust_path, generate_rust_ids as fn(&AssetManifest) -> Result<String, String>), (c_path, generate_c_ids)];
+      for (path, generate) in outputs {
+            let Some(path) = path else {
+                  continue;
+            };
+            match generate(&manifest).and_then(|source| write(&path, source).map_err(|e| e.to_string())) {
+                  Ok(()) => println!("[+] Wrote {} Asset IDs To {:?}", manifest.get_assets().len(), path),
+                  Err(e) => eprintln!("[-] Error: Failed To Write Asset IDs To {:?}: {}", path, e),
+            }
+      }
 }
\ No newline at end of file
@@ -11,9 +11,7 @@ use crossterm::terminal;
 use crossterm::execute;
 use crossterm::terminal::ClearType;
 use crossterm::terminal::EnterAlternateScreen;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, EventStream, KeyEventKind};
-
-use std::io::{stdout, Write};
+use crossterm::event::{Event, KeyCode, KeyEventKind};
 
 pub enum MenuState {
    Main,
@@ -64,6 +62,8 @@ const MENU: &str = r#"
 "#;
 
 
+// The draw calls need `screen_stack` mutably, so they can't move into match guards
+#[allow(clippy::collapsible_match)]
 pub fn interactive_term<W>(w: &mut W, recievers:&InteractiveTermRecievers) -> std::io::Result<()>
 where 
    W: io::Write, {
@@ -72,7 +72,7 @@ where
 
    execute!(w, EnterAlternateScreen).unwrap();
 
-   terminal::enable_raw_mode();
+   terminal::enable_raw_mode()?;
 
    loop {
       match screen_stack.peek() {
@@ -136,13 +136,14 @@ pub fn carat_blinker(sender: Sender<bool>) {
    loop {
       thread::sleep(Duration::from_millis(500));
       visible = !visible;
-      if let Err(_) = sender.send(visible) {
+      if sender.send(visible).is_err() {
          eprintln!("[-] Error: Failed To Send Carat Blinker Message");
          break;
       }
    }
 }
 
+#[allow(clippy::single_match)]
 pub fn draw_pack_menu<W>(w:&mut W, screen_stack:&mut ScreenStack, recievers:&InteractiveTermRecievers) -> std::io::Result<()>
 where 
    W: io::Write, {
@@ -151,7 +152,6 @@ where
       let mut input_mode = InputMode::Nav;
    
       let mut selection = 0;
-      let mut cursor_pos = (0, 0);
       let mut input_dir = String::new();
       let mut output_name = String::new();
 
@@ -227,7 +227,7 @@ where
          queue!(w, cursor::MoveTo(0, buffer_size().unwrap().1 - 6), style::Print(divider_text)).unwrap();
 
          // Show Asset File Name & Manifest File Name + Path
-         if output_name.len() > 0 {
+         if !output_name.is_empty() {
             queue!(w, cursor::MoveTo(3, buffer_size().unwrap().1 - 5), style::PrintStyledContent(format!("Asset Chunk File: {}.chunk.asset", output_name).magenta())).unwrap();
             queue!(w, cursor::MoveTo(3, buffer_size().unwrap().1 - 4), style::PrintStyledContent(format!("Asset Manifest File: {}.manifest.asset", output_name).magenta())).unwrap();
          }
@@ -343,6 +343,7 @@ where
       }
 }
 
+#[allow(clippy::single_match)]
 pub fn draw_main_menu<W>(w: &mut W, screen_stack:&mut ScreenStack, recievers:&InteractiveTermRecievers) -> std::io::Result<()>
 where 
    W: io::Write, {
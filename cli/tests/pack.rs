@@ -0,0 +1,50 @@
+use assetchunk::{AssetManifest, AssetType};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+fn temp_directory(name:&str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("assetchunk_cli_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/**
+ * Run `assetchunk-cli pack` On A Directory, Writing `<output>.chunk.asset` And `<output>.manifest.asset`
+ */
+fn pack(input_dir:&Path, output:&Path, flags:&[&str]) -> ExitStatus {
+    Command::new(env!("CARGO_BIN_EXE_assetchunk-cli"))
+        .arg("pack")
+        .arg(input_dir)
+        .arg(output)
+        .args(flags)
+        .output()
+        .unwrap()
+        .status
+}
+
+#[test]
+fn failed_packs_exit_with_an_error() {
+    let directory = temp_directory("failed_pack");
+    let input_dir = directory.join("assets");
+    std::fs::create_dir_all(&input_dir).unwrap();
+    std::fs::write(input_dir.join("broken.lua"), "if then\n").unwrap();
+    assert!(!pack(&input_dir, &directory.join("game"), &[]).success());
+
+    std::fs::write(input_dir.join("broken.lua"), "print('fixed')\n").unwrap();
+    assert!(pack(&input_dir, &directory.join("game"), &[]).success());
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[test]
+fn packs_record_identified_types_and_type_overrides() {
+    let directory = temp_directory("typed_pack");
+    let input_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../test");
+    assert!(pack(&input_dir, &directory.join("game"), &["--type", "script1=Other:TXT"]).success());
+
+    let manifest = AssetManifest::try_from_json(&std::fs::read_to_string(directory.join("game.manifest.asset")).unwrap()).unwrap();
+    let type_of = |name:&str| manifest.get_assets().iter().find(|asset| asset.get_name() == name).unwrap().get_type().clone();
+    assert_eq!(type_of("Tileset_Terrain1"), AssetType::Image("PNG".to_string()));
+    assert_eq!(type_of("script1"), AssetType::Other("TXT".to_string()));
+    assert!(manifest.get_assets().iter().all(|asset| asset.get_type().is_identified()));
+    let _ = std::fs::remove_dir_all(directory);
+}
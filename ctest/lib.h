@@ -1,8 +1,27 @@
 
 #include <stdio.h>
 #include <stdint.h>
+#include <stdbool.h>
 #include <stdlib.h>
 
+///  * API: - The Kind Of An Asset, Mirrors The Variants Of `AssetType`
+typedef enum RawCAssetKind {
+  RawCAssetKind_Image = 0,
+  RawCAssetKind_Audio = 1,
+  RawCAssetKind_Font = 2,
+  RawCAssetKind_Shader = 3,
+  RawCAssetKind_Model = 4,
+  RawCAssetKind_Script = 5,
+  RawCAssetKind_Other = 6,
+  RawCAssetKind_Custom = 7,
+} RawCAssetKind;
+
+///  * API: - A Single Metadata Key-Value Pair Of An Asset
+typedef struct RawCAssetMetadata {
+  const char *key;
+  const char *value;
+} RawCAssetMetadata;
+
 ///  * API: - Represents A Single Asset Within The Chunk  *
 ///
 typedef struct RawCAsset {
@@ -10,24 +29,315 @@ typedef struct RawCAsset {
   uintptr_t location;
   uintptr_t size;
   const char *name;
+  RawCAssetKind kind;
+  const char *kind_name;
+  const char *format;
+  const char *extension;
+  const RawCAssetMetadata *metadata;
+  uintptr_t metadata_count;
+  const char *const *dependencies;
+  uintptr_t dependency_count;
+  uint32_t id;
+  const char *virtual_path;
 } RawCAsset;
 
+///  * API: - Where A Sprite Lives Inside An Atlas Page Asset
+typedef struct RawCSpriteRect {
+  const char *name;
+  const char *atlas;
+  uint32_t x;
+  uint32_t y;
+  uint32_t width;
+  uint32_t height;
+  float u0;
+  float v0;
+  float u1;
+  float v1;
+} RawCSpriteRect;
+
+///  * API: - What Happens When An Animation Reaches Its Last Frame
+typedef enum RawCLoopMode {
+  RawCLoopMode_Loop = 0,
+  RawCLoopMode_Once = 1,
+  RawCLoopMode_PingPong = 2,
+} RawCLoopMode;
+
+///  * API: - A Single Frame Of An Animation
+typedef struct RawCAnimationFrame {
+  const char *image;
+  uint32_t x;
+  uint32_t y;
+  uint32_t width;
+  uint32_t height;
+  uint32_t duration_ms;
+} RawCAnimationFrame;
+
+///  * API: - An Animation Cut From An Image Asset
+typedef struct RawCAnimation {
+  const char *name;
+  const char *image;
+  RawCLoopMode loop_mode;
+  const RawCAnimationFrame *frames;
+  uintptr_t frame_count;
+} RawCAnimation;
+
+///  * API: - Image Properties Recorded At Pack Time
+typedef struct RawCImageInfo {
+  uint32_t width;
+  uint32_t height;
+  uint8_t bit_depth;
+  const char *color_type;
+  uint32_t frame_count;
+} RawCImageInfo;
+
+///  * API: - Audio Properties Recorded At Pack Time
+typedef struct RawCAudioInfo {
+  uint32_t sample_rate;
+  uint16_t channels;
+  uint16_t bit_depth;
+  uint64_t duration_ms;
+} RawCAudioInfo;
+
+///  * API: - Seek Origins, Same Values As `SEEK_SET`, `SEEK_CUR` And `SEEK_END`
+#define ASSET_SEEK_SET 0
+#define ASSET_SEEK_CUR 1
+#define ASSET_SEEK_END 2
+
+///  * API: - A Stream Over A Single Asset, For Decoders That Pull Data Incrementally (Music, Video).
+///  * Call The Callbacks With The Stream Itself, e.g. `stream->read(stream, buffer, 4096)`.
+typedef struct RawCAssetStream {
+  void *user_data;
+  uint64_t size;
+  uintptr_t (*read)(struct RawCAssetStream *stream, void *buffer, uintptr_t count);
+  int32_t (*seek)(struct RawCAssetStream *stream, int64_t offset, int32_t origin);
+  int64_t (*tell)(struct RawCAssetStream *stream);
+} RawCAssetStream;
+
+///  * API: - A Tileset Used By A Tilemap, `image` Is The Name Of The Tileset Image Asset (Or Atlas Sprite)
+typedef struct RawCTileset {
+  uint32_t first_gid;
+  const char *name;
+  const char *image;
+  uint32_t tile_width;
+  uint32_t tile_height;
+  uint32_t columns;
+  uint32_t tile_count;
+} RawCTileset;
+
+///  * API: - A Layer Of Tiles, Row Major. 0 Is Empty, The Top 4 Bits Of A Tile Are Tiled's Flip Flags
+typedef struct RawCTileLayer {
+  const char *name;
+  uint32_t width;
+  uint32_t height;
+  const uint32_t *tiles;
+  const RawCAssetMetadata *properties;
+  uintptr_t property_count;
+} RawCTileLayer;
+
+///  * API: - An Object Of An Object Layer, Positions Are In Pixels
+typedef struct RawCTilemapObject {
+  uint32_t id;
+  const char *name;
+  const char *class_name;
+  float x;
+  float y;
+  float width;
+  float height;
+  float rotation;
+  uint32_t gid;
+  const RawCAssetMetadata *properties;
+  uintptr_t property_count;
+} RawCTilemapObject;
+
+///  * API: - A Layer Of Objects
+typedef struct RawCObjectLayer {
+  const char *name;
+  const RawCTilemapObject *objects;
+  uintptr_t object_count;
+  const RawCAssetMetadata *properties;
+  uintptr_t property_count;
+} RawCObjectLayer;
+
+///  * API: - A Tilemap Imported From Tiled
+typedef struct RawCTilemap {
+  uint32_t width;
+  uint32_t height;
+  uint32_t tile_width;
+  uint32_t tile_height;
+  const RawCTileset *tilesets;
+  uintptr_t tileset_count;
+  const RawCTileLayer *layers;
+  uintptr_t layer_count;
+  const RawCObjectLayer *object_layers;
+  uintptr_t object_layer_count;
+  const RawCAssetMetadata *properties;
+  uintptr_t property_count;
+} RawCTilemap;
+
+///  * API: - Vertex Layout Flags Of A Mesh
+#define MESH_LAYOUT_POSITION 1
+#define MESH_LAYOUT_NORMAL 2
+#define MESH_LAYOUT_UV 4
+
+///  * API: - A Run Of Indices Drawn With One Material
+typedef struct RawCSubMesh {
+  const char *material;
+  uint32_t index_start;
+  uint32_t index_count;
+} RawCSubMesh;
+
+///  * API: - An Indexed Triangle Mesh, Vertices Are Interleaved Position (3 Floats), Normal (3) And UV (2)
+///  * With Only The Parts Set In `layout`
+typedef struct RawCMesh {
+  uint32_t layout;
+  uint32_t vertex_stride;
+  const float *vertices;
+  uintptr_t vertex_count;
+  const uint32_t *indices;
+  uintptr_t index_count;
+  float bounds_min[3];
+  float bounds_max[3];
+  const RawCSubMesh *submeshes;
+  uintptr_t submesh_count;
+  const char *const *material_libraries;
+  uintptr_t material_library_count;
+} RawCMesh;
+
+///  * API: - Assets Of A Loaded Chunk In The Order They Should Be Loaded, Dependencies First.
+///  * The Pointers Are Into The Chunk's Manifest. Free It With `free_asset_load_order`.
+typedef struct RawCAssetLoadOrder {
+  RawCAsset *const *assets;
+  uintptr_t asset_count;
+} RawCAssetLoadOrder;
+
+///  * API: - A Group Of Assets Laid Out Back To Back In The Chunk, Load It With `load_asset_group`
+typedef struct RawCAssetGroup {
+  const char *name;
+  uintptr_t offset;
+  uintptr_t size;
+  const char *const *assets;
+  uintptr_t asset_count;
+} RawCAssetGroup;
+
 ///  * API: - Represents The Asset Manifest
 typedef struct RawCAssetManifest {
-  RawCAsset *assets;
+  RawCAsset **assets;
   uintptr_t asset_count;
+  RawCSpriteRect *sprites;
+  uintptr_t sprite_count;
+  RawCAnimation *animations;
+  uintptr_t animation_count;
+  RawCAssetGroup *groups;
+  uintptr_t group_count;
+  void *id_index;
 } RawCAssetManifest;
 
 
+///  * API: - Several Chunks Mounted Together, Opaque, Made With `create_asset_vfs`
+typedef struct AssetVfs AssetVfs;
+
+///  * API: - Watches A Source Directory During Development, Opaque, Made With `create_hot_reloader`
+typedef struct HotReloader HotReloader;
+
+///  * API: - Called With Every Reloaded Asset. `data` Stays Valid Until The Asset Reloads Again Or The Reloader Is Destroyed.
+typedef void (*RawCReloadCallback)(const char *name, const void *data, uintptr_t size, void *user_data);
+
+///  * API: - A Connection To An Asset Server, Opaque, Made With `connect_asset_server`
+typedef struct AssetClient AssetClient;
+
 typedef struct RawCAssetChunk {
   void *data;
   uintptr_t size;
   RawCAssetManifest *manifest;
 } RawCAssetChunk;
 
+///  * API: - Loads Chunks On Worker Threads, Opaque, Made With `create_asset_loader`
+typedef struct AssetLoader AssetLoader;
+
+///  * API: - A Handle To A Background Load, Opaque, Free With `release_load_ticket`
+typedef struct LoadTicket LoadTicket;
+
+///  * API: - Where A Background Load Has Got To
+typedef enum RawCLoadStatus {
+  RawCLoadStatus_Queued = 0,
+  RawCLoadStatus_Loading = 1,
+  RawCLoadStatus_Loaded = 2,
+  RawCLoadStatus_Failed = 3,
+} RawCLoadStatus;
+
+///  * API: - Called On A Worker Thread When A Background Load Finishes, `chunk` Is NULL If It Failed.
+///  * The Chunk Then Belongs To The Callback, Which Frees It With `free_asset_chunk`, And Can't Also Be Taken From The Ticket.
+typedef void (*RawCLoadCallback)(RawCAssetChunk *chunk, void *user_data);
+
+///  * API: - A Reference Counted Chunk Any Thread Can Read, Opaque, Made With `assetchunk_open`
+typedef struct SharedChunkData SharedChunkData;
+
+///  * API: - An Asset Looked Up Through A Shared Chunk Or A VFS. `data` Points Into The Chunk, So It Is Valid
+///  * For As Long As The Caller Holds A Reference To The Chunk (Or Keeps It Mounted).
+typedef struct RawCAssetView {
+  const void *data;
+  uintptr_t size;
+  RawCAssetKind kind;
+} RawCAssetView;
+
+///  * API: - Keeps Part Of A Chunk In Memory Within A Byte Budget, Opaque, Made With `create_residency_manager`
+typedef struct ResidencyManager ResidencyManager;
+
 
 extern "C" RawCAssetManifest* load_asset_manifest(const char *filepath);
 extern "C" RawCAssetChunk* load_asset_chunk(const char *filepath_chunk, const char* filepath_manifest);
+extern "C" void free_asset_manifest(RawCAssetManifest *manifest);
+extern "C" void free_asset_chunk(RawCAssetChunk *asset_chunk);
 extern "C" RawCAsset* get_asset(RawCAssetChunk *asset_chunk, const char *name);
+extern "C" bool get_sprite_rect(RawCAssetChunk *asset_chunk, const char *name, RawCSpriteRect *out);
+extern "C" bool get_animation(RawCAssetChunk *asset_chunk, const char *name, RawCAnimation *out);
+extern "C" const char* get_asset_metadata(RawCAsset *asset, const char *key);
+extern "C" bool get_image_info(RawCAsset *asset, RawCImageInfo *out);
+extern "C" bool get_audio_info(RawCAsset *asset, RawCAudioInfo *out);
+extern "C" RawCAssetStream* open_asset_stream(RawCAssetChunk *asset_chunk, const char *name);
+extern "C" RawCAssetStream* open_asset_stream_file(const char *filepath_chunk, const char *filepath_manifest, const char *name);
+extern "C" void close_asset_stream(RawCAssetStream *stream);
+extern "C" RawCTilemap* get_tilemap(RawCAssetChunk *asset_chunk, const char *name);
+extern "C" void free_tilemap(RawCTilemap *tilemap);
+extern "C" RawCMesh* get_mesh(RawCAssetChunk *asset_chunk, const char *name);
+extern "C" void free_mesh(RawCMesh *mesh);
+extern "C" RawCAssetLoadOrder* get_asset_load_order(RawCAssetChunk *asset_chunk, const char *name);
+extern "C" void free_asset_load_order(RawCAssetLoadOrder *load_order);
+extern "C" RawCAssetChunk* load_asset_group(const char *filepath_chunk, const char *filepath_manifest, const char *group);
+extern "C" AssetVfs* create_asset_vfs(void);
+extern "C" void destroy_asset_vfs(AssetVfs *vfs);
+extern "C" int64_t vfs_mount(AssetVfs *vfs, const char *filepath_chunk, const char *filepath_manifest, int32_t priority);
+extern "C" bool vfs_unmount(AssetVfs *vfs, int64_t mount);
+extern "C" bool vfs_lookup(AssetVfs *vfs, const char *name, RawCAssetView *out);
+extern "C" void set_asset_override_dir(const char *directory);
+extern "C" HotReloader* create_hot_reloader(const char *directory, const char *config_path, RawCReloadCallback callback, void *user_data);
+extern "C" uintptr_t hot_reload_poll(HotReloader *reloader);
+extern "C" void destroy_hot_reloader(HotReloader *reloader);
+extern "C" AssetClient* connect_asset_server(const char *address);
+extern "C" RawCAsset* fetch_asset(AssetClient *client, const char *name);
+extern "C" void free_fetched_asset(RawCAsset *asset);
+extern "C" void disconnect_asset_server(AssetClient *client);
+extern "C" AssetLoader* create_asset_loader(uintptr_t threads);
+extern "C" void destroy_asset_loader(AssetLoader *loader);
+extern "C" LoadTicket* load_asset_chunk_async(AssetLoader *loader, const char *filepath_chunk, const char *filepath_manifest, RawCLoadCallback callback, void *user_data);
+extern "C" LoadTicket* load_asset_group_async(AssetLoader *loader, const char *filepath_chunk, const char *filepath_manifest, const char *group, RawCLoadCallback callback, void *user_data);
+extern "C" RawCLoadStatus get_load_status(LoadTicket *ticket);
+extern "C" RawCAssetChunk* take_loaded_chunk(LoadTicket *ticket);
+extern "C" RawCAssetChunk* wait_for_load(LoadTicket *ticket);
+extern "C" void release_load_ticket(LoadTicket *ticket);
+extern "C" const SharedChunkData* assetchunk_open(const char *filepath_chunk, const char *filepath_manifest);
+extern "C" const SharedChunkData* assetchunk_retain(const SharedChunkData *chunk);
+extern "C" void assetchunk_release(const SharedChunkData *chunk);
+extern "C" bool assetchunk_get_asset(const SharedChunkData *chunk, const char *name, RawCAssetView *asset);
+extern "C" ResidencyManager* create_residency_manager(const char *filepath_chunk, const char *filepath_manifest, uintptr_t budget);
+extern "C" void destroy_residency_manager(ResidencyManager *manager);
+extern "C" bool residency_get_asset(ResidencyManager *manager, const char *name, RawCAssetView *asset);
+extern "C" bool residency_pin(ResidencyManager *manager, const char *name);
+extern "C" bool residency_unpin(ResidencyManager *manager, const char *name);
+extern "C" void residency_set_budget(ResidencyManager *manager, uintptr_t budget);
+extern "C" uintptr_t residency_resident_bytes(ResidencyManager *manager);
+extern "C" uint32_t get_asset_id(const char *name);
+extern "C" bool get_asset_by_id(RawCAssetChunk *asset_chunk, uint32_t id, RawCAsset *out);
+extern "C" bool assetchunk_get_asset_by_id(const SharedChunkData *chunk, uint32_t id, RawCAssetView *asset);
 
 
@@ -18,7 +18,7 @@ int main()
     printf("Name: %s\n", tileset_terrain1->name);
     printf("Loc: %zu\n", tileset_terrain1->location);
 
-    Texture2D tex = LoadTextureFromImage(LoadImageFromMemory("png", (unsigned char*)tileset_terrain1->data, tileset_terrain1->size));
+    Texture2D tex = LoadTextureFromImage(LoadImageFromMemory(tileset_terrain1->extension, (unsigned char*)tileset_terrain1->data, tileset_terrain1->size));
     printf("Texture: %s\n", (unsigned char*)tileset_terrain1->data);
     const int screenWidth = 800;
     const int screenHeight = 600;
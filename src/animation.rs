@@ -0,0 +1,209 @@
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::image::png_dimensions;
+use crate::{Asset, AssetType, SpriteRect};
+
+
+/**
+ * What Happens When An Animation Reaches Its Last Frame
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopMode {
+    #[default]
+    Loop, // Start Over From The First Frame
+    Once, // Stop On The Last Frame
+    PingPong, // Play Backwards Back To The First Frame, Then Forwards Again
+}
+
+
+/**
+ * A Single Frame Of An Animation
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AnimationFrame {
+    pub image: String, // Image Asset Holding The Frame (An Atlas Page If The Sheet Was Packed Into One)
+    pub x: u32, // Pixel Rectangle Of The Frame Within The Image
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub duration_ms: u32,
+}
+
+
+/**
+ * An Animation Record, Linked To The Image Asset(s) Its Frames Come From
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    pub name: String,
+    pub image: String, // The Image Asset The Animation Was Defined On (The Atlas Page Holding It If It Was Packed Into One)
+    pub loop_mode: LoopMode,
+    pub frames: Vec<AnimationFrame>,
+}
+
+
+/**
+ * Animation Definition, Either From A `<asset>.anim.toml` Sidecar Next To The Image
+ * Or From An `[animations.<asset>]` Table In The Pack Config:
+ *
+ * ```toml
+ * frame_width = 16
+ * frame_height = 16
+ * frames = [0, 1, 2, 3, 2, 1] # Grid Cells, Row Major (Default: Every Cell In Order)
+ * duration_ms = 120           # Duration Of Every Frame
+ * durations_ms = [200, 100]   # Or Per Frame Durations
+ * loop_mode = "ping_pong"     # "loop", "once" Or "ping_pong"
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AnimationConfig {
+    pub name: Option<String>, // Defaults To The Image Asset Name
+    pub frame_width: Option<u32>, // Defaults To The Image Height (Square Frames In A Horizontal Strip)
+    pub frame_height: Option<u32>, // Defaults To The Image Height
+    pub frames: Option<Vec<u32>>,
+    pub duration_ms: Option<u32>,
+    pub durations_ms: Option<Vec<u32>>,
+    pub loop_mode: LoopMode,
+}
+
+
+impl AnimationConfig {
+
+    const DEFAULT_DURATION_MS:u32 = 100;
+
+    /**
+     * Parse An Animation Definition From A TOML String
+     */
+    pub fn from_toml(text:&str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| format!("Failed To Parse Animation: {}", e))
+    }
+
+    /**
+     * Cut An Image Of The Given Size Into The Frames This Definition Describes
+     */
+    pub fn build(&self, image_name:&str, image_width:u32, image_height:u32) -> Result<Animation, String> {
+        let frame_width = self.frame_width.unwrap_or(image_height);
+        let frame_height = self.frame_height.unwrap_or(image_height);
+        if frame_width == 0 || frame_height == 0 || frame_width > image_width || frame_height > image_height {
+            return Err(format!("Frame Size {}x{} Does Not Fit Image {} ({}x{})", frame_width, frame_height, image_name, image_width, image_height));
+        }
+
+        let columns = image_width / frame_width;
+        let cells = columns * (image_height / frame_height);
+        let order:Vec<u32> = self.frames.clone().unwrap_or_else(|| (0..cells).collect());
+        if let Some(cell) = order.iter().find(|cell| **cell >= cells) {
+            return Err(format!("Frame {} Is Outside The {} Cell Grid Of {}", cell, cells, image_name));
+        }
+        if let Some(durations) = &self.durations_ms {
+            if durations.len() != order.len() {
+                return Err(format!("{} Has {} Frames But {} Durations", image_name, order.len(), durations.len()));
+            }
+        }
+
+        let frames = order.iter().enumerate().map(|(index, cell)| AnimationFrame {
+            image: image_name.to_string(),
+            x: (cell % columns) * frame_width,
+            y: (cell / columns) * frame_height,
+            width: frame_width,
+            height: frame_height,
+            duration_ms: self.durations_ms.as_ref()
+                .map(|durations| durations[index])
+                .or(self.duration_ms)
+                .unwrap_or(Self::DEFAULT_DURATION_MS),
+        }).collect();
+
+        Ok(Animation {
+            name: self.name.clone().unwrap_or_else(|| image_name.to_string()),
+            image: image_name.to_string(),
+            loop_mode: self.loop_mode,
+            frames,
+        })
+    }
+}
+
+
+/**
+ * Names Ending In One Of These Are Treated As Horizontal Strips Of Square Frames
+ */
+const STRIP_SUFFIXES:[&str; 8] = ["_idle", "_walk", "_run", "_attack", "_hurt", "_death", "_cast", "_jump"];
+
+
+/**
+ * Find The Animations Among Image Assets.
+ * ---
+ * 1. Images With A Definition In `definitions` (Sidecars / Pack Config) Use It.
+ * 2. `<name>_Frame<N>` Images Become One Animation Named `<name>`, One Whole Image Per Frame.
+ * 3. Images Named Like `adept_necromancer_idle` Whose Width Is A Multiple Of Their Height
+ *    Become A Strip Of Square Frames.
+ */
+pub fn detect_animations(assets:&[Asset], definitions:&BTreeMap<String, AnimationConfig>) -> Result<Vec<Animation>, String> {
+    let png = AssetType::Image("PNG".to_string());
+    let mut animations:Vec<Animation> = Vec::new();
+    let mut sequences:BTreeMap<String, Vec<(u32, &Asset, u32, u32)>> = BTreeMap::new();
+
+    for asset in assets.iter().filter(|asset| *asset.get_type() == png) {
+        let Some((width, height)) = asset.get_data().as_deref().and_then(png_dimensions) else {
+            continue;
+        };
+        let name = asset.get_name();
+
+        if let Some(definition) = definitions.get(name) {
+            animations.push(definition.build(name, width, height)?);
+        } else if let Some((base, number)) = split_frame_number(name) {
+            sequences.entry(base.to_string()).or_default().push((number, asset, width, height));
+        } else if STRIP_SUFFIXES.iter().any(|suffix| name.to_ascii_lowercase().ends_with(suffix)) && width > height && width % height == 0 {
+            animations.push(AnimationConfig::default().build(name, width, height)?);
+        }
+    }
+
+    for (base, mut frames) in sequences.into_iter().filter(|(_, frames)| frames.len() > 1) {
+        frames.sort_by_key(|(number, ..)| *number);
+        animations.push(Animation {
+            name: base,
+            image: frames[0].1.get_name().to_string(),
+            loop_mode: LoopMode::Loop,
+            frames: frames.iter().map(|(_, asset, width, height)| AnimationFrame {
+                image: asset.get_name().to_string(),
+                x: 0,
+                y: 0,
+                width: *width,
+                height: *height,
+                duration_ms: AnimationConfig::DEFAULT_DURATION_MS,
+            }).collect(),
+        });
+    }
+
+    Ok(animations)
+}
+
+
+/**
+ * Split `Tileset_Water_Frame3` Into (`Tileset_Water`, 3)
+ */
+fn split_frame_number(name:&str) -> Option<(&str, u32)> {
+    let index = name.to_ascii_lowercase().rfind("_frame")?;
+    let number = name[index + "_frame".len()..].parse().ok()?;
+    Some((&name[..index], number))
+}
+
+
+/**
+ * Point Animations And Frames Of Sprites That Were Packed Into An Atlas At The Atlas Page, Frames At Their Place In It
+ */
+pub fn remap_animations_to_atlas(animations:&mut [Animation], sprites:&[SpriteRect]) {
+    for animation in animations.iter_mut() {
+        if let Some(sprite) = sprites.iter().find(|sprite| sprite.name == animation.image) {
+            animation.image = sprite.atlas.clone();
+        }
+        for frame in animation.frames.iter_mut() {
+            if let Some(sprite) = sprites.iter().find(|sprite| sprite.name == frame.image) {
+                frame.image = sprite.atlas.clone();
+                frame.x += sprite.x;
+                frame.y += sprite.y;
+            }
+        }
+    }
+}
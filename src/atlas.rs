@@ -0,0 +1,147 @@
+use serde::{Serialize, Deserialize};
+
+use crate::config::{matches_pattern, AtlasConfig};
+use crate::image::{decode_png_rgba, encode_png_rgba, RgbaImage};
+use crate::{Asset, AssetType};
+
+
+/**
+ * Where A Sprite Ended Up Inside An Atlas Page
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SpriteRect {
+    pub name: String, // Name Of The Sprite (The Asset It Was Packed From)
+    pub atlas: String, // Name Of The Atlas Page Asset Holding The Sprite
+    pub x: u32, // Pixel Rectangle Within The Page
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub u0: f32, // Normalized Texture Coordinates, (u0, v0) Top Left To (u1, v1) Bottom Right
+    pub v0: f32,
+    pub u1: f32,
+    pub v1: f32,
+}
+
+
+/**
+ * A Single Page Of An Atlas, Before It Is Encoded Into An Asset
+ */
+#[derive(Debug, Clone)]
+pub struct AtlasPage {
+    pub name: String,
+    pub image: RgbaImage,
+}
+
+
+/**
+ * Bin Pack Sprites Into As Few Pages As Possible Using Shelves Sorted By Height.
+ * Pages Are Trimmed To The Area Actually Used.
+ */
+pub fn build_atlas(config:&AtlasConfig, sprites:Vec<(String, RgbaImage)>) -> Result<(Vec<AtlasPage>, Vec<SpriteRect>), String> {
+    let padding = config.padding;
+    let mut sprites = sprites;
+    sprites.sort_by(|(a_name, a), (b_name, b)| b.height.cmp(&a.height).then(b.width.cmp(&a.width)).then(a_name.cmp(b_name)));
+
+    // (Page, Sprite Name, Image, x, y)
+    let mut placements:Vec<(usize, String, RgbaImage, u32, u32)> = Vec::new();
+    let mut page = 0;
+    let (mut x, mut y, mut shelf_height) = (padding, padding, 0);
+    for (name, image) in sprites {
+        if image.width + padding * 2 > config.page_size || image.height + padding * 2 > config.page_size {
+            return Err(format!("Sprite {} ({}x{}) Does Not Fit In A {}px Atlas Page", name, image.width, image.height, config.page_size));
+        }
+        if x + image.width + padding > config.page_size {
+            // Start A New Shelf
+            x = padding;
+            y += shelf_height + padding;
+            shelf_height = 0;
+        }
+        if y + image.height + padding > config.page_size {
+            // Start A New Page
+            page += 1;
+            x = padding;
+            y = padding;
+            shelf_height = 0;
+        }
+        shelf_height = shelf_height.max(image.height);
+        let width = image.width;
+        placements.push((page, name, image, x, y));
+        x += width + padding;
+    }
+
+    let mut pages:Vec<AtlasPage> = Vec::new();
+    let mut rects:Vec<SpriteRect> = Vec::new();
+    for page in 0..placements.last().map(|placement| placement.0 + 1).unwrap_or(0) {
+        let on_page:Vec<&(usize, String, RgbaImage, u32, u32)> = placements.iter().filter(|placement| placement.0 == page).collect();
+        let width = on_page.iter().map(|(_, _, image, x, _)| x + image.width + padding).max().unwrap_or(1);
+        let height = on_page.iter().map(|(_, _, image, _, y)| y + image.height + padding).max().unwrap_or(1);
+        let page_name = format!("{}_page{}", config.name, page);
+
+        let mut page_image = RgbaImage::new(width, height);
+        for (_, name, image, x, y) in on_page {
+            page_image.blit(image, *x, *y);
+            rects.push(SpriteRect {
+                name: name.clone(),
+                atlas: page_name.clone(),
+                x: *x,
+                y: *y,
+                width: image.width,
+                height: image.height,
+                u0: *x as f32 / width as f32,
+                v0: *y as f32 / height as f32,
+                u1: (x + image.width) as f32 / width as f32,
+                v1: (y + image.height) as f32 / height as f32,
+            });
+        }
+        pages.push(AtlasPage { name: page_name, image: page_image });
+    }
+
+    Ok((pages, rects))
+}
+
+
+/**
+ * Build Every Configured Atlas Out Of The PNG Assets Being Packed.
+ * The Page Assets Are Added To `assets` And, Unless `keep_sprites` Is Set, The Sprites Are Removed.
+ */
+pub fn build_atlases(assets:&mut Vec<Asset>, configs:&[AtlasConfig]) -> Result<Vec<SpriteRect>, String> {
+    let png = AssetType::Image("PNG".to_string());
+    let mut rects:Vec<SpriteRect> = Vec::new();
+
+    for config in configs {
+        let selected = |asset:&Asset| *asset.get_type() == png && config.sprites.iter().any(|pattern| matches_pattern(pattern, asset.get_name()));
+
+        // Pages Go In The Sprites' Group When They All Share One
+        let mut groups = assets.iter().filter(|asset| selected(asset)).map(|asset| asset.get_group().clone());
+        let first = groups.next().flatten();
+        let group = if groups.all(|group| group == first) { first } else { None };
+
+        let mut sprites:Vec<(String, RgbaImage)> = Vec::new();
+        for asset in assets.iter().filter(|asset| selected(asset)) {
+            let data = asset.get_data().as_deref().ok_or(format!("Sprite {} Has No Data", asset.get_name()))?;
+            let image = decode_png_rgba(data).map_err(|e| format!("Sprite {}: {}", asset.get_name(), e))?;
+            sprites.push((asset.get_name().to_string(), image));
+        }
+        if sprites.is_empty() {
+            println!("[!] Warning: Atlas {} Matched No PNG Assets", config.name);
+            continue;
+        }
+
+        println!("[+] Building Atlas {} From {} Sprites...", config.name, sprites.len());
+        let (pages, atlas_rects) = build_atlas(config, sprites)?;
+        if !config.keep_sprites {
+            assets.retain(|asset| !selected(asset));
+        }
+        for page in pages {
+            println!("[+] Atlas Page {}: {}x{}", page.name, page.image.width, page.image.height);
+            let mut page_asset = Asset::from_data(&page.name, png.clone(), encode_png_rgba(&page.image)?);
+            page_asset.set_metadata("atlas", &config.name);
+            page_asset.set_group(group.clone());
+            page_asset.extract_metadata();
+            assets.push(page_asset);
+        }
+        rects.extend(atlas_rects);
+    }
+
+    Ok(rects)
+}
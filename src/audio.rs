@@ -0,0 +1,200 @@
+use serde::Deserialize;
+
+use crate::{Asset, AssetType};
+
+
+/**
+ * Audio Properties Read From The File Header, Recorded In The Manifest So Buffers Can Be Preallocated
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioInfo {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub bit_depth: u16, // Bits Per Sample, 0 For Lossy Formats (Vorbis) That Have None
+    pub duration_ms: u64,
+}
+
+
+impl AudioInfo {
+
+    /**
+     * Parse The Header Of A WAV, OGG Vorbis Or FLAC File, Returns None For Other Or Malformed Data
+     */
+    pub fn from_data(data:&[u8]) -> Option<Self> {
+        if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WAVE") {
+            wav_info(data)
+        } else if data.starts_with(b"OggS") {
+            ogg_vorbis_info(data)
+        } else if data.starts_with(b"fLaC") {
+            flac_info(data)
+        } else {
+            None
+        }
+    }
+}
+
+
+/**
+ * Duration In Milliseconds Of A Number Of Sample Frames
+ */
+fn frames_to_ms(frames:u64, sample_rate:u32) -> u64 {
+    if sample_rate == 0 {
+        return 0;
+    }
+    frames * 1000 / sample_rate as u64
+}
+
+
+/**
+ * Walks The RIFF Chunks For `fmt ` (Layout) And `data` (Length)
+ */
+fn wav_info(data:&[u8]) -> Option<AudioInfo> {
+    let mut fmt:Option<&[u8]> = None;
+    let mut data_size:Option<usize> = None;
+    let mut offset = 12;
+    while offset + 8 <= data.len() {
+        let size = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = offset + 8;
+        match &data[offset..offset + 4] {
+            b"fmt " => fmt = data.get(body..body + size),
+            b"data" => data_size = Some(size.min(data.len() - body)),
+            _ => {}
+        }
+        offset = body + size + (size & 1); // Chunks Are Padded To An Even Length
+    }
+
+    let fmt = fmt.filter(|fmt| fmt.len() >= 16)?;
+    let channels = u16::from_le_bytes(fmt[2..4].try_into().unwrap());
+    let sample_rate = u32::from_le_bytes(fmt[4..8].try_into().unwrap());
+    let block_align = u16::from_le_bytes(fmt[12..14].try_into().unwrap());
+    let bit_depth = u16::from_le_bytes(fmt[14..16].try_into().unwrap());
+    let frames = match block_align {
+        0 => 0,
+        _ => (data_size? / block_align as usize) as u64,
+    };
+    Some(AudioInfo { sample_rate, channels, bit_depth, duration_ms: frames_to_ms(frames, sample_rate) })
+}
+
+
+/**
+ * The First Page Holds The Vorbis Identification Header,
+ * The Granule Position Of The Last Page Is The Total Number Of Sample Frames
+ */
+fn ogg_vorbis_info(data:&[u8]) -> Option<AudioInfo> {
+    let mut identification:Option<&[u8]> = None;
+    let mut last_granule:u64 = 0;
+    let mut offset = 0;
+    while data.get(offset..offset + 4) == Some(b"OggS") {
+        let segment_count = *data.get(offset + 26)? as usize;
+        let segments = data.get(offset + 27..offset + 27 + segment_count)?;
+        let body = offset + 27 + segment_count;
+        let body_size:usize = segments.iter().map(|size| *size as usize).sum();
+        if identification.is_none() {
+            identification = data.get(body..body + body_size);
+        }
+        let granule = i64::from_le_bytes(data[offset + 6..offset + 14].try_into().unwrap());
+        if granule >= 0 {
+            last_granule = granule as u64;
+        }
+        offset = body + body_size;
+    }
+
+    let identification = identification.filter(|header| header.len() >= 16 && header.starts_with(b"\x01vorbis"))?;
+    let channels = identification[11] as u16;
+    let sample_rate = u32::from_le_bytes(identification[12..16].try_into().unwrap());
+    Some(AudioInfo { sample_rate, channels, bit_depth: 0, duration_ms: frames_to_ms(last_granule, sample_rate) })
+}
+
+
+/**
+ * STREAMINFO Is Always The First Metadata Block, Packed As
+ * 20 Bits Sample Rate, 3 Bits Channels - 1, 5 Bits Bits Per Sample - 1, 36 Bits Total Samples
+ */
+fn flac_info(data:&[u8]) -> Option<AudioInfo> {
+    if *data.get(4)? & 0x7F != 0 {
+        return None;
+    }
+    let packed = u64::from_be_bytes(data.get(18..26)?.try_into().unwrap());
+    let sample_rate = (packed >> 44) as u32;
+    let channels = ((packed >> 41) & 0x07) as u16 + 1;
+    let bit_depth = ((packed >> 36) & 0x1F) as u16 + 1;
+    let total_samples = packed & 0xF_FFFF_FFFF;
+    Some(AudioInfo { sample_rate, channels, bit_depth, duration_ms: frames_to_ms(total_samples, sample_rate) })
+}
+
+
+/**
+ * The Audio Format The Project Requires, From The `[audio]` Table Of The Pack Config:
+ *
+ * ```toml
+ * [audio]
+ * sample_rates = [44100, 48000]
+ * channels = [1, 2]
+ * bit_depths = [16]         # Only Checked For Formats That Have A Bit Depth
+ * max_duration_ms = 600000
+ * strict = true             # Fail The Pack Instead Of Warning
+ * ```
+ *
+ * Empty Lists Allow Anything.
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AudioRules {
+    pub sample_rates: Vec<u32>,
+    pub channels: Vec<u16>,
+    pub bit_depths: Vec<u16>,
+    pub max_duration_ms: Option<u64>,
+    pub strict: bool,
+}
+
+
+impl AudioRules {
+
+    /**
+     * List Every Way The Audio Breaks The Rules, Empty If It Conforms
+     */
+    pub fn check(&self, info:&AudioInfo) -> Vec<String> {
+        let mut problems = Vec::new();
+        if !self.sample_rates.is_empty() && !self.sample_rates.contains(&info.sample_rate) {
+            problems.push(format!("Sample Rate {} Hz Is Not One Of {:?}", info.sample_rate, self.sample_rates));
+        }
+        if !self.channels.is_empty() && !self.channels.contains(&info.channels) {
+            problems.push(format!("{} Channels Is Not One Of {:?}", info.channels, self.channels));
+        }
+        if info.bit_depth != 0 && !self.bit_depths.is_empty() && !self.bit_depths.contains(&info.bit_depth) {
+            problems.push(format!("Bit Depth {} Is Not One Of {:?}", info.bit_depth, self.bit_depths));
+        }
+        if let Some(max_duration_ms) = self.max_duration_ms {
+            if info.duration_ms > max_duration_ms {
+                problems.push(format!("Duration {} ms Is Longer Than {} ms", info.duration_ms, max_duration_ms));
+            }
+        }
+        problems
+    }
+}
+
+
+/**
+ * Check Every Audio Asset Against The Rules, Printing A Warning For Each Problem.
+ * Fails If The Rules Are Strict And Any Asset Broke Them.
+ */
+pub fn check_audio_rules(assets:&[Asset], rules:&AudioRules) -> Result<(), String> {
+    let mut failed:Vec<&str> = Vec::new();
+    for asset in assets.iter().filter(|asset| matches!(asset.get_type(), AssetType::Audio(_))) {
+        let problems = match asset.get_audio_info() {
+            Some(info) => rules.check(&info),
+            None => continue, // Formats We Can't Read The Header Of Aren't Checked
+        };
+        for problem in problems.iter() {
+            println!("[!] Warning: Audio {}: {}", asset.get_name(), problem);
+        }
+        if !problems.is_empty() {
+            failed.push(asset.get_name());
+        }
+    }
+
+    if rules.strict && !failed.is_empty() {
+        return Err(format!("Audio Does Not Match The Required Format: {}", failed.join(", ")));
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+
+/**
+ * Little Endian Writer For The Packed Binary Formats (Tilemaps, Meshes) And The Asset Server Protocol.
+ * Strings And Byte Blobs Are A u32 Length Followed By The Bytes, Maps Are A u32 Count Followed By Key-Value Strings.
+ */
+pub(crate) struct Writer(pub Vec<u8>);
+
+impl Writer {
+    pub fn u8(&mut self, value:u8) {
+        self.0.push(value);
+    }
+
+    pub fn u32(&mut self, value:u32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn f32(&mut self, value:f32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn string(&mut self, value:&str) {
+        self.u32(value.len() as u32);
+        self.0.extend_from_slice(value.as_bytes());
+    }
+
+    pub fn bytes(&mut self, value:&[u8]) {
+        self.u32(value.len() as u32);
+        self.0.extend_from_slice(value);
+    }
+
+    pub fn properties(&mut self, properties:&BTreeMap<String, String>) {
+        self.u32(properties.len() as u32);
+        for (key, value) in properties.iter() {
+            self.string(key);
+            self.string(value);
+        }
+    }
+}
+
+
+/**
+ * Reads Back What `Writer` Wrote, Failing Instead Of Panicking On Truncated Data
+ */
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data:&'a [u8]) -> Self {
+        Reader { data, offset: 0 }
+    }
+
+    pub fn take(&mut self, count:usize) -> Result<&'a [u8], String> {
+        let bytes = self.data.get(self.offset..self.offset.saturating_add(count)).ok_or("Packed Data Is Truncated")?;
+        self.offset += count;
+        Ok(bytes)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub fn f32(&mut self) -> Result<f32, String> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub fn string(&mut self) -> Result<String, String> {
+        let length = self.u32()? as usize;
+        String::from_utf8(self.take(length)?.to_vec()).map_err(|_| "Packed Data Has An Invalid String".to_string())
+    }
+
+    pub fn bytes(&mut self) -> Result<&'a [u8], String> {
+        let length = self.u32()? as usize;
+        self.take(length)
+    }
+
+    pub fn properties(&mut self) -> Result<BTreeMap<String, String>, String> {
+        let mut properties = BTreeMap::new();
+        for _ in 0..self.u32()? {
+            properties.insert(self.string()?, self.string()?);
+        }
+        Ok(properties)
+    }
+}
@@ -0,0 +1,161 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+
+use crate::{AnimationConfig, AssetKind, AssetRegistry, AudioRules, ScriptConfig};
+
+
+/**
+ * Pack Configuration, Read From A TOML File Such As:
+ *
+ * ```toml
+ * group_by_directory = true
+ *
+ * [types]
+ * event_script = "Script:LUA"
+ *
+ * [kinds]
+ * Dialogue = ["dlg", "dialogue"]
+ *
+ * [importers.png_metadata]
+ * keep = ["pHYs"]
+ *
+ * [importers.wav_pcm]
+ * enabled = false
+ *
+ * [importers.raw_texture]
+ * enabled = true
+ * format = "indexed"
+ *
+ * [[atlases]]
+ * name = "characters"
+ * sprites = ["*_idle", "sorcerer_sprite"]
+ *
+ * [animations.adept_necromancer_idle]
+ * frame_width = 16
+ * loop_mode = "ping_pong"
+ *
+ * [audio]
+ * sample_rates = [48000]
+ * strict = true
+ *
+ * [scripts]
+ * bytecode = "replace"
+ *
+ * [groups]
+ * town = ["town_*", "npc_*"]
+ * ```
+ */
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct PackConfig {
+    pub types: BTreeMap<String, String>, // Asset Name -> `Kind:FORMAT` Type Override
+    pub kinds: BTreeMap<String, Vec<String>>, // Custom Kind Name -> File Extensions
+    pub importers: BTreeMap<String, toml::Table>, // Importer Name -> Importer Settings
+    pub atlases: Vec<AtlasConfig>, // Sprite Atlases To Build From PNG Assets
+    pub animations: BTreeMap<String, AnimationConfig>, // Image Asset Name -> Animation Definition
+    pub audio: AudioRules, // Required Format Of Audio Assets
+    pub scripts: ScriptConfig, // Lua Bytecode Options, Scripts Are Always Syntax Checked
+    pub groups: BTreeMap<String, Vec<String>>, // Group Name -> Asset Names Or `*` Patterns Laid Out Together
+    pub group_by_directory: bool, // Assets In A Subdirectory Of The Input Are Grouped By Its Name
+}
+
+
+/**
+ * A Sprite Atlas To Build While Packing
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct AtlasConfig {
+    pub name: String, // Pages Are Stored As `<name>_page<n>` Assets
+    pub sprites: Vec<String>, // Asset Names Or `*` Patterns Of The PNGs To Pack
+    #[serde(default = "AtlasConfig::default_page_size")]
+    pub page_size: u32, // Maximum Width And Height Of A Page
+    #[serde(default = "AtlasConfig::default_padding")]
+    pub padding: u32, // Transparent Pixels Between Sprites, Stops Filtering Bleeding Into Neighbours
+    #[serde(default)]
+    pub keep_sprites: bool, // Also Keep The Individual Sprite Assets In The Chunk
+}
+
+
+impl AtlasConfig {
+
+    fn default_page_size() -> u32 {
+        1024
+    }
+
+    fn default_padding() -> u32 {
+        1
+    }
+}
+
+
+/**
+ * Match A Name Against A Pattern Where `*` Matches Any Run Of Characters
+ */
+pub fn matches_pattern(pattern:&str, name:&str) -> bool {
+    let parts:Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == name;
+    }
+
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !name.starts_with(first) || !name[first.len()..].ends_with(last) {
+        return false;
+    }
+    let mut rest = &name[first.len()..name.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+
+impl PackConfig {
+
+    /**
+     * Load A Pack Config From A TOML File
+     */
+    pub fn load(filepath:&str) -> Result<Self, String> {
+        let text = read_to_string(filepath).map_err(|e| format!("Failed To Read Pack Config {:?}: {}", filepath, e))?;
+        Self::from_toml(&text)
+    }
+
+    /**
+     * Parse A Pack Config From A TOML String
+     */
+    pub fn from_toml(text:&str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| format!("Failed To Parse Pack Config: {}", e))
+    }
+
+    /**
+     * Get The Settings Table Of An Importer, Empty If The Config Doesn't Mention It
+     */
+    pub fn importer_settings(&self, importer:&str) -> toml::Table {
+        self.importers.get(importer).cloned().unwrap_or_default()
+    }
+
+    /**
+     * Is An Importer Enabled, Its Settings Can Say `enabled = true / false` To Override The Default
+     */
+    pub fn importer_enabled(&self, importer:&str, default:bool) -> bool {
+        self.importers.get(importer)
+            .and_then(|settings| settings.get("enabled"))
+            .and_then(|enabled| enabled.as_bool())
+            .unwrap_or(default)
+    }
+
+    /**
+     * A Registry With The Custom Kinds Named In `[kinds]`, Identified By Their File Extensions
+     */
+    pub fn registry(&self) -> AssetRegistry {
+        let mut registry = AssetRegistry::new();
+        for (name, extensions) in self.kinds.iter() {
+            let extensions:Vec<&str> = extensions.iter().map(String::as_str).collect();
+            registry.register(AssetKind::new(name).with_extensions(&extensions));
+        }
+        registry
+    }
+}
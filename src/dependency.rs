@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::{Asset, AssetManifest, SpriteRect};
+
+
+/**
+ * Where An Asset Is While Walking The Dependency Graph
+ */
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Unvisited,
+    Visiting, // On The Current Path, Reaching It Again Is A Cycle
+    Done,
+}
+
+
+/**
+ * Order Assets So Every Asset Comes After Its Dependencies, Starting From `roots`.
+ * `nodes` Are (Name, Dependency Names) Pairs, The Result Holds Indices Into `nodes`.
+ * Assets Not Reachable From `roots` Are Left Out, Ties Keep The Order Of `nodes`.
+ */
+pub(crate) fn topological_order(nodes:&[(&str, Vec<&str>)], roots:impl IntoIterator<Item = usize>) -> Result<Vec<usize>, String> {
+    let lookup:HashMap<&str, usize> = nodes.iter().enumerate().rev().map(|(index, (name, _))| (*name, index)).collect();
+    let mut marks = vec![Mark::Unvisited; nodes.len()];
+    let mut order:Vec<usize> = Vec::new();
+
+    for root in roots {
+        if marks[root] != Mark::Unvisited {
+            continue;
+        }
+
+        // Depth First, Each Entry Is An Asset And How Many Of Its Dependencies Were Walked
+        let mut path:Vec<(usize, usize)> = vec![(root, 0)];
+        marks[root] = Mark::Visiting;
+        while let Some((index, next)) = path.last_mut() {
+            let (name, dependencies) = &nodes[*index];
+            let Some(dependency) = dependencies.get(*next) else {
+                marks[*index] = Mark::Done;
+                order.push(*index);
+                path.pop();
+                continue;
+            };
+            *next += 1;
+
+            let target = *lookup.get(dependency).ok_or(format!("Asset {} Depends On {} Which Is Not Being Packed", name, dependency))?;
+            match marks[target] {
+                Mark::Done => {},
+                Mark::Visiting => {
+                    let start = path.iter().position(|(index, _)| *index == target).unwrap_or(0);
+                    let cycle:Vec<&str> = path[start..].iter().map(|(index, _)| nodes[*index].0).chain([nodes[target].0]).collect();
+                    return Err(format!("Dependency Cycle: {}", cycle.join(" -> ")));
+                },
+                Mark::Unvisited => {
+                    marks[target] = Mark::Visiting;
+                    path.push((target, 0));
+                },
+            }
+        }
+    }
+    Ok(order)
+}
+
+
+/**
+ * The Dependency Graph Of A List Of Assets
+ */
+fn asset_nodes(assets:&[Asset]) -> Vec<(&str, Vec<&str>)> {
+    assets.iter()
+        .map(|asset| (asset.get_name(), asset.get_dependencies().iter().map(String::as_str).collect()))
+        .collect()
+}
+
+
+/**
+ * Reorder Assets So Each One Comes After Everything It Depends On, Which Makes The Manifest Order A Valid Load Order.
+ * Fails If An Asset Depends On One That Isn't Being Packed Or The Dependencies Form A Cycle.
+ */
+pub fn sort_assets_by_dependencies(assets:&mut Vec<Asset>) -> Result<(), String> {
+    let order = topological_order(&asset_nodes(assets), 0..assets.len())?;
+    let mut slots:Vec<Option<Asset>> = assets.drain(..).map(Some).collect();
+    assets.extend(order.into_iter().filter_map(|index| slots[index].take()));
+    Ok(())
+}
+
+
+/**
+ * Point Dependencies On Sprites That Were Packed Into An Atlas At The Atlas Page Holding Them
+ */
+pub fn remap_dependencies_to_atlas(assets:&mut [Asset], sprites:&[SpriteRect]) {
+    let names:Vec<String> = assets.iter().map(|asset| asset.get_name().to_string()).collect();
+    for asset in assets.iter_mut() {
+        let dependencies = asset.get_dependencies().clone();
+        asset.set_dependencies(Vec::new());
+        for dependency in dependencies {
+            match sprites.iter().find(|sprite| sprite.name == dependency && !names.contains(&dependency)) {
+                Some(sprite) => asset.add_dependency(&sprite.atlas),
+                None => asset.add_dependency(&dependency),
+            }
+        }
+    }
+}
+
+
+impl AssetManifest {
+
+    /**
+     * Get Everything An Asset Depends On, Directly Or Not, In The Order It Should Be Loaded.
+     * The Asset Itself Is Not Included.
+     */
+    pub fn get_dependency_closure(&self, name:&str) -> Result<Vec<&Asset>, String> {
+        let mut order = self.get_load_order_for(name)?;
+        order.pop();
+        Ok(order)
+    }
+
+
+    /**
+     * Get An Asset And Everything It Depends On, Dependencies First
+     */
+    pub fn get_load_order_for(&self, name:&str) -> Result<Vec<&Asset>, String> {
+        let root = self.get_assets().iter().position(|asset| asset.get_name() == name).ok_or(format!("No Asset Named {}", name))?;
+        let order = topological_order(&asset_nodes(self.get_assets()), [root])?;
+        Ok(order.into_iter().map(|index| &self.get_assets()[index]).collect())
+    }
+
+
+    /**
+     * Get Every Asset In The Order It Should Be Loaded, Dependencies First
+     */
+    pub fn get_load_order(&self) -> Result<Vec<&Asset>, String> {
+        let order = topological_order(&asset_nodes(self.get_assets()), 0..self.get_assets().len())?;
+        Ok(order.into_iter().map(|index| &self.get_assets()[index]).collect())
+    }
+}
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::dependency::topological_order;
+use crate::{matches_pattern, sort_assets_by_dependencies, Asset, AssetManifest, LoadedChunk};
+
+
+/**
+ * A Named Run Of Assets Laid Out Back To Back In The Chunk, So It Can Be Loaded With One Read
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AssetGroup {
+    pub name: String,
+    pub offset: usize, // Where The Group Starts In The Chunk
+    pub size: usize, // Bytes From `offset` To The End Of The Last Asset
+    pub assets: Vec<String>, // Names Of The Assets In The Group, In Chunk Order
+}
+
+
+/**
+ * Put Assets Into The Groups Whose Patterns Match Their Names.
+ * Config Groups Win Over Groups From Directories, An Asset Matching Two Config Groups Is An Error.
+ * Returns How Many Assets Were Assigned.
+ */
+pub fn assign_groups(assets:&mut [Asset], groups:&BTreeMap<String, Vec<String>>) -> Result<usize, String> {
+    let mut count = 0;
+    for asset in assets.iter_mut() {
+        let matched:Vec<&String> = groups.iter()
+            .filter(|(_, patterns)| patterns.iter().any(|pattern| matches_pattern(pattern, asset.get_name())))
+            .map(|(name, _)| name)
+            .collect();
+        match matched.as_slice() {
+            [] => {},
+            [group] => {
+                asset.set_group(Some(group.to_string()));
+                count += 1;
+            },
+            _ => return Err(format!("Asset {} Matches More Than One Group: {:?}", asset.get_name(), matched)),
+        }
+    }
+    Ok(count)
+}
+
+
+/**
+ * What An Asset Is Laid Out As Part Of, Its Group Or Just Itself When It Isn't In One
+ */
+fn layout_unit(asset:&Asset) -> String {
+    match asset.get_group() {
+        Some(group) => format!("group {}", group),
+        None => asset.get_name().to_string(),
+    }
+}
+
+
+/**
+ * Lay Assets Out Group By Group So Each Group Can Be Loaded With One Read, Keeping The Manifest A Valid Load Order.
+ * Assets Are Sorted By Dependencies, Then Whole Groups Are Put After The Groups (And Ungrouped Assets) They Depend On.
+ * Where Nothing Depends Between Them, Ungrouped Assets Come First And Groups Follow By Name.
+ * Fails If Groups Depend On Each Other, No Layout Keeps Them Contiguous And In Load Order.
+ */
+pub fn sort_assets_by_group(assets:&mut Vec<Asset>) -> Result<(), String> {
+    sort_assets_by_dependencies(assets)?;
+
+    let mut units:Vec<String> = assets.iter().filter(|asset| asset.get_group().is_none()).map(layout_unit).collect();
+    units.extend(assets.iter().filter(|asset| asset.get_group().is_some()).map(layout_unit).collect::<BTreeSet<String>>());
+    let index:HashMap<&str, usize> = units.iter().enumerate().map(|(index, unit)| (unit.as_str(), index)).collect();
+    let unit_of:HashMap<&str, String> = assets.iter().map(|asset| (asset.get_name(), layout_unit(asset))).collect();
+
+    // A Unit Depends On The Units Holding Its Assets' Dependencies
+    let mut nodes:Vec<(&str, Vec<&str>)> = units.iter().map(|unit| (unit.as_str(), Vec::new())).collect();
+    for asset in assets.iter() {
+        let unit = index[unit_of[asset.get_name()].as_str()];
+        for dependency in asset.get_dependencies() {
+            let target = unit_of[dependency.as_str()].as_str();
+            if target != units[unit] && !nodes[unit].1.contains(&target) {
+                nodes[unit].1.push(target);
+            }
+        }
+    }
+
+    let order = topological_order(&nodes, 0..nodes.len()).map_err(|e| format!("Groups Can't Be Laid Out In Load Order, {}", e))?;
+    let mut rank = vec![0; order.len()];
+    for (position, unit) in order.into_iter().enumerate() {
+        rank[unit] = position;
+    }
+    assets.sort_by_cached_key(|asset| rank[index[layout_unit(asset).as_str()]]);
+    Ok(())
+}
+
+
+/**
+ * Work Out The Byte Range Of Every Group From Where Its Assets Were Packed.
+ * Fails If A Group's Assets Aren't Back To Back.
+ */
+pub fn group_ranges(assets:&[Asset]) -> Result<Vec<AssetGroup>, String> {
+    let mut groups:Vec<AssetGroup> = Vec::new();
+    for asset in assets.iter() {
+        let Some(name) = asset.get_group() else {
+            continue;
+        };
+        let end = asset.get_chunk_location() + asset.get_size();
+        match groups.iter_mut().find(|group| group.name == *name) {
+            Some(group) if group.offset + group.size == asset.get_chunk_location() => {
+                group.size = end - group.offset;
+                group.assets.push(asset.get_name().to_string());
+            },
+            Some(_) => return Err(format!("Asset {} Is Not Packed Next To The Rest Of Group {}", asset.get_name(), name)),
+            None => groups.push(AssetGroup {
+                name: name.clone(),
+                offset: asset.get_chunk_location(),
+                size: asset.get_size(),
+                assets: vec![asset.get_name().to_string()],
+            }),
+        }
+    }
+    Ok(groups)
+}
+
+
+impl AssetManifest {
+
+    /**
+     * Read Only The Byte Range Of A Group Out Of The Chunk File, Into One Contiguous Block
+     */
+    pub fn load_asset_group(&self, chunk_path:&str, name:&str) -> Result<LoadedChunk, String> {
+        let group = self.get_group(name).ok_or(format!("No Asset Group Named {}", name))?;
+        self.load_group_range(chunk_path, group)
+    }
+
+
+    /**
+     * Read A Group's Byte Range, The Group Doesn't Have To Be In The Manifest (e.g. A Single Asset)
+     */
+    pub(crate) fn load_group_range(&self, chunk_path:&str, group:&AssetGroup) -> Result<LoadedChunk, String> {
+        let mut file = File::open(chunk_path).map_err(|e| format!("Failed To Open Chunk {}: {}", chunk_path, e))?;
+        let mut data = vec![0; group.size];
+        file.seek(SeekFrom::Start(group.offset as u64)).map_err(|e| e.to_string())?;
+        file.read_exact(&mut data).map_err(|e| format!("Failed To Read Group {}: {}", group.name, e))?;
+
+        Ok(LoadedChunk { manifest: self.group_manifest(group), data })
+    }
+
+
+    /**
+     * A Manifest Of Just A Group's Assets With Locations Relative To The Group, Along With
+     * The Sprites On Its Atlas Pages And The Animations Whose Frames Are All In It
+     */
+    fn group_manifest(&self, group:&AssetGroup) -> AssetManifest {
+        let in_group = |name:&str| group.assets.iter().any(|asset| asset == name);
+        let assets:Vec<Asset> = self.get_assets().iter()
+            .filter(|asset| in_group(asset.get_name()))
+            .map(|asset| {
+                let mut asset = asset.clone();
+                asset.set_chunk_location(asset.get_chunk_location() - group.offset);
+                asset
+            })
+            .collect();
+
+        let mut manifest = AssetManifest::new(assets);
+        manifest.set_sprites(self.get_sprites().iter().filter(|sprite| in_group(&sprite.atlas)).cloned().collect());
+        manifest.set_animations(self.get_animations().iter()
+            .filter(|animation| animation.frames.iter().all(|frame| in_group(&frame.image)))
+            .cloned()
+            .collect());
+        manifest.set_groups(vec![AssetGroup { offset: 0, ..group.clone() }]);
+        manifest
+    }
+}
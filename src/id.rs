@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{Asset, AssetManifest};
+
+
+/**
+ * A Numeric Handle To An Asset, Cheaper To Look Up Than Its Name.
+ * It Is The FNV-1a Hash Of The Name, So It Stays The Same Between Packs As Long As The Name Does.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[serde(transparent)]
+pub struct AssetId(pub u32);
+
+
+impl AssetId {
+
+    /**
+     * Never The ID Of An Asset, Also What Manifests From Before IDs Hold
+     */
+    pub const INVALID:AssetId = AssetId(0);
+
+
+    /**
+     * Get The ID An Asset Named `name` Has
+     */
+    pub fn from_name(name:&str) -> Self {
+        let mut hash:u32 = 0x811c9dc5;
+        for byte in name.as_bytes() {
+            hash ^= *byte as u32;
+            hash = hash.wrapping_mul(0x01000193);
+        }
+        AssetId(hash)
+    }
+
+
+    /**
+     * Check The ID Isn't `INVALID`
+     */
+    pub fn is_valid(&self) -> bool {
+        *self != AssetId::INVALID
+    }
+}
+
+
+impl fmt::Display for AssetId {
+    fn fmt(&self, f:&mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:08x}", self.0)
+    }
+}
+
+
+/**
+ * Check No Two Assets Share An ID (Or Hash To `INVALID`), Renaming One Of Them Fixes It
+ */
+pub fn check_asset_ids(assets:&[Asset]) -> Result<(), String> {
+    let mut seen:HashMap<AssetId, &str> = HashMap::new();
+    for asset in assets.iter() {
+        if !asset.get_id().is_valid() {
+            return Err(format!("Asset {} Hashes To The Invalid Asset ID, Rename It", asset.get_name()));
+        }
+        if let Some(other) = seen.insert(asset.get_id(), asset.get_name()) {
+            return Err(format!("Assets {} And {} Share The Asset ID {}, Rename One Of Them", other, asset.get_name(), asset.get_id()));
+        }
+    }
+    Ok(())
+}
+
+
+/**
+ * Name Of The Constant Generated For An Asset, e.g. `tileset/terrain1` -> `ASSET_TILESET_TERRAIN1`
+ */
+pub fn asset_constant_name(name:&str) -> String {
+    let name:String = name.chars().map(|c| match c.is_ascii_alphanumeric() {
+        true => c.to_ascii_uppercase(),
+        false => '_',
+    }).collect();
+    format!("ASSET_{}", name)
+}
+
+
+/**
+ * The Constants To Generate, Sorted By Name. Fails If Two Asset Names Make The Same Constant.
+ */
+fn asset_constants(manifest:&AssetManifest) -> Result<Vec<(String, &Asset)>, String> {
+    let mut constants:Vec<(String, &Asset)> = manifest.get_assets().iter().map(|asset| (asset_constant_name(asset.get_name()), asset)).collect();
+    constants.sort_by(|a, b| a.0.cmp(&b.0));
+    if let Some(pair) = constants.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        return Err(format!("Assets {} And {} Both Generate The Constant {}, Rename One Of Them", pair[0].1.get_name(), pair[1].1.get_name(), pair[0].0));
+    }
+    Ok(constants)
+}
+
+
+/**
+ * Generate A Rust Module With An `AssetId` Constant For Every Asset In A Manifest
+ */
+pub fn generate_rust_ids(manifest:&AssetManifest) -> Result<String, String> {
+    let mut source = String::from("// Generated By assetchunk, Do Not Edit\n\nuse assetchunk::AssetId;\n\n");
+    for (constant, asset) in asset_constants(manifest)? {
+        source.push_str(&format!("/// `{}` ({}:{})\npub const {}:AssetId = AssetId({});\n", asset.get_name(), asset.get_type().kind_name(), asset.get_type().format(), constant, asset.get_id()));
+    }
+    Ok(source)
+}
+
+
+/**
+ * Generate A C Header With A `uint32_t` Constant For Every Asset In A Manifest
+ */
+pub fn generate_c_ids(manifest:&AssetManifest) -> Result<String, String> {
+    let mut source = String::from("// Generated By assetchunk, Do Not Edit\n\n#pragma once\n\n#include <stdint.h>\n\n");
+    for (constant, asset) in asset_constants(manifest)? {
+        source.push_str(&format!("/// `{}` ({}:{})\n#define {} ((uint32_t){}u)\n", asset.get_name(), asset.get_type().kind_name(), asset.get_type().format(), constant, asset.get_id()));
+    }
+    Ok(source)
+}
@@ -0,0 +1,235 @@
+use std::path::Path;
+
+use crate::{identify_asset, AssetType};
+
+
+/**
+ * Which Layer Of The Detector Produced An Identification
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentificationSource {
+    Extension, // Mapped From The File Extension Alone
+    Content, // Matched By A Content Heuristic (Shebang, #version, Keywords, ...)
+    ExtensionAndContent, // Extension And Content Heuristics Agree
+    Signature, // Fell Back To The file_format Magic Number Detection
+}
+
+
+/**
+ * The Result Of Running The Layered Asset Detector
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identification {
+    pub asset_type: AssetType,
+    pub confidence: f32, // 0.0 (Pure Guess) To 1.0 (Certain)
+    pub source: IdentificationSource,
+}
+
+
+/**
+ * Identify An Asset Using Every Layer Of The Detector.
+ * ---
+ * 1. The file extension (if a filename is given) is mapped to a type.
+ * 2. Text content is sniffed for shebangs, GLSL `#version` directives, Lua keywords,
+ *    Wavefront OBJ statements and JSON/TOML/XML documents.
+ * 3. If neither layer has an answer the `file_format` signature detection is used.
+ *
+ * When the extension and content disagree, a strong content match wins over the extension,
+ * otherwise the extension is trusted with a reduced confidence. Binary formats are checked
+ * against their `file_format` signature instead.
+ */
+pub fn identify(filename:Option<&str>, data:&[u8]) -> Identification {
+    let by_extension = filename.and_then(identify_by_extension);
+    let by_content = identify_by_content(data);
+
+    match (by_extension, by_content) {
+        (Some(ext_type), Some((content_type, _))) if ext_type == content_type => Identification {
+            asset_type: ext_type,
+            confidence: 1.0,
+            source: IdentificationSource::ExtensionAndContent,
+        },
+        // Custom Kinds Like Tiled Maps Are Built On JSON / XML, The Container Doesn't Contradict Them
+        (Some(ext_type @ AssetType::Custom { .. }), Some((AssetType::Other(_), _))) => Identification {
+            asset_type: ext_type,
+            confidence: 0.9,
+            source: IdentificationSource::ExtensionAndContent,
+        },
+        (Some(_), Some((content_type, confidence))) if confidence >= 0.9 => Identification {
+            asset_type: content_type,
+            confidence: confidence - 0.2,
+            source: IdentificationSource::Content,
+        },
+        (Some(ext_type), Some(_)) => Identification {
+            asset_type: ext_type,
+            confidence: 0.5,
+            source: IdentificationSource::Extension,
+        },
+        (Some(ext_type), None) => {
+            // Binary Formats Have No Text Heuristics, Let The Signature Confirm Or Contradict The Extension
+            let signature_type = identify_asset(data);
+            if signature_type == ext_type {
+                Identification {
+                    asset_type: ext_type,
+                    confidence: 1.0,
+                    source: IdentificationSource::ExtensionAndContent,
+                }
+            } else if signature_type.is_identified() && !matches!(signature_type, AssetType::Other(_)) {
+                Identification {
+                    asset_type: signature_type,
+                    confidence: 0.8,
+                    source: IdentificationSource::Signature,
+                }
+            } else {
+                Identification {
+                    asset_type: ext_type,
+                    confidence: 0.75,
+                    source: IdentificationSource::Extension,
+                }
+            }
+        },
+        (None, Some((content_type, confidence))) => Identification {
+            asset_type: content_type,
+            confidence,
+            source: IdentificationSource::Content,
+        },
+        (None, None) => {
+            let asset_type = identify_asset(data);
+            let confidence = match &asset_type {
+                AssetType::Other(name) if name == "UNIDENTIFIED" => 0.0,
+                AssetType::Other(_) => 0.3,
+                _ => 0.9,
+            };
+            Identification {
+                asset_type,
+                confidence,
+                source: IdentificationSource::Signature,
+            }
+        }
+    }
+}
+
+
+/**
+ * Map A File Extension To An Asset Type
+ */
+pub fn identify_by_extension(filename:&str) -> Option<AssetType> {
+    let extension = Path::new(filename).extension()?.to_str()?.to_ascii_lowercase();
+    let asset_type = match extension.as_str() {
+        "png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "ico" | "tiff" | "psd" | "svg" => AssetType::Image(extension.to_ascii_uppercase()),
+        "tif" => AssetType::Image("TIFF".to_string()),
+
+        "mp3" | "wav" | "aiff" | "flac" | "ogg" | "midi" | "aac" | "wma" => AssetType::Audio(extension.to_ascii_uppercase()),
+        "mid" => AssetType::Audio("MIDI".to_string()),
+
+        "ttf" | "otf" | "woff" | "woff2" | "eot" => AssetType::Font(extension.to_ascii_uppercase()),
+
+        "glsl" | "vert" | "frag" | "geom" | "comp" | "tesc" | "tese" | "vs" | "fs" => AssetType::Shader("GLSL".to_string()),
+        "hlsl" | "cgfx" | "fx" | "shader" => AssetType::Shader(extension.to_ascii_uppercase()),
+
+        "obj" | "fbx" | "3ds" | "c4d" | "blend" | "max" => AssetType::Model(extension.to_ascii_uppercase()),
+
+        "lua" | "js" | "py" | "vb" | "script" => AssetType::Script(extension.to_ascii_uppercase()),
+
+        "json" | "toml" | "xml" | "mtl" => AssetType::Other(extension.to_ascii_uppercase()),
+
+        // Tiled Maps And Tilesets, `.tsx` Here Is Never TypeScript
+        "tmx" | "tmj" => AssetType::Custom { kind: "Tilemap".to_string(), format: extension.to_ascii_uppercase() },
+        "tsx" | "tsj" => AssetType::Custom { kind: "Tileset".to_string(), format: extension.to_ascii_uppercase() },
+        _ => return None,
+    };
+    Some(asset_type)
+}
+
+
+/**
+ * Sniff Text Content For Known Formats.
+ * Returns The Detected Type Along With How Confident The Heuristic Is.
+ */
+pub fn identify_by_content(data:&[u8]) -> Option<(AssetType, f32)> {
+    let text = std::str::from_utf8(data).ok()?;
+    let trimmed = text.trim_start_matches('\u{feff}').trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    // Shebangs Name The Interpreter Outright
+    if let Some(shebang) = trimmed.strip_prefix("#!") {
+        let interpreter = shebang.lines().next().unwrap_or("");
+        if interpreter.contains("lua") {
+            return Some((AssetType::Script("LUA".to_string()), 0.95));
+        } else if interpreter.contains("python") {
+            return Some((AssetType::Script("PY".to_string()), 0.95));
+        } else if interpreter.contains("node") {
+            return Some((AssetType::Script("JS".to_string()), 0.95));
+        }
+    }
+
+    // GLSL Requires #version To Be The First Directive In The Shader
+    let first_code_line = trimmed.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("//"));
+    if first_code_line.is_some_and(|line| line.starts_with("#version")) {
+        return Some((AssetType::Shader("GLSL".to_string()), 0.95));
+    }
+
+    if (trimmed.starts_with('{') || trimmed.starts_with('[')) && serde_json::from_str::<serde_json::Value>(trimmed).is_ok() {
+        return Some((AssetType::Other("JSON".to_string()), 0.95));
+    }
+
+    if trimmed.starts_with("<?xml") {
+        return Some((AssetType::Other("XML".to_string()), 0.9));
+    }
+
+    if looks_like_obj(trimmed) {
+        return Some((AssetType::Model("OBJ".to_string()), 0.8));
+    }
+
+    let lua_score = lua_keyword_score(trimmed);
+    if lua_score >= 3 {
+        return Some((AssetType::Script("LUA".to_string()), 0.85));
+    } else if lua_score == 2 {
+        return Some((AssetType::Script("LUA".to_string()), 0.6));
+    }
+
+    if trimmed.contains('=') && toml::from_str::<toml::Table>(trimmed).is_ok() {
+        return Some((AssetType::Other("TOML".to_string()), 0.7));
+    }
+
+    None
+}
+
+
+/**
+ * Wavefront OBJ Files Are Made Of Lines Starting With Short Statement Keywords
+ */
+fn looks_like_obj(text:&str) -> bool {
+    let mut vertices = 0;
+    let mut faces = 0;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_whitespace().next() {
+            Some("v") | Some("vn") | Some("vt") => vertices += 1,
+            Some("f") => faces += 1,
+            Some("o") | Some("g") | Some("s") | Some("usemtl") | Some("mtllib") => {},
+            _ => return false,
+        }
+    }
+    vertices > 0 && faces > 0
+}
+
+
+/**
+ * Count How Many Distinct Lua-Only Constructs Appear In The Text
+ */
+fn lua_keyword_score(text:&str) -> usize {
+    let markers = ["local function", "local ", "elseif", "then", "end", "--", "require(", "nil", "~="];
+    let words:Vec<&str> = text.split(|c:char| !(c.is_alphanumeric() || c == '_')).collect();
+    markers.iter().filter(|marker| {
+        match **marker {
+            "then" | "end" | "nil" | "elseif" => words.contains(marker),
+            _ => text.contains(*marker),
+        }
+    }).count()
+}
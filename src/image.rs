@@ -0,0 +1,226 @@
+use png::{BitDepth, ColorType, Decoder, Encoder, Transformations};
+
+
+/**
+ * A Decoded Image With 8 Bit RGBA Pixels, Rows Stored Top To Bottom
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>, // width * height * 4 Bytes
+}
+
+
+impl RgbaImage {
+
+    /**
+     * Create A Fully Transparent Image
+     */
+    pub fn new(width:u32, height:u32) -> Self {
+        RgbaImage {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /**
+     * Copy Another Image Into This One With Its Top Left Corner At (x, y)
+     */
+    pub fn blit(&mut self, source:&RgbaImage, x:u32, y:u32) {
+        let row_bytes = source.width as usize * 4;
+        for row in 0..source.height as usize {
+            let source_start = row * row_bytes;
+            let dest_start = ((y as usize + row) * self.width as usize + x as usize) * 4;
+            self.pixels[dest_start..dest_start + row_bytes].copy_from_slice(&source.pixels[source_start..source_start + row_bytes]);
+        }
+    }
+}
+
+
+/**
+ * Read The Width And Height From A PNG's IHDR Chunk Without Decoding It, None If Either Is Zero (Not A Valid PNG)
+ */
+pub fn png_dimensions(data:&[u8]) -> Option<(u32, u32)> {
+    if data.len() < 24 || !data.starts_with(b"\x89PNG\r\n\x1a\n") || &data[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(data[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(data[20..24].try_into().unwrap());
+    if width == 0 || height == 0 {
+        return None;
+    }
+    Some((width, height))
+}
+
+
+/**
+ * Decode A PNG (Any Color Type Or Bit Depth) Into 8 Bit RGBA
+ */
+pub fn decode_png_rgba(data:&[u8]) -> Result<RgbaImage, String> {
+    let mut decoder = Decoder::new(data);
+    decoder.set_transformations(Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|e| format!("Failed To Read PNG Header: {}", e))?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut buffer).map_err(|e| format!("Failed To Decode PNG: {}", e))?;
+    buffer.truncate(frame.buffer_size());
+
+    let pixels = match frame.color_type {
+        ColorType::Rgba => buffer,
+        ColorType::Rgb => buffer.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+        ColorType::GrayscaleAlpha => buffer.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        ColorType::Grayscale => buffer.iter().flat_map(|p| [*p, *p, *p, 255]).collect(),
+        ColorType::Indexed => return Err("PNG Palette Was Not Expanded".to_string()),
+    };
+
+    Ok(RgbaImage {
+        width: frame.width,
+        height: frame.height,
+        pixels,
+    })
+}
+
+
+/**
+ * Encode An RGBA Image As A PNG
+ */
+pub fn encode_png_rgba(image:&RgbaImage) -> Result<Vec<u8>, String> {
+    let mut output = Vec::new();
+    let mut encoder = Encoder::new(&mut output, image.width, image.height);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| format!("Failed To Write PNG Header: {}", e))?;
+    writer.write_image_data(&image.pixels).map_err(|e| format!("Failed To Encode PNG: {}", e))?;
+    writer.finish().map_err(|e| format!("Failed To Finish PNG: {}", e))?;
+    Ok(output)
+}
+
+
+/**
+ * Image Properties Read From The File Header, Recorded In The Manifest So The Engine Doesn't Have To Decode
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub width: u32,
+    pub height: u32,
+    pub bit_depth: u8, // Bits Per Channel For PNG, Bits Per Pixel For BMP, Bits Per Palette Index For GIF
+    pub color_type: String, // "grayscale", "grayscale_alpha", "rgb", "rgba" Or "indexed"
+    pub frame_count: u32, // 1 Unless The Image Is An APNG Or Animated GIF
+}
+
+
+impl ImageInfo {
+
+    /**
+     * Parse The Header Of A PNG, BMP Or GIF, Returns None For Other Or Malformed Data
+     */
+    pub fn from_data(data:&[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            png_info(data)
+        } else if data.starts_with(b"BM") {
+            bmp_info(data)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            gif_info(data)
+        } else {
+            None
+        }
+    }
+}
+
+
+/**
+ * IHDR Gives The Size And Pixel Layout, An acTL Chunk (APNG) Gives The Frame Count
+ */
+fn png_info(data:&[u8]) -> Option<ImageInfo> {
+    let (width, height) = png_dimensions(data)?;
+    let bit_depth = *data.get(24)?;
+    let color_type = match *data.get(25)? {
+        0 => "grayscale",
+        2 => "rgb",
+        3 => "indexed",
+        4 => "grayscale_alpha",
+        6 => "rgba",
+        _ => return None,
+    };
+
+    let mut frame_count = 1;
+    let mut offset = 8;
+    while offset + 8 <= data.len() {
+        let length = u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let chunk_type = &data[offset + 4..offset + 8];
+        if chunk_type == b"acTL" && offset + 12 <= data.len() {
+            frame_count = u32::from_be_bytes(data[offset + 8..offset + 12].try_into().unwrap());
+            break;
+        }
+        if chunk_type == b"IDAT" || chunk_type == b"IEND" {
+            break; // acTL Must Come Before The Image Data
+        }
+        offset += 12 + length;
+    }
+
+    Some(ImageInfo { width, height, bit_depth, color_type: color_type.to_string(), frame_count })
+}
+
+
+/**
+ * Reads The BITMAPINFOHEADER (Or The Older BITMAPCOREHEADER), Bottom Up Images Have A Negative Height
+ */
+fn bmp_info(data:&[u8]) -> Option<ImageInfo> {
+    let header_size = u32::from_le_bytes(data.get(14..18)?.try_into().unwrap());
+    let (width, height, bit_depth) = if header_size == 12 {
+        let width = u16::from_le_bytes(data.get(18..20)?.try_into().unwrap()) as u32;
+        let height = u16::from_le_bytes(data.get(20..22)?.try_into().unwrap()) as u32;
+        (width, height, u16::from_le_bytes(data.get(24..26)?.try_into().unwrap()))
+    } else {
+        let width = i32::from_le_bytes(data.get(18..22)?.try_into().unwrap()).unsigned_abs();
+        let height = i32::from_le_bytes(data.get(22..26)?.try_into().unwrap()).unsigned_abs();
+        (width, height, u16::from_le_bytes(data.get(28..30)?.try_into().unwrap()))
+    };
+    let color_type = match bit_depth {
+        1 | 2 | 4 | 8 => "indexed",
+        16 | 24 => "rgb",
+        32 => "rgba",
+        _ => return None,
+    };
+    Some(ImageInfo { width, height, bit_depth: bit_depth as u8, color_type: color_type.to_string(), frame_count: 1 })
+}
+
+
+/**
+ * Reads The Logical Screen Descriptor, Then Walks The Blocks Counting Image Descriptors As Frames
+ */
+fn gif_info(data:&[u8]) -> Option<ImageInfo> {
+    let width = u16::from_le_bytes(data.get(6..8)?.try_into().unwrap()) as u32;
+    let height = u16::from_le_bytes(data.get(8..10)?.try_into().unwrap()) as u32;
+    let packed = *data.get(10)?;
+    let bit_depth = (packed & 0x07) + 1;
+
+    let color_table_size = |packed:u8| if packed & 0x80 != 0 { 3 << ((packed & 0x07) + 1) } else { 0 };
+    // Skips A Run Of Data Sub-Blocks, Returning The Offset After The Terminator
+    let skip_sub_blocks = |mut offset:usize| -> Option<usize> {
+        loop {
+            let size = *data.get(offset)? as usize;
+            offset += 1 + size;
+            if size == 0 {
+                return Some(offset);
+            }
+        }
+    };
+
+    let mut frame_count = 0;
+    let mut offset = 13 + color_table_size(packed);
+    loop {
+        match data.get(offset) {
+            Some(0x21) => offset = skip_sub_blocks(offset + 2)?, // Extension: Introducer, Label, Sub-Blocks
+            Some(0x2C) => {
+                frame_count += 1;
+                let local_packed = *data.get(offset + 9)?;
+                offset = skip_sub_blocks(offset + 10 + color_table_size(local_packed) + 1)?; // Descriptor, Local Table, LZW Code Size
+            },
+            _ => break, // Trailer, Or A Truncated File
+        }
+    }
+
+    Some(ImageInfo { width, height, bit_depth, color_type: "indexed".to_string(), frame_count: frame_count.max(1) })
+}
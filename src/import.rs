@@ -0,0 +1,537 @@
+use serde::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::image::decode_png_rgba;
+use crate::{asset_name_of, compile_scripts, extension_of, import_meshes, import_tilemaps, scan_script_dependencies, Asset, AssetRegistry, AssetType, PackConfig};
+
+
+/**
+ * Transforms Source Data Into Runtime Ready Data While Packing
+ */
+pub trait Importer: Send + Sync {
+
+    /**
+     * Unique Name Of The Importer, Also The Key Of Its Settings In The Pack Config
+     */
+    fn name(&self) -> &str;
+
+    /**
+     * Bump Whenever The Importer Produces Different Output For The Same Input,
+     * Cached Imports Made By Another Version Are Thrown Away
+     */
+    fn version(&self) -> u32;
+
+    /**
+     * Whether The Importer Runs When The Pack Config Doesn't Set `enabled` For It
+     */
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+
+    /**
+     * Import The Asset In Place, Replacing Its Data (And Type Or Metadata If Needed)
+     */
+    fn import(&self, asset:&mut Asset, settings:&toml::Table) -> Result<(), String>;
+}
+
+
+/**
+ * An Importer That Ran On An Asset, As Stored In The Manifest
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ImporterStamp {
+    pub name: String,
+    pub version: u32,
+    pub settings_hash: u64,
+}
+
+
+/**
+ * How An Asset Was Imported, Used To Decide Whether A Previous Import Can Be Reused
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ImportRecord {
+    pub source_hash: u64, // Hash Of The Source File Before Importing
+    pub importers: Vec<ImporterStamp>, // Importers That Ran, In Order
+}
+
+
+/**
+ * Importers Registered By Asset Type
+ */
+#[derive(Default)]
+pub struct ImportPipeline {
+    importers: Vec<(String, Box<dyn Importer>)>, // (Type Pattern, Importer)
+}
+
+
+impl ImportPipeline {
+
+    /**
+     * Create A Pipeline With No Importers, Assets Are Packed Verbatim
+     */
+    pub fn new() -> Self {
+        ImportPipeline {
+            importers: Vec::new(),
+        }
+    }
+
+    /**
+     * Create A Pipeline With The Built-In Importers Registered
+     */
+    pub fn with_defaults() -> Self {
+        let mut pipeline = Self::new();
+        pipeline.register("Image:PNG", Box::new(PngMetadataImporter));
+        pipeline.register("Script", Box::new(LineEndingImporter));
+        pipeline.register("Shader", Box::new(LineEndingImporter));
+        pipeline.register("Audio:WAV", Box::new(WavPcmImporter));
+        pipeline.register("Image:PNG", Box::new(RawTextureImporter));
+        pipeline
+    }
+
+    /**
+     * Register An Importer For A Type, Given Either As A Kind (`Script`) Or `Kind:FORMAT` (`Image:PNG`).
+     * Several Importers Can Be Registered For One Type, They Run In Registration Order.
+     */
+    pub fn register(&mut self, asset_type:&str, importer:Box<dyn Importer>) {
+        self.importers.push((asset_type.to_string(), importer));
+    }
+
+    /**
+     * Get The Importers That Would Run On An Asset Type With The Given Config
+     */
+    pub fn importers_for(&self, asset_type:&AssetType, config:&PackConfig) -> Vec<&dyn Importer> {
+        self.importers.iter()
+            .filter(|(pattern, _)| type_matches(pattern, asset_type))
+            .filter(|(_, importer)| config.importer_enabled(importer.name(), importer.enabled_by_default()))
+            .map(|(_, importer)| importer.as_ref())
+            .collect()
+    }
+
+    /**
+     * Work Out The Import Record An Asset Would Get, Without Running Any Importers.
+     * If It Matches The Record Of A Previous Pack, That Import Can Be Reused.
+     */
+    pub fn plan(&self, asset:&Asset, config:&PackConfig) -> Option<ImportRecord> {
+        let importers = self.importers_for(asset.get_type(), config);
+        if importers.is_empty() {
+            return None;
+        }
+        Some(ImportRecord {
+            source_hash: hash_bytes(asset.get_data().as_deref().unwrap_or_default()),
+            importers: importers.iter().map(|importer| ImporterStamp {
+                name: importer.name().to_string(),
+                version: importer.version(),
+                settings_hash: hash_bytes(config.importer_settings(importer.name()).to_string().as_bytes()),
+            }).collect(),
+        })
+    }
+
+    /**
+     * Run Every Matching Importer On The Asset And Record The Import In It
+     */
+    pub fn run(&self, asset:&mut Asset, config:&PackConfig) -> Result<(), String> {
+        let record = self.plan(asset, config);
+        for importer in self.importers_for(asset.get_type(), config) {
+            let settings = config.importer_settings(importer.name());
+            importer.import(asset, &settings).map_err(|e| format!("Importer {} Failed On {}: {}", importer.name(), asset.get_name(), e))?;
+        }
+        asset.set_import_record(record);
+        Ok(())
+    }
+
+    /**
+     * Load, Identify, Convert And Import A Single Source File The Same Way The Packer Does.
+     * Tilemaps, Meshes And Scripts Need The Files They Use (Tilesets, Material Libraries, Required Scripts),
+     * So For Those Every Other File Under `directory`, The Root Of The Source Files, Is Loaded Alongside.
+     */
+    pub fn import_file(&self, path:&Path, directory:&Path, config:&PackConfig) -> Result<Asset, String> {
+        let registry = config.registry();
+        let mut asset = load_source_file(path, config, &registry)?;
+        if is_converted(asset.get_type()) {
+            let mut assets = vec![asset];
+            for other in source_files(directory).iter().filter(|other| other.as_path() != path) {
+                match load_source_file(other, config, &registry) {
+                    Ok(other) => assets.push(other),
+                    Err(e) => println!("[!] Warning: Skipped {:?} While Importing {:?}: {}", other, path, e), // Only Matters If It's Used
+                }
+            }
+            convert_assets(&mut assets, config)?;
+            asset = assets.swap_remove(0);
+        }
+        self.run(&mut asset, config)?;
+        Ok(asset)
+    }
+}
+
+
+/**
+ * Every File Under A Source Directory, Sorted. Animation Sidecars Aren't Assets.
+ */
+pub(crate) fn source_files(directory:&Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut directories = vec![directory.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        for path in std::fs::read_dir(&directory).into_iter().flatten().flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                directories.push(path);
+            } else if !path.to_string_lossy().ends_with(".anim.toml") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+
+/**
+ * Load And Identify A Source File Like The Packer, The Config's Type Overrides Win Over The Registry's Kinds.
+ * Custom Kinds Must Pass Their Validators.
+ */
+fn load_source_file(path:&Path, config:&PackConfig, registry:&AssetRegistry) -> Result<Asset, String> {
+    let path_str = path.to_str().ok_or(format!("Invalid Path {:?}", path))?;
+    let name = asset_name_of(path_str);
+    let data = std::fs::read(path).map_err(|e| format!("Failed To Read {}: {}", path_str, e))?;
+    let asset_type = match config.types.get(&name) {
+        Some(asset_type) => asset_type.parse::<AssetType>()?,
+        None => registry.identify(Some(path_str), &data).asset_type,
+    };
+    registry.validate(&asset_type, &data).map_err(|e| format!("Asset {} Failed Validation As {:?}: {}", name, asset_type, e))?;
+
+    let mut asset = Asset::from_data(&name, asset_type, data);
+    asset.set_source_extension(extension_of(path_str));
+    asset.extract_metadata();
+    Ok(asset)
+}
+
+
+/**
+ * Is The Type Changed By `convert_assets`
+ */
+fn is_converted(asset_type:&AssetType) -> bool {
+    match asset_type {
+        AssetType::Custom { kind, format } => kind == "Tilemap" && format != "BIN",
+        AssetType::Model(format) => format == "OBJ",
+        AssetType::Script(format) => format == "LUA",
+        AssetType::Other(format) => format == "MTL",
+        _ => false,
+    }
+}
+
+
+/**
+ * Convert Tiled Maps And OBJ Models, Record What Scripts Require And Reference, Then Syntax Check (And Compile) The Lua Scripts.
+ * These Look Across Assets, So `assets` Should Hold Everything Being Packed. Runs Before Atlases Are Built
+ * So Tileset Images Are Still There, And Before Importing So Cached Imports Match The Bytecode Setting.
+ */
+pub fn convert_assets(assets:&mut Vec<Asset>, config:&PackConfig) -> Result<(), String> {
+    import_tilemaps(assets).map_err(|e| format!("Failed To Import Tilemaps: {}", e))?;
+    import_meshes(assets).map_err(|e| format!("Failed To Import Meshes: {}", e))?;
+    let dependencies = scan_script_dependencies(assets, &config.scripts)?;
+    println!("[+] Found {} Script Dependencies", dependencies);
+    let scripts = compile_scripts(assets, &config.scripts)?;
+    println!("[+] Checked {} Lua Scripts", scripts);
+    Ok(())
+}
+
+
+/**
+ * Does An Importer Type Pattern (`Kind` Or `Kind:FORMAT`) Match An Asset Type
+ */
+fn type_matches(pattern:&str, asset_type:&AssetType) -> bool {
+    match pattern.split_once(':') {
+        Some((kind, format)) => kind.eq_ignore_ascii_case(asset_type.kind_name()) && format.eq_ignore_ascii_case(asset_type.format()),
+        None => pattern.eq_ignore_ascii_case(asset_type.kind_name()),
+    }
+}
+
+
+/**
+ * 64 Bit FNV-1a Hash, Stable Across Builds And Platforms So It Can Live In The Manifest
+ */
+pub fn hash_bytes(data:&[u8]) -> u64 {
+    let mut hash:u64 = 0xcbf29ce484222325;
+    for byte in data {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+
+/**
+ * Strips Ancillary PNG Chunks (Text, Timestamps, Physical Size, ...) That The Engine Never Reads.
+ * Settings: `keep = ["pHYs", ...]` Keeps Extra Chunk Types.
+ */
+pub struct PngMetadataImporter;
+
+impl PngMetadataImporter {
+    // Ancillary Chunks That Change How The Image Looks Or Animates
+    const KEEP:[&'static str; 9] = ["tRNS", "gAMA", "cHRM", "sRGB", "iCCP", "sBIT", "acTL", "fcTL", "fdAT"];
+}
+
+impl Importer for PngMetadataImporter {
+
+    fn name(&self) -> &str {
+        "png_metadata"
+    }
+
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn import(&self, asset:&mut Asset, settings:&toml::Table) -> Result<(), String> {
+        const SIGNATURE:&[u8] = b"\x89PNG\r\n\x1a\n";
+        let extra_keep:Vec<&str> = settings.get("keep")
+            .and_then(|keep| keep.as_array())
+            .map(|keep| keep.iter().filter_map(|chunk| chunk.as_str()).collect())
+            .unwrap_or_default();
+
+        let data = asset.get_data().as_deref().ok_or("Asset Data Doesnt Exist")?;
+        if !data.starts_with(SIGNATURE) {
+            return Err("Missing PNG Signature".to_string());
+        }
+
+        let mut output = SIGNATURE.to_vec();
+        let mut offset = SIGNATURE.len();
+        while offset < data.len() {
+            if offset + 8 > data.len() {
+                return Err(format!("Truncated Chunk Header At Offset {}", offset));
+            }
+            let length = u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+            let chunk_end = offset + 12 + length;
+            if chunk_end > data.len() {
+                return Err(format!("Truncated Chunk At Offset {}", offset));
+            }
+            let chunk_type = std::str::from_utf8(&data[offset + 4..offset + 8]).map_err(|_| "Invalid Chunk Type")?;
+            let critical = chunk_type.starts_with(|c:char| c.is_ascii_uppercase());
+            if critical || Self::KEEP.contains(&chunk_type) || extra_keep.contains(&chunk_type) {
+                output.extend_from_slice(&data[offset..chunk_end]);
+            }
+            offset = chunk_end;
+        }
+
+        asset.set_data(output);
+        Ok(())
+    }
+}
+
+
+/**
+ * Normalizes Text Line Endings To `\n` And Strips A Leading UTF-8 Byte Order Mark, Binary Data Is Left Alone.
+ * Settings: `strip_bom = false` Keeps The Byte Order Mark.
+ */
+pub struct LineEndingImporter;
+
+impl Importer for LineEndingImporter {
+
+    fn name(&self) -> &str {
+        "line_endings"
+    }
+
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn import(&self, asset:&mut Asset, settings:&toml::Table) -> Result<(), String> {
+        let strip_bom = settings.get("strip_bom").and_then(|strip| strip.as_bool()).unwrap_or(true);
+        let mut data = asset.get_data().as_deref().ok_or("Asset Data Doesnt Exist")?;
+        if data.contains(&0) {
+            return Ok(()); // Binary, e.g. Precompiled Lua Bytecode
+        }
+        if strip_bom {
+            data = data.strip_prefix(b"\xef\xbb\xbf").unwrap_or(data);
+        }
+
+        let mut output = Vec::with_capacity(data.len());
+        let mut bytes = data.iter().peekable();
+        while let Some(byte) = bytes.next() {
+            if *byte == b'\r' {
+                bytes.next_if_eq(&&b'\n');
+                output.push(b'\n');
+            } else {
+                output.push(*byte);
+            }
+        }
+
+        asset.set_data(output);
+        Ok(())
+    }
+}
+
+
+/**
+ * Rewrites WAV Files Into The Canonical 44 Byte Header PCM Layout (RIFF, `fmt ` With 16 Bytes, `data`),
+ * Dropping Every Other Chunk And Unwrapping WAVE_FORMAT_EXTENSIBLE PCM.
+ * Anything Else (Float Samples, Compressed Audio, Streamed Files With An Unknown Length) Is Left As Is With A Warning.
+ */
+pub struct WavPcmImporter;
+
+impl WavPcmImporter {
+
+    /**
+     * The Canonical Form Of A PCM WAV, Or Why It Can't Be Canonicalized
+     */
+    fn canonicalize(data:&[u8]) -> Result<Vec<u8>, String> {
+        if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+            return Err("Not A RIFF WAVE File".to_string());
+        }
+
+        let mut fmt:Option<&[u8]> = None;
+        let mut samples:Option<&[u8]> = None;
+        let mut offset = 12;
+        while offset + 8 <= data.len() {
+            let chunk_id = &data[offset..offset + 4];
+            let length = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+            let body = data.get(offset + 8..offset + 8 + length).ok_or(format!("Truncated Chunk At Offset {}", offset))?;
+            match chunk_id {
+                b"fmt " => fmt = Some(body),
+                b"data" => samples = Some(body),
+                _ => {},
+            }
+            offset += 8 + length + (length & 1); // Chunks Are Padded To An Even Length
+        }
+
+        let fmt = fmt.filter(|fmt| fmt.len() >= 16).ok_or("Missing fmt Chunk")?;
+        let samples = samples.ok_or("Missing data Chunk")?;
+
+        let mut format_tag = u16::from_le_bytes([fmt[0], fmt[1]]);
+        if format_tag == 0xFFFE && fmt.len() >= 26 {
+            format_tag = u16::from_le_bytes([fmt[24], fmt[25]]); // Sub Format GUID Starts With The Real Tag
+        }
+        if format_tag != 1 {
+            return Err(format!("Unsupported WAV Format Tag {:#06x}, Only PCM Can Be Canonicalized", format_tag));
+        }
+
+        let mut output = Vec::with_capacity(44 + samples.len() + 1);
+        output.extend_from_slice(b"RIFF");
+        output.extend_from_slice(&((36 + samples.len() + (samples.len() & 1)) as u32).to_le_bytes());
+        output.extend_from_slice(b"WAVE");
+        output.extend_from_slice(b"fmt ");
+        output.extend_from_slice(&16u32.to_le_bytes());
+        output.extend_from_slice(&1u16.to_le_bytes());
+        output.extend_from_slice(&fmt[2..16]); // Channels, Sample Rate, Byte Rate, Block Align, Bits Per Sample
+        output.extend_from_slice(b"data");
+        output.extend_from_slice(&(samples.len() as u32).to_le_bytes());
+        output.extend_from_slice(samples);
+        if samples.len() & 1 == 1 {
+            output.push(0);
+        }
+
+        Ok(output)
+    }
+}
+
+impl Importer for WavPcmImporter {
+
+    fn name(&self) -> &str {
+        "wav_pcm"
+    }
+
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn import(&self, asset:&mut Asset, _settings:&toml::Table) -> Result<(), String> {
+        let data = asset.get_data().as_deref().ok_or("Asset Data Doesnt Exist")?;
+        match Self::canonicalize(data) {
+            Ok(output) => asset.set_data(output),
+            Err(reason) => println!("[!] Warning: WAV {} Left As Is, {}", asset.get_name(), reason),
+        }
+        Ok(())
+    }
+}
+
+
+/**
+ * Decodes PNGs At Pack Time So The Engine Can Upload Pixels Straight From The Chunk.
+ * Off By Default, Enable It With `[importers.raw_texture] enabled = true`.
+ * ---
+ * Settings: `format = "rgba8"` (Default) Stores Raw 8 Bit RGBA Rows,
+ * `format = "indexed"` Stores A Palette Of RGBA Colors Followed By One Byte Palette Index Per Pixel
+ * (Fails If The Image Has More Than 256 Colors), `format = "auto"` Picks Indexed When It Fits.
+ * ---
+ * The Type Becomes `Image("RGBA8")` Or `Image("INDEXED8")` With `width`, `height`, `pixel_format`
+ * And For Indexed Images `palette_size` (In Colors) Recorded In The Asset Metadata.
+ */
+pub struct RawTextureImporter;
+
+impl Importer for RawTextureImporter {
+
+    fn name(&self) -> &str {
+        "raw_texture"
+    }
+
+    fn version(&self) -> u32 {
+        2 // The Default Format Changed From Auto To RGBA8
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn import(&self, asset:&mut Asset, settings:&toml::Table) -> Result<(), String> {
+        let format = settings.get("format").and_then(|format| format.as_str()).unwrap_or("rgba8").to_ascii_lowercase();
+        let image = decode_png_rgba(asset.get_data().as_deref().ok_or("Asset Data Doesnt Exist")?)?;
+
+        let indexed = match format.as_str() {
+            "rgba8" => None,
+            "indexed" => Some(index_colors(&image.pixels).ok_or("Image Has More Than 256 Colors, It Can't Be Indexed")?),
+            "auto" => index_colors(&image.pixels),
+            _ => return Err(format!("Unknown Raw Texture Format {:?}", format)),
+        };
+
+        asset.set_metadata("width", &image.width.to_string());
+        asset.set_metadata("height", &image.height.to_string());
+        match indexed {
+            Some((palette, indices)) => {
+                asset.set_metadata("pixel_format", "INDEXED8");
+                asset.set_metadata("bit_depth", "8");
+                asset.set_metadata("color_type", "indexed");
+                asset.set_metadata("palette_size", &(palette.len() / 4).to_string());
+                asset.set_type(AssetType::Image("INDEXED8".to_string()));
+                let mut data = palette;
+                data.extend(indices);
+                asset.set_data(data);
+            },
+            None => {
+                asset.set_metadata("pixel_format", "RGBA8");
+                asset.set_metadata("bit_depth", "8");
+                asset.set_metadata("color_type", "rgba");
+                asset.set_type(AssetType::Image("RGBA8".to_string()));
+                asset.set_data(image.pixels);
+            }
+        }
+        Ok(())
+    }
+}
+
+
+/**
+ * Build A Palette Of Up To 256 RGBA Colors And The Palette Index Of Every Pixel
+ */
+fn index_colors(pixels:&[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut palette:Vec<u8> = Vec::new();
+    let mut lookup:BTreeMap<[u8; 4], u8> = BTreeMap::new();
+    let mut indices = Vec::with_capacity(pixels.len() / 4);
+    for pixel in pixels.chunks_exact(4) {
+        let color:[u8; 4] = pixel.try_into().unwrap();
+        let index = match lookup.get(&color) {
+            Some(index) => *index,
+            None => {
+                if lookup.len() == 256 {
+                    return None;
+                }
+                let index = lookup.len() as u8;
+                lookup.insert(color, index);
+                palette.extend_from_slice(&color);
+                index
+            }
+        };
+        indices.push(index);
+    }
+    Some((palette, indices))
+}
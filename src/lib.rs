@@ -1,14 +1,89 @@
 use file_format::{FileFormat, Kind};
 use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::{CStr, c_void, c_char, CString};
 use std::fs::{write, read};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 
+mod identify;
+pub use identify::{identify, identify_by_content, identify_by_extension, Identification, IdentificationSource};
 
+mod registry;
+pub use registry::{AssetDetector, AssetKind, AssetRegistry, AssetValidator};
 
+mod config;
+pub use config::{matches_pattern, AtlasConfig, PackConfig};
 
-#[derive(Serialize, Deserialize, Debug)]
+mod image;
+pub use image::{decode_png_rgba, encode_png_rgba, png_dimensions, ImageInfo, RgbaImage};
+
+mod atlas;
+pub use atlas::{build_atlas, build_atlases, AtlasPage, SpriteRect};
+
+mod animation;
+pub use animation::{detect_animations, remap_animations_to_atlas, Animation, AnimationConfig, AnimationFrame, LoopMode};
+
+mod audio;
+pub use audio::{check_audio_rules, AudioInfo, AudioRules};
+
+mod stream;
+pub use stream::AssetStream;
+
+mod binary;
+
+mod tilemap;
+pub use tilemap::{import_tilemaps, ObjectLayer, TileLayer, Tilemap, TilemapObject, TilemapTileset};
+
+mod mesh;
+pub use mesh::{import_meshes, material_textures, Mesh, SubMesh};
+
+mod script;
+pub use script::{check_lua, compile_lua, compile_scripts, scan_lua, scan_script_dependencies, BytecodeMode, ScriptConfig, ScriptReferences, LUA_VERSION};
+
+mod dependency;
+pub use dependency::{remap_dependencies_to_atlas, sort_assets_by_dependencies};
+
+mod group;
+pub use group::{assign_groups, group_ranges, sort_assets_by_group, AssetGroup};
+
+mod vfs;
+pub use vfs::{AssetVfs, MountedChunk, VfsEntry};
+
+#[cfg(feature = "overrides")]
+mod overrides;
+#[cfg(feature = "overrides")]
+pub use overrides::{get_overridden_assets, get_override_directory, read_override, set_override_directory};
+
+#[cfg(feature = "hot-reload")]
+mod reload;
+#[cfg(feature = "hot-reload")]
+pub use reload::{HotReloadWatch, HotReloader, ReloadCallback};
+
+mod net;
+pub use net::{AssetClient, AssetServer, AssetSource, PROTOCOL_VERSION};
+
+mod loader;
+pub use loader::{AssetLoader, LoadCallback, LoadedChunk, LoadStatus, LoadTicket};
+
+mod shared;
+pub use shared::{SharedChunk, SharedChunkData};
+
+mod residency;
+pub use residency::ResidencyManager;
+
+mod id;
+pub use id::{asset_constant_name, check_asset_ids, generate_c_ids, generate_rust_ids, AssetId};
+
+mod import;
+pub use import::{convert_assets, hash_bytes, Importer, ImporterStamp, ImportPipeline, ImportRecord, LineEndingImporter, PngMetadataImporter, RawTextureImporter, WavPcmImporter};
+
+
+
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum AssetType {
     Image(String),
     Audio(String),
@@ -17,15 +92,98 @@ pub enum AssetType {
     Model(String),
     Script(String),
     Other(String),
+    Custom { kind: String, format: String }, // A Kind Registered By The Game Through `AssetRegistry`
 }
 
 
-#[derive(Serialize, Deserialize, Debug)]
+impl AssetType {
+
+    /**
+     * Has This Asset Been Given A Real Type Yet
+     */
+    pub fn is_identified(&self) -> bool {
+        !matches!(self, AssetType::Other(name) if name == "UNIDENTIFIED")
+    }
+
+    /**
+     * Get The Format Name Carried By The Type, e.g. `PNG` For `Image("PNG")`
+     */
+    pub fn format(&self) -> &str {
+        match self {
+            AssetType::Image(format) | AssetType::Audio(format) | AssetType::Font(format) |
+            AssetType::Shader(format) | AssetType::Model(format) | AssetType::Script(format) |
+            AssetType::Other(format) | AssetType::Custom { format, .. } => format,
+        }
+    }
+
+    /**
+     * Get The Name Of The Kind, e.g. `Image` Or The Name Of A Registered Custom Kind
+     */
+    pub fn kind_name(&self) -> &str {
+        match self {
+            AssetType::Image(_) => "Image",
+            AssetType::Audio(_) => "Audio",
+            AssetType::Font(_) => "Font",
+            AssetType::Shader(_) => "Shader",
+            AssetType::Model(_) => "Model",
+            AssetType::Script(_) => "Script",
+            AssetType::Other(_) => "Other",
+            AssetType::Custom { kind, .. } => kind,
+        }
+    }
+
+    /**
+     * Get The File Extension For The Format, e.g. `.png` (What raylib's `Load*FromMemory` Expect)
+     */
+    pub fn extension(&self) -> String {
+        format!(".{}", self.format().to_ascii_lowercase())
+    }
+}
+
+
+/**
+ * Parse An Asset Type From A `Kind:FORMAT` String, e.g. `Script:LUA` or `Image:PNG`
+ * Any Kind That Isn't Built In Is Treated As A Custom Kind, e.g. `Dialogue:JSON`
+ */
+impl FromStr for AssetType {
+    type Err = String;
+
+    fn from_str(s:&str) -> Result<Self, Self::Err> {
+        let (kind, format) = s.split_once(':').ok_or(format!("Expected KIND:FORMAT, Got {:?}", s))?;
+        let format = format.trim().to_ascii_uppercase();
+        match kind.trim().to_ascii_lowercase().as_str() {
+            "image" => Ok(AssetType::Image(format)),
+            "audio" => Ok(AssetType::Audio(format)),
+            "font" => Ok(AssetType::Font(format)),
+            "shader" => Ok(AssetType::Shader(format)),
+            "model" => Ok(AssetType::Model(format)),
+            "script" => Ok(AssetType::Script(format)),
+            "other" => Ok(AssetType::Other(format)),
+            "" => Err(format!("Missing Asset Kind In {:?}", s)),
+            _ => Ok(AssetType::Custom { kind: kind.trim().to_string(), format }),
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Asset {
     name: String, // Asset Name
+    #[serde(default)]
+    id: AssetId, // Hash Of The Name, See `AssetId`
     asset_type: AssetType, // Asset Type
     location: usize, // Location Of Asset In Chunk
     size: usize, // Size Of Asset In Bytes
+    #[serde(default)]
+    metadata: BTreeMap<String, String>, // Free Form Key-Value Properties Of The Asset
+    #[serde(default)]
+    import: Option<ImportRecord>, // How The Asset Was Imported When Packed, None If Packed Verbatim
+    #[serde(default)]
+    dependencies: Vec<String>, // Names Of The Assets This One References
+    #[serde(default)]
+    group: Option<String>, // Group The Asset Is Laid Out With, See `AssetGroup`
+    #[serde(default)]
+    source_extension: Option<String>, // Extension Of The File The Asset Was Packed From (".png"), None If It Was Generated
     #[serde(skip_serializing)]
     #[serde(skip_deserializing)]
     data: Option<Vec<u8>>, // Vector Of Bytes, Representing The Asset Data
@@ -42,14 +200,39 @@ impl Asset {
         let file_data = read(filepath).unwrap();
         let file_size = file_data.len();
         Asset {
+            source_extension: extension_of(filepath),
             name: asset_name.to_string(),
-            asset_type: asset_type,
+            id: AssetId::from_name(asset_name),
+            asset_type,
             location: 0,
             size: file_size,
+            metadata: BTreeMap::new(),
+            import: None,
+            dependencies: Vec::new(),
+            group: None,
             data: Some(file_data),
         }
     }
 
+    /**
+     * Create An Asset From Data Generated While Packing (e.g. An Atlas Page)
+     */
+    pub fn from_data(asset_name:&str, asset_type:AssetType, data:Vec<u8>) -> Self {
+        Asset {
+            name: asset_name.to_string(),
+            id: AssetId::from_name(asset_name),
+            asset_type,
+            location: 0,
+            size: data.len(),
+            metadata: BTreeMap::new(),
+            import: None,
+            dependencies: Vec::new(),
+            group: None,
+            source_extension: None,
+            data: Some(data),
+        }
+    }
+
     /**
      * Set Asset Location In Chunk
      */
@@ -79,6 +262,14 @@ impl Asset {
         &self.data
     }
 
+    /**
+     * Replace Asset Data, Updating The Asset Size To Match
+     */
+    pub fn set_data(&mut self, data:Vec<u8>) {
+        self.size = data.len();
+        self.data = Some(data);
+    }
+
     /**
      * Get Asset Name
      */
@@ -86,6 +277,13 @@ impl Asset {
         &self.name
     }
 
+    /**
+     * Get Asset ID
+     */
+    pub fn get_id(&self) -> AssetId {
+        self.id
+    }
+
     /**
      * Get Asset Type
      **/
@@ -100,6 +298,160 @@ impl Asset {
         self.asset_type = asset_type;
     }
 
+    /**
+     * Get Asset Metadata
+     */
+    pub fn get_metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /**
+     * Set An Asset Metadata Value, Replacing Any Existing Value For The Key
+     */
+    pub fn set_metadata(&mut self, key:&str, value:&str) {
+        self.metadata.insert(key.to_string(), value.to_string());
+    }
+
+    /**
+     * Record Properties Read From The Asset's Header In Its Metadata.
+     * Images Get `width`, `height`, `bit_depth`, `color_type` And `frame_count`,
+     * Audio Gets `sample_rate`, `channels`, `bit_depth` And `duration_ms`, Other Types Are Left Alone.
+     */
+    pub fn extract_metadata(&mut self) {
+        let Some(data) = self.data.as_deref() else {
+            return;
+        };
+        match self.asset_type {
+            AssetType::Image(_) => {
+                if let Some(info) = ImageInfo::from_data(data) {
+                    self.set_metadata("width", &info.width.to_string());
+                    self.set_metadata("height", &info.height.to_string());
+                    self.set_metadata("bit_depth", &info.bit_depth.to_string());
+                    self.set_metadata("color_type", &info.color_type);
+                    self.set_metadata("frame_count", &info.frame_count.to_string());
+                }
+            },
+            AssetType::Audio(_) => {
+                if let Some(info) = AudioInfo::from_data(data) {
+                    self.set_metadata("sample_rate", &info.sample_rate.to_string());
+                    self.set_metadata("channels", &info.channels.to_string());
+                    self.set_metadata("bit_depth", &info.bit_depth.to_string());
+                    self.set_metadata("duration_ms", &info.duration_ms.to_string());
+                }
+            },
+            _ => {}
+        }
+    }
+
+    /**
+     * Get The Image Properties Recorded In The Metadata, None If The Asset Isn't An Image Or Wasn't Parsed
+     */
+    pub fn get_image_info(&self) -> Option<ImageInfo> {
+        if !matches!(self.asset_type, AssetType::Image(_)) {
+            return None;
+        }
+        let get = |key:&str| self.metadata.get(key);
+        Some(ImageInfo {
+            width: get("width")?.parse().ok()?,
+            height: get("height")?.parse().ok()?,
+            bit_depth: get("bit_depth")?.parse().ok()?,
+            color_type: get("color_type")?.clone(),
+            frame_count: get("frame_count")?.parse().ok()?,
+        })
+    }
+
+    /**
+     * Get The Audio Properties Recorded In The Metadata, None If The Asset Isn't Audio Or Wasn't Parsed
+     */
+    pub fn get_audio_info(&self) -> Option<AudioInfo> {
+        if !matches!(self.asset_type, AssetType::Audio(_)) {
+            return None;
+        }
+        let get = |key:&str| self.metadata.get(key);
+        Some(AudioInfo {
+            sample_rate: get("sample_rate")?.parse().ok()?,
+            channels: get("channels")?.parse().ok()?,
+            bit_depth: get("bit_depth")?.parse().ok()?,
+            duration_ms: get("duration_ms")?.parse().ok()?,
+        })
+    }
+
+    /**
+     * Get The Names Of The Assets This One References
+     */
+    pub fn get_dependencies(&self) -> &Vec<String> {
+        &self.dependencies
+    }
+
+    /**
+     * Replace The Names Of The Assets This One References
+     */
+    pub fn set_dependencies(&mut self, dependencies:Vec<String>) {
+        self.dependencies = dependencies;
+    }
+
+    /**
+     * Add A Dependency On Another Asset, Ignoring Duplicates
+     */
+    pub fn add_dependency(&mut self, name:&str) {
+        if !self.dependencies.iter().any(|dependency| dependency == name) {
+            self.dependencies.push(name.to_string());
+        }
+    }
+
+    /**
+     * Get The Extension Of The File The Asset Was Packed From, e.g. ".png" For A PNG Packed As Raw RGBA8
+     */
+    pub fn get_source_extension(&self) -> Option<&str> {
+        self.source_extension.as_deref()
+    }
+
+    /**
+     * Set The Extension Of The File The Asset Was Packed From
+     */
+    pub fn set_source_extension(&mut self, extension:Option<String>) {
+        self.source_extension = extension;
+    }
+
+    /**
+     * Get The Path A Loose File Must Have To Override The Asset, e.g. `hero.png`.
+     * Uses The Extension Of The Source File, So It Stays The Same When Packing Converts The Asset.
+     */
+    pub fn get_virtual_path(&self) -> String {
+        match self.source_extension.as_deref() {
+            Some(extension) => virtual_path(&self.name, extension),
+            None => virtual_path(&self.name, &self.asset_type.extension()),
+        }
+    }
+
+    /**
+     * Get The Group The Asset Is Laid Out With
+     */
+    pub fn get_group(&self) -> &Option<String> {
+        &self.group
+    }
+
+    /**
+     * Set The Group The Asset Is Laid Out With
+     */
+    pub fn set_group(&mut self, group:Option<String>) {
+        self.group = group;
+    }
+
+    /**
+     * Get How The Asset Was Imported
+     */
+    pub fn get_import_record(&self) -> &Option<ImportRecord> {
+        &self.import
+    }
+
+    /**
+     * Set How The Asset Was Imported
+     */
+    pub fn set_import_record(&mut self, import:Option<ImportRecord>) {
+        self.import = import;
+    }
+
 
 }
 
@@ -107,6 +459,12 @@ impl Asset {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AssetManifest {
     assets: Vec<Asset>,
+    #[serde(default)]
+    sprites: Vec<SpriteRect>, // Sprites Packed Into Atlas Page Assets
+    #[serde(default)]
+    animations: Vec<Animation>, // Animations Cut From Image Assets
+    #[serde(default)]
+    groups: Vec<AssetGroup>, // Byte Ranges Of Assets Laid Out Together
 }
 
 
@@ -118,7 +476,10 @@ impl AssetManifest {
      */
     pub fn new(assets:Vec<Asset>) -> Self {
         AssetManifest {
-            assets: assets,
+            assets,
+            sprites: Vec::new(),
+            animations: Vec::new(),
+            groups: Vec::new(),
         }
     }
 
@@ -139,6 +500,79 @@ impl AssetManifest {
     }
 
 
+    /**
+     * Get The Sprites Packed Into Atlases
+     */
+    pub fn get_sprites(&self) -> &Vec<SpriteRect> {
+        &self.sprites
+    }
+
+    /**
+     * Set The Sprites Packed Into Atlases
+     */
+    pub fn set_sprites(&mut self, sprites:Vec<SpriteRect>) {
+        self.sprites = sprites;
+    }
+
+    /**
+     * Get Where A Sprite Lives In Its Atlas Page
+     */
+    pub fn get_sprite_rect(&self, name:&str) -> Option<&SpriteRect> {
+        self.sprites.iter().find(|sprite| sprite.name == name)
+    }
+
+
+    /**
+     * Get Every Animation In The Manifest
+     */
+    pub fn get_animations(&self) -> &Vec<Animation> {
+        &self.animations
+    }
+
+    /**
+     * Set The Animations In The Manifest
+     */
+    pub fn set_animations(&mut self, animations:Vec<Animation>) {
+        self.animations = animations;
+    }
+
+    /**
+     * Get An Animation By Name
+     */
+    pub fn get_animation(&self, name:&str) -> Option<&Animation> {
+        self.animations.iter().find(|animation| animation.name == name)
+    }
+
+    /**
+     * Get The Animations Defined On An Image Asset
+     */
+    pub fn get_animations_for(&self, image:&str) -> Vec<&Animation> {
+        self.animations.iter().filter(|animation| animation.image == image).collect()
+    }
+
+
+    /**
+     * Get The Asset Groups
+     */
+    pub fn get_groups(&self) -> &Vec<AssetGroup> {
+        &self.groups
+    }
+
+    /**
+     * Set The Asset Groups
+     */
+    pub fn set_groups(&mut self, groups:Vec<AssetGroup>) {
+        self.groups = groups;
+    }
+
+    /**
+     * Get An Asset Group By Name
+     */
+    pub fn get_group(&self, name:&str) -> Option<&AssetGroup> {
+        self.groups.iter().find(|group| group.name == name)
+    }
+
+
     /**
      * Serialize The Asset Manifest To A JSON String
      */
@@ -151,7 +585,37 @@ impl AssetManifest {
      * Load An Asset Manifest From A JSON String
      */
     pub fn from_json(json:&str) -> Self {
-        serde_json::from_str(json).unwrap()
+        let mut manifest:AssetManifest = serde_json::from_str(json).unwrap();
+        manifest.fill_missing_ids();
+        manifest
+    }
+
+
+    /**
+     * Load An Asset Manifest From A JSON String, Returning The Parse Error Instead Of Panicking
+     */
+    pub fn try_from_json(json:&str) -> Result<Self, String> {
+        let mut manifest:AssetManifest = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        manifest.fill_missing_ids();
+        Ok(manifest)
+    }
+
+
+    /**
+     * Give Assets From Manifests Packed Before IDs Their IDs
+     */
+    fn fill_missing_ids(&mut self) {
+        for asset in self.assets.iter_mut().filter(|asset| !asset.id.is_valid()) {
+            asset.id = AssetId::from_name(&asset.name);
+        }
+    }
+
+
+    /**
+     * Find An Asset By Its ID
+     */
+    pub fn get_asset_by_id(&self, id:AssetId) -> Option<&Asset> {
+        self.assets.iter().find(|asset| asset.id == id)
     }
 
 
@@ -167,9 +631,8 @@ impl AssetManifest {
         println!("[+] Loading Asset Data From Chunk: {:?}...", filepath);
         let filedata = read(filepath);
         println!("File Data: {:?}", filedata);
-        if filedata.is_ok() {
+        if let Ok(data) = filedata {
             println!("[+] Chunk Loaded Successfully!");
-            let data = filedata.unwrap();
 
             println!("[+] Loaded {} Bytes", data.len());
             for asset in self.assets.iter_mut() {
@@ -182,7 +645,7 @@ impl AssetManifest {
             }
         }
 
-        // Identify Assets
+        // Identify Assets (Only Manifests Packed Without Types Still Need This)
         identify_assets(self.assets.as_mut());
     }
 
@@ -198,16 +661,40 @@ impl AssetManifest {
 
 }
 
+/**
+ * Name Of The Asset A File Path Refers To, Assets Are Named After Their File Stem
+ */
+pub(crate) fn asset_name_of(path:&str) -> String {
+    std::path::Path::new(path).file_stem().and_then(|stem| stem.to_str()).unwrap_or(path).to_string()
+}
+
+/**
+ * Path A Loose File Must Have To Override An Asset, Its Name Followed By An Extension
+ */
+fn virtual_path(name:&str, extension:&str) -> String {
+    format!("{}{}", name, extension)
+}
+
+/**
+ * Extension Of A File Path With Its Dot (".png"), None If It Has None
+ */
+pub(crate) fn extension_of(path:&str) -> Option<String> {
+    std::path::Path::new(path).extension().and_then(|extension| extension.to_str()).map(|extension| format!(".{}", extension))
+}
+
 /**
  * Identify Assets After The Data Has Been Loaded
+ * Assets That Already Carry A Type (Identified At Pack Time Or Overridden) Are Left Alone
  */
-pub fn identify_assets(assets:&mut Vec<Asset>) {
+pub fn identify_assets(assets:&mut [Asset]) {
     println!("[+] Identifying Assets...");
-    for asset in assets {
+    for asset in assets.iter_mut().filter(|asset| !asset.get_type().is_identified()) {
           println!("[+] Identifying Asset: {}", asset.get_name());
           let asset_data = asset.get_data().as_deref().expect("[-] Error While Identifying Asset: Asset Data Doesnt Exist.");
-          let asset_type = identify_asset(asset_data);
-          asset.set_type(asset_type);
+          let identification = identify(Some(asset.get_name()), asset_data);
+          println!("[+] Identified As {:?} (Confidence: {:.2})", identification.asset_type, identification.confidence);
+          asset.set_type(identification.asset_type);
+          asset.extract_metadata();
     }
     println!("[+] Assets Identified Successfully!");
 }
@@ -318,100 +805,454 @@ pub struct RawCAsset {
     pub location: usize,
     pub size: usize,
     pub name: *const c_char,
+    pub kind: RawCAssetKind,
+    pub kind_name: *const c_char, // Kind Name, e.g. "Image" Or A Custom Kind Like "Dialogue"
+    pub format: *const c_char, // Format Name, e.g. "PNG"
+    pub extension: *const c_char, // Format As A File Extension, e.g. ".png"
+    pub metadata: *const RawCAssetMetadata, // Array Of Key-Value Pairs
+    pub metadata_count: usize,
+    pub dependencies: *const *const c_char, // Names Of The Assets This One References
+    pub dependency_count: usize,
+    pub id: u32, // See `AssetId`
+    pub virtual_path: *const c_char, // Path A Loose File Must Have To Override The Asset, e.g. "hero.png"
 }
 
 /**
- * API: - Represents The Asset Manifest
+ * API: - The Kind Of An Asset, Mirrors The Variants Of `AssetType`
  */
 #[repr(C)]
-#[derive(Debug,Clone)]
-pub struct RawCAssetManifest {
-    pub assets: *mut *mut RawCAsset, // Remember This is an array of assets (pointers)
-    pub asset_count: usize,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawCAssetKind {
+    Image = 0,
+    Audio = 1,
+    Font = 2,
+    Shader = 3,
+    Model = 4,
+    Script = 5,
+    Other = 6,
+    Custom = 7, // Registered By The Game, See `kind_name`
+}
+
+impl From<&AssetType> for RawCAssetKind {
+    fn from(asset_type:&AssetType) -> Self {
+        match asset_type {
+            AssetType::Image(_) => RawCAssetKind::Image,
+            AssetType::Audio(_) => RawCAssetKind::Audio,
+            AssetType::Font(_) => RawCAssetKind::Font,
+            AssetType::Shader(_) => RawCAssetKind::Shader,
+            AssetType::Model(_) => RawCAssetKind::Model,
+            AssetType::Script(_) => RawCAssetKind::Script,
+            AssetType::Other(_) => RawCAssetKind::Other,
+            AssetType::Custom { .. } => RawCAssetKind::Custom,
+        }
+    }
 }
 
 /**
- * API: - Represents The Asset Chunk
+ * API: - A Single Metadata Key-Value Pair Of An Asset
  */
 #[repr(C)]
-#[derive(Debug,Clone, Copy)]
-pub struct RawCAssetChunk {
-    pub data: *mut c_void,
-    pub size: usize,
-    pub manifest: *mut RawCAssetManifest,
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAssetMetadata {
+    pub key: *const c_char,
+    pub value: *const c_char,
 }
 
+impl RawCAssetMetadata {
 
-#[no_mangle]
-pub extern "C" fn load_asset_manifest(filepath:*const c_char) -> *mut RawCAssetManifest {
-    let filestr:&str;
-    unsafe {
-        filestr = CStr::from_ptr(filepath).to_str().unwrap();
+    /**
+     * Leak A Key-Value Map As A C Array, Returning The Array And Its Length
+     */
+    pub fn leak_map(map:&BTreeMap<String, String>) -> (*const RawCAssetMetadata, usize) {
+        let entries = Box::leak(map.iter().map(|(key, value)| RawCAssetMetadata {
+            key: CString::new(key.as_str()).unwrap().into_raw(),
+            value: CString::new(value.as_str()).unwrap().into_raw(),
+        }).collect::<Vec<RawCAssetMetadata>>().into_boxed_slice());
+        (entries.as_ptr(), entries.len())
     }
 
-    if filestr.is_empty() {
-        println!("[-] Error: Invalid / Empty File Path");
-        return std::ptr::null_mut();
+    /**
+     * Free An Array Made With `leak_map` And The Strings In It
+     */
+    unsafe fn free_map(entries:*const RawCAssetMetadata, count:usize) {
+        for entry in take_boxed_slice(entries, count).iter() {
+            free_c_string(entry.key);
+            free_c_string(entry.value);
+        }
     }
+}
 
-    let path = PathBuf::from_str(filestr).unwrap();
-    let filedata = read(path);
-
-    if filedata.is_err() {
-        println!("[-] Error: Failed To Load Asset Manifest: {:?}", filedata.err());
-        return std::ptr::null_mut();
-    } else {
-        let filedata = filedata.unwrap();
-
-        let datastr = String::from_utf8(filedata);
+impl RawCAsset {
 
-        if datastr.is_err() {
-            println!("[-] Error: Failed To Convert Asset Manifest Data To JSON String: {:?}", datastr.err());
-            return std::ptr::null_mut();
+    /**
+     * Build The C Representation Of An Asset's Manifest Entry.
+     * The Strings, Metadata And Dependency Arrays Are Leaked, They Live As Long As The Manifest And Are Freed With It.
+     */
+    pub fn from_asset(asset:&Asset) -> Self {
+        let (metadata_ptr, metadata_count) = RawCAssetMetadata::leak_map(asset.get_metadata());
+        let dependencies = Box::leak(asset.get_dependencies().iter()
+            .map(|name| CString::new(name.as_str()).unwrap().into_raw() as *const c_char)
+            .collect::<Vec<*const c_char>>()
+            .into_boxed_slice());
+
+        RawCAsset {
+            data: std::ptr::null_mut(), // We're just loading the manifest, not the data, yet
+            location: asset.get_chunk_location(),
+            size: asset.get_size(),
+            name: CString::new(asset.get_name()).unwrap().into_raw(),
+            kind: RawCAssetKind::from(asset.get_type()),
+            kind_name: CString::new(asset.get_type().kind_name()).unwrap().into_raw(),
+            format: CString::new(asset.get_type().format()).unwrap().into_raw(),
+            extension: CString::new(asset.get_type().extension()).unwrap().into_raw(),
+            metadata: metadata_ptr,
+            metadata_count,
+            dependencies: dependencies.as_ptr(),
+            dependency_count: dependencies.len(),
+            id: asset.get_id().0,
+            virtual_path: CString::new(asset.get_virtual_path()).unwrap().into_raw(),
         }
+    }
 
-        let datastr =  datastr.unwrap();
+    /**
+     * Free The Strings And Arrays `from_asset` Leaked, Copies Of The Asset Are Left Dangling
+     */
+    unsafe fn free(&self) {
+        for string in [self.name, self.kind_name, self.format, self.extension, self.virtual_path] {
+            free_c_string(string);
+        }
+        RawCAssetMetadata::free_map(self.metadata, self.metadata_count);
+        free_c_strings(self.dependencies, self.dependency_count);
+    }
+}
 
-        let manifest:AssetManifest = AssetManifest::from_json(&datastr);
+/**
+ * Free A String Handed To C With `CString::into_raw`
+ */
+unsafe fn free_c_string(string:*const c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string as *mut c_char));
+    }
+}
+
+/**
+ * Take Back An Array Handed To C With `Box::leak`
+ */
+unsafe fn take_boxed_slice<T>(array:*const T, count:usize) -> Box<[T]> {
+    Box::from_raw(std::ptr::slice_from_raw_parts_mut(array as *mut T, count))
+}
 
-        let mut raw_assets:Vec<RawCAsset> = Vec::new();
+/**
+ * Free A Leaked Array Of Strings And The Strings In It
+ */
+unsafe fn free_c_strings(strings:*const *const c_char, count:usize) {
+    for string in take_boxed_slice(strings, count).iter() {
+        free_c_string(*string);
+    }
+}
+
+/**
+ * API: - Represents The Asset Manifest
+ */
+#[repr(C)]
+#[derive(Debug,Clone)]
+pub struct RawCAssetManifest {
+    pub assets: *mut *mut RawCAsset, // Remember This is an array of assets (pointers)
+    pub asset_count: usize,
+    pub sprites: *mut RawCSpriteRect, // Array Of Sprites Packed Into Atlas Pages
+    pub sprite_count: usize,
+    pub animations: *mut RawCAnimation, // Array Of Animations
+    pub animation_count: usize,
+    pub groups: *mut RawCAssetGroup, // Array Of Asset Groups
+    pub group_count: usize,
+    pub id_index: *mut c_void, // Private, Asset ID -> Index In `assets`, Used By `get_asset_by_id`
+}
 
-        
+impl RawCAssetManifest {
 
-        
-        for asset in manifest.get_assets().iter() {
+    /**
+     * Build The C Representation Of A Manifest, Everything In It Is Leaked Until `free_asset_manifest`
+     */
+    pub fn from_manifest(manifest:&AssetManifest) -> Self {
+        let raw_assets = Box::leak(manifest.get_assets().iter().map(|asset| {
             println!("Asset: {:?}", asset);
-            let raw_asset = RawCAsset {
-                data:std::ptr::null_mut(), // We're just loading the manifest, not the data, yet
-                location: asset.get_chunk_location(),
-                name: CString::new(asset.get_name()).unwrap().into_raw(),
-                size: asset.get_size(),
-            };
-            raw_assets.push(raw_asset);
+            RawCAsset::from_asset(asset)
+        }).collect::<Vec<RawCAsset>>().into_boxed_slice());
+        let raw_asset_ptrs = Box::leak(raw_assets.iter_mut().map(|asset| asset as *mut RawCAsset).collect::<Vec<*mut RawCAsset>>().into_boxed_slice());
+
+        let raw_sprites = Box::leak(manifest.get_sprites().iter().map(RawCSpriteRect::from_sprite).collect::<Vec<RawCSpriteRect>>().into_boxed_slice());
+
+        let raw_groups = Box::leak(manifest.get_groups().iter().map(RawCAssetGroup::from_group).collect::<Vec<RawCAssetGroup>>().into_boxed_slice());
+
+        let raw_animations = Box::leak(manifest.get_animations().iter().map(RawCAnimation::from_animation).collect::<Vec<RawCAnimation>>().into_boxed_slice());
+
+        let id_index:HashMap<AssetId, usize> = manifest.get_assets().iter().enumerate().map(|(index, asset)| (asset.get_id(), index)).collect();
+
+        RawCAssetManifest {
+            assets: raw_asset_ptrs.as_mut_ptr(),
+            asset_count: raw_asset_ptrs.len(),
+            sprites: raw_sprites.as_mut_ptr(),
+            sprite_count: raw_sprites.len(),
+            animations: raw_animations.as_mut_ptr(),
+            animation_count: raw_animations.len(),
+            groups: raw_groups.as_mut_ptr(),
+            group_count: raw_groups.len(),
+            id_index: Box::into_raw(Box::new(id_index)) as *mut c_void,
         }
-        
-        
+    }
 
-        if raw_assets.is_empty() {
-            println!("[-] Error: Failed To Load Asset Manifest");
+
+    /**
+     * Find An Asset By Its ID Through The Index Built In `from_manifest`
+     */
+    fn find_by_id(&self, id:AssetId) -> Option<*mut RawCAsset> {
+        if self.id_index.is_null() {
+            return None;
+        }
+        let id_index = unsafe { &*(self.id_index as *const HashMap<AssetId, usize>) };
+        id_index.get(&id).map(|&index| unsafe { *self.assets.add(index) })
+    }
+
+    /**
+     * Free Everything `from_manifest` Leaked. The Assets Sit In One Array Behind The Pointer Array.
+     */
+    unsafe fn free(&self) {
+        let asset_ptrs = take_boxed_slice(self.assets, self.asset_count);
+        if let Some(&first) = asset_ptrs.first() {
+            for asset in take_boxed_slice(first, self.asset_count).iter() {
+                asset.free();
+            }
+        }
+        for sprite in take_boxed_slice(self.sprites, self.sprite_count).iter() {
+            free_c_string(sprite.name);
+            free_c_string(sprite.atlas);
+        }
+        for animation in take_boxed_slice(self.animations, self.animation_count).iter() {
+            free_c_string(animation.name);
+            free_c_string(animation.image);
+            for frame in take_boxed_slice(animation.frames, animation.frame_count).iter() {
+                free_c_string(frame.image);
+            }
+        }
+        for group in take_boxed_slice(self.groups, self.group_count).iter() {
+            free_c_string(group.name);
+            free_c_strings(group.assets, group.asset_count);
+        }
+        if !self.id_index.is_null() {
+            drop(Box::from_raw(self.id_index as *mut HashMap<AssetId, usize>));
+        }
+    }
+}
+
+/**
+ * API: - A Group Of Assets Laid Out Back To Back In The Chunk, Load It With `load_asset_group`
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAssetGroup {
+    pub name: *const c_char,
+    pub offset: usize,
+    pub size: usize,
+    pub assets: *const *const c_char, // Names Of The Assets In The Group
+    pub asset_count: usize,
+}
+
+impl RawCAssetGroup {
+
+    /**
+     * Build The C Representation Of An Asset Group
+     */
+    pub fn from_group(group:&AssetGroup) -> Self {
+        let assets = Box::leak(group.assets.iter()
+            .map(|name| CString::new(name.as_str()).unwrap().into_raw() as *const c_char)
+            .collect::<Vec<*const c_char>>()
+            .into_boxed_slice());
+        RawCAssetGroup {
+            name: CString::new(group.name.as_str()).unwrap().into_raw(),
+            offset: group.offset,
+            size: group.size,
+            assets: assets.as_ptr(),
+            asset_count: assets.len(),
+        }
+    }
+}
+
+/**
+ * API: - What Happens When An Animation Reaches Its Last Frame
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawCLoopMode {
+    Loop = 0,
+    Once = 1,
+    PingPong = 2,
+}
+
+/**
+ * API: - A Single Frame Of An Animation
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAnimationFrame {
+    pub image: *const c_char, // Image Asset Holding The Frame, Look It Up With `get_asset`
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub duration_ms: u32,
+}
+
+/**
+ * API: - An Animation Cut From An Image Asset
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAnimation {
+    pub name: *const c_char,
+    pub image: *const c_char,
+    pub loop_mode: RawCLoopMode,
+    pub frames: *const RawCAnimationFrame,
+    pub frame_count: usize,
+}
+
+impl RawCAnimation {
+
+    /**
+     * Build The C Representation Of An Animation, The Strings And Frames Live As Long As The Manifest
+     */
+    pub fn from_animation(animation:&Animation) -> Self {
+        let frames:Vec<RawCAnimationFrame> = animation.frames.iter().map(|frame| RawCAnimationFrame {
+            image: CString::new(frame.image.as_str()).unwrap().into_raw(),
+            x: frame.x,
+            y: frame.y,
+            width: frame.width,
+            height: frame.height,
+            duration_ms: frame.duration_ms,
+        }).collect();
+        let frames = Box::leak(frames.into_boxed_slice());
+
+        RawCAnimation {
+            name: CString::new(animation.name.as_str()).unwrap().into_raw(),
+            image: CString::new(animation.image.as_str()).unwrap().into_raw(),
+            loop_mode: match animation.loop_mode {
+                LoopMode::Loop => RawCLoopMode::Loop,
+                LoopMode::Once => RawCLoopMode::Once,
+                LoopMode::PingPong => RawCLoopMode::PingPong,
+            },
+            frames: frames.as_ptr(),
+            frame_count: frames.len(),
+        }
+    }
+}
+
+/**
+ * API: - Where A Sprite Lives Inside An Atlas Page Asset
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCSpriteRect {
+    pub name: *const c_char,
+    pub atlas: *const c_char, // Name Of The Atlas Page Asset, Look It Up With `get_asset`
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub u0: f32,
+    pub v0: f32,
+    pub u1: f32,
+    pub v1: f32,
+}
+
+impl RawCSpriteRect {
+
+    /**
+     * Build The C Representation Of A Sprite, The Strings Live As Long As The Manifest
+     */
+    pub fn from_sprite(sprite:&SpriteRect) -> Self {
+        RawCSpriteRect {
+            name: CString::new(sprite.name.as_str()).unwrap().into_raw(),
+            atlas: CString::new(sprite.atlas.as_str()).unwrap().into_raw(),
+            x: sprite.x,
+            y: sprite.y,
+            width: sprite.width,
+            height: sprite.height,
+            u0: sprite.u0,
+            v0: sprite.v0,
+            u1: sprite.u1,
+            v1: sprite.v1,
+        }
+    }
+}
+
+/**
+ * API: - Represents The Asset Chunk
+ */
+#[repr(C)]
+#[derive(Debug,Clone, Copy)]
+pub struct RawCAssetChunk {
+    pub data: *mut c_void,
+    pub size: usize,
+    pub manifest: *mut RawCAssetManifest,
+}
+
+impl RawCAssetChunk {
+
+    /**
+     * Hand A Chunk Loaded In Rust Over To C, The Caller Owns It And Frees It With `free_asset_chunk`
+     */
+    fn from_loaded(manifest:&AssetManifest, data:Vec<u8>) -> *mut RawCAssetChunk {
+        let data = Box::leak(data.into_boxed_slice());
+        Box::into_raw(Box::new(RawCAssetChunk {
+            data: data.as_mut_ptr() as *mut c_void,
+            size: data.len(),
+            manifest: Box::into_raw(Box::new(RawCAssetManifest::from_manifest(manifest))),
+        }))
+    }
+}
+
+
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn load_asset_manifest(filepath:*const c_char) -> *mut RawCAssetManifest {
+    let filestr:&str;
+    unsafe {
+        filestr = CStr::from_ptr(filepath).to_str().unwrap();
+    }
+
+    if filestr.is_empty() {
+        println!("[-] Error: Invalid / Empty File Path");
+        return std::ptr::null_mut();
+    }
+
+    let path = PathBuf::from_str(filestr).unwrap();
+    let filedata = read(path);
+
+    if let Err(err) = filedata {
+        println!("[-] Error: Failed To Load Asset Manifest: {:?}", err);
+        std::ptr::null_mut()
+    } else {
+        let filedata = filedata.unwrap();
+
+        let datastr = String::from_utf8(filedata);
+
+        if datastr.is_err() {
+            println!("[-] Error: Failed To Convert Asset Manifest Data To JSON String: {:?}", datastr.err());
             return std::ptr::null_mut();
         }
 
-        let raw_assets_len = raw_assets.len();
-        let mut raw_ptr_vec:Vec<*mut RawCAsset> = raw_assets.iter_mut().map(|asset| asset as *mut RawCAsset).collect();
-        let raw_assets_ptr = raw_ptr_vec.as_mut_ptr();
-        
+        let datastr =  datastr.unwrap();
 
-        std::mem::forget(raw_assets);
+        let manifest:AssetManifest = AssetManifest::from_json(&datastr);
 
-        let return_manifest = RawCAssetManifest {
-            assets: raw_assets_ptr,
-            asset_count: raw_assets_len,
-        };
+        if manifest.get_assets().is_empty() {
+            println!("[-] Error: Failed To Load Asset Manifest");
+            return std::ptr::null_mut();
+        }
+
+        let return_manifest = RawCAssetManifest::from_manifest(&manifest);
 
         let return_manifest_ptr = Box::new(return_manifest);
 
-        return Box::into_raw(return_manifest_ptr);
+        Box::into_raw(return_manifest_ptr)
 
     }
 
@@ -419,6 +1260,7 @@ pub extern "C" fn load_asset_manifest(filepath:*const c_char) -> *mut RawCAssetM
 
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn load_asset_chunk(filepath_chunk:*const c_char, filepath_manifest:*const c_char) -> *mut RawCAssetChunk {
     let filestr = unsafe {
         CStr::from_ptr(filepath_chunk).to_str().unwrap()
@@ -442,7 +1284,8 @@ pub extern "C" fn load_asset_chunk(filepath_chunk:*const c_char, filepath_manife
 
     let chunk_data_len = chunk_data_block.len();
 
-    let boxed_chunk_data = Box::new(chunk_data_block.as_slice());
+    // Leak The Chunk Data So It Outlives This Call, The Assets Point Into It
+    let boxed_chunk_data = Box::leak(chunk_data_block.into_boxed_slice());
 
     let manifest_str = unsafe {
         CStr::from_ptr(filepath_manifest).to_str().unwrap()
@@ -464,17 +1307,51 @@ pub extern "C" fn load_asset_chunk(filepath_chunk:*const c_char, filepath_manife
         manifest: manifest_data
     };
 
-    return Box::into_raw(Box::new(raw_chunk));   
+    Box::into_raw(Box::new(raw_chunk))
+
+}
+
+
+/**
+ * API: - Free A Manifest From `load_asset_manifest`, Every Pointer Into It Is Left Dangling
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn free_asset_manifest(manifest:*mut RawCAssetManifest) {
+    if !manifest.is_null() {
+        unsafe {
+            Box::from_raw(manifest).free();
+        }
+    }
+}
 
+
+/**
+ * API: - Free A Chunk And Its Manifest, Whichever Function Loaded It.
+ * Assets, Sprites And Animations Got From The Chunk Are Left Dangling.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn free_asset_chunk(asset_chunk:*mut RawCAssetChunk) {
+    if asset_chunk.is_null() {
+        return;
+    }
+    let chunk = unsafe { Box::from_raw(asset_chunk) };
+    if !chunk.data.is_null() {
+        drop(unsafe { take_boxed_slice(chunk.data as *const u8, chunk.size) });
+    }
+    free_asset_manifest(chunk.manifest);
 }
 
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn get_asset(asset_chunk:*const RawCAssetChunk, name:*const c_char) -> *mut RawCAsset {
 
-    let rust_asset_chunk: Box<RawCAssetChunk> = unsafe { Box::from_raw(asset_chunk as *mut RawCAssetChunk) };
+    // Borrow The Chunk And Manifest, They Still Belong To The Caller And Must Outlive This Call
+    let rust_asset_chunk: &RawCAssetChunk = unsafe { &*asset_chunk };
     
-    let rust_asset_manifest: Box<RawCAssetManifest> = unsafe { Box::from_raw(rust_asset_chunk.manifest as *mut RawCAssetManifest) };
+    let rust_asset_manifest: &RawCAssetManifest = unsafe { &*rust_asset_chunk.manifest };
 
 
     // Okay lets get the vector of assets back from the struct C sent us.
@@ -503,12 +1380,1699 @@ pub extern "C" fn get_asset(asset_chunk:*const RawCAssetChunk, name:*const c_cha
     println!("Asset Found: {:?}", asset.unwrap());
 
     // TODO: Do this better or something
-    // Im cloning to shut the compiler up.
-    let mut asset = asset.unwrap().clone();
-    asset.data = unsafe {rust_asset_chunk.data.offset(asset.location as isize) as *mut c_void};
+    let mut asset = *asset.unwrap();
+    asset.data = unsafe {rust_asset_chunk.data.add(asset.location)};
+    apply_override(&mut asset);
     println!("Asset Data Pointer: {:?}", asset.data);
 
-    let raw_asset = Box::new(asset.clone());
+    let raw_asset = Box::new(asset);
     Box::into_raw(raw_asset)
 
 }
+
+
+/**
+ * API: - Find Where A Sprite Lives In Its Atlas Page And Fill `out`, Nothing Is Allocated.
+ * The Names In `out` Belong To The Chunk's Manifest. Returns False If The Sprite Isn't In The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_sprite_rect(asset_chunk:*const RawCAssetChunk, name:*const c_char, out:*mut RawCSpriteRect) -> bool {
+    if asset_chunk.is_null() || name.is_null() || out.is_null() {
+        println!("[-] Error: Invalid Asset Chunk, Sprite Name Or Output");
+        return false;
+    }
+
+    let manifest = unsafe { &*(*asset_chunk).manifest };
+    let sprite_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    let sprites = unsafe { std::slice::from_raw_parts(manifest.sprites, manifest.sprite_count) };
+
+    let sprite = sprites.iter().find(|sprite| {
+        unsafe { CStr::from_ptr(sprite.name).to_str().unwrap() == sprite_name }
+    });
+
+    match sprite {
+        Some(sprite) => {
+            warn_sprite_override(sprite_name);
+            unsafe { *out = *sprite };
+            true
+        },
+        None => {
+            println!("[-] Error: Sprite Not Found: {}", sprite_name);
+            false
+        }
+    }
+}
+
+
+/**
+ * API: - Find An Animation By Name And Fill `out`, Nothing Is Allocated.
+ * The Name, Image And Frames In `out` Belong To The Chunk's Manifest. Returns False If The Animation Isn't In The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_animation(asset_chunk:*const RawCAssetChunk, name:*const c_char, out:*mut RawCAnimation) -> bool {
+    if asset_chunk.is_null() || name.is_null() || out.is_null() {
+        println!("[-] Error: Invalid Asset Chunk, Animation Name Or Output");
+        return false;
+    }
+
+    let manifest = unsafe { &*(*asset_chunk).manifest };
+    let animation_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    let animations = unsafe { std::slice::from_raw_parts(manifest.animations, manifest.animation_count) };
+
+    let animation = animations.iter().find(|animation| {
+        unsafe { CStr::from_ptr(animation.name).to_str().unwrap() == animation_name }
+    });
+
+    match animation {
+        Some(animation) => {
+            unsafe { *out = *animation };
+            true
+        },
+        None => {
+            println!("[-] Error: Animation Not Found: {}", animation_name);
+            false
+        }
+    }
+}
+
+
+/**
+ * API: - Look Up A Metadata Value Of An Asset (e.g. "width", "pixel_format"), Returns NULL If The Key Isn't Set
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_asset_metadata(asset:*const RawCAsset, key:*const c_char) -> *const c_char {
+    if asset.is_null() || key.is_null() {
+        println!("[-] Error: Invalid Asset Or Metadata Key");
+        return std::ptr::null();
+    }
+
+    let asset = unsafe { &*asset };
+    let key = unsafe { CStr::from_ptr(key).to_str().unwrap() };
+    if asset.metadata.is_null() {
+        return std::ptr::null();
+    }
+    let metadata = unsafe { std::slice::from_raw_parts(asset.metadata, asset.metadata_count) };
+
+    metadata.iter()
+        .find(|entry| unsafe { CStr::from_ptr(entry.key).to_str().unwrap() == key })
+        .map(|entry| entry.value)
+        .unwrap_or(std::ptr::null())
+}
+
+
+/**
+ * API: - Image Properties Recorded At Pack Time
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCImageInfo {
+    pub width: u32,
+    pub height: u32,
+    pub bit_depth: u8,
+    pub color_type: *const c_char, // "grayscale", "grayscale_alpha", "rgb", "rgba" Or "indexed"
+    pub frame_count: u32,
+}
+
+
+/**
+ * API: - Fill `out` With The Image Properties Of An Asset, Nothing Is Allocated.
+ * Returns False If The Asset Isn't An Image Or Has None Recorded.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_image_info(asset:*const RawCAsset, out:*mut RawCImageInfo) -> bool {
+    if asset.is_null() || out.is_null() || unsafe { (*asset).kind } != RawCAssetKind::Image {
+        return false;
+    }
+
+    match raw_image_info(asset) {
+        Some(info) => {
+            unsafe { *out = info };
+            true
+        },
+        None => false,
+    }
+}
+
+/**
+ * Look Up A Metadata Value Of A C Asset
+ */
+fn raw_metadata(asset:*const RawCAsset, key:&str) -> Option<*const c_char> {
+    let key = CString::new(key).unwrap();
+    let value = get_asset_metadata(asset, key.as_ptr());
+    (!value.is_null()).then_some(value)
+}
+
+/**
+ * Look Up A Numeric Metadata Value Of A C Asset
+ */
+fn raw_metadata_number<T:FromStr>(asset:*const RawCAsset, key:&str) -> Option<T> {
+    let value = raw_metadata(asset, key)?;
+    unsafe { CStr::from_ptr(value) }.to_str().ok()?.parse().ok()
+}
+
+/**
+ * Collect The Image Properties From An Asset's Metadata Array
+ */
+fn raw_image_info(asset:*const RawCAsset) -> Option<RawCImageInfo> {
+    Some(RawCImageInfo {
+        width: raw_metadata_number(asset, "width")?,
+        height: raw_metadata_number(asset, "height")?,
+        bit_depth: raw_metadata_number(asset, "bit_depth")?,
+        color_type: raw_metadata(asset, "color_type")?,
+        frame_count: raw_metadata_number(asset, "frame_count")?,
+    })
+}
+
+
+/**
+ * API: - Audio Properties Recorded At Pack Time
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAudioInfo {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub bit_depth: u16, // 0 For Lossy Formats Without One
+    pub duration_ms: u64,
+}
+
+
+/**
+ * API: - Fill `out` With The Audio Properties Of An Asset, Nothing Is Allocated.
+ * Returns False If The Asset Isn't Audio Or Has None Recorded.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_audio_info(asset:*const RawCAsset, out:*mut RawCAudioInfo) -> bool {
+    if asset.is_null() || out.is_null() || unsafe { (*asset).kind } != RawCAssetKind::Audio {
+        return false;
+    }
+
+    match raw_audio_info(asset) {
+        Some(info) => {
+            unsafe { *out = info };
+            true
+        },
+        None => false,
+    }
+}
+
+/**
+ * Collect The Audio Properties From An Asset's Metadata Array
+ */
+fn raw_audio_info(asset:*const RawCAsset) -> Option<RawCAudioInfo> {
+    Some(RawCAudioInfo {
+        sample_rate: raw_metadata_number(asset, "sample_rate")?,
+        channels: raw_metadata_number(asset, "channels")?,
+        bit_depth: raw_metadata_number(asset, "bit_depth")?,
+        duration_ms: raw_metadata_number(asset, "duration_ms")?,
+    })
+}
+
+
+/**
+ * Anything A C Stream Handle Can Pull Data From
+ */
+trait ReadSeek: Read + Seek {}
+impl<T:Read + Seek> ReadSeek for T {}
+
+/**
+ * API: - Seek Origins, Same Values As `SEEK_SET`, `SEEK_CUR` And `SEEK_END`
+ */
+pub const ASSET_SEEK_SET:i32 = 0;
+pub const ASSET_SEEK_CUR:i32 = 1;
+pub const ASSET_SEEK_END:i32 = 2;
+
+/**
+ * API: - A Stream Over A Single Asset, For Decoders That Pull Data Incrementally (Music, Video).
+ * Call The Callbacks With The Stream Itself, e.g. `stream->read(stream, buffer, 4096)`.
+ */
+#[repr(C)]
+#[derive(Debug)]
+pub struct RawCAssetStream {
+    pub user_data: *mut c_void, // The Underlying Rust Stream, Opaque To C
+    pub size: u64, // Size Of The Asset In Bytes
+    pub read: extern "C" fn(stream:*mut RawCAssetStream, buffer:*mut c_void, count:usize) -> usize, // Bytes Read, Less Than `count` Only At The End
+    pub seek: extern "C" fn(stream:*mut RawCAssetStream, offset:i64, origin:i32) -> i32, // 0 On Success, -1 On Error
+    pub tell: extern "C" fn(stream:*mut RawCAssetStream) -> i64, // Current Position, -1 On Error
+}
+
+impl RawCAssetStream {
+
+    /**
+     * Wrap A Rust Stream In A Heap Allocated Handle For C, Freed By `close_asset_stream`
+     */
+    fn into_raw<R:Read + Seek + 'static>(stream:AssetStream<R>) -> *mut RawCAssetStream {
+        let size = stream.size();
+        let inner:Box<dyn ReadSeek> = Box::new(stream);
+        Box::into_raw(Box::new(RawCAssetStream {
+            user_data: Box::into_raw(Box::new(inner)) as *mut c_void,
+            size,
+            read: asset_stream_read,
+            seek: asset_stream_seek,
+            tell: asset_stream_tell,
+        }))
+    }
+
+    /**
+     * Borrow The Rust Stream Behind A Handle
+     */
+    fn inner<'a>(stream:*mut RawCAssetStream) -> Option<&'a mut Box<dyn ReadSeek>> {
+        if stream.is_null() {
+            return None;
+        }
+        unsafe { ((*stream).user_data as *mut Box<dyn ReadSeek>).as_mut() }
+    }
+}
+
+extern "C" fn asset_stream_read(stream:*mut RawCAssetStream, buffer:*mut c_void, count:usize) -> usize {
+    let Some(inner) = RawCAssetStream::inner(stream) else {
+        return 0;
+    };
+    if buffer.is_null() {
+        return 0;
+    }
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer as *mut u8, count) };
+    // Keep Reading Until The Buffer Is Full, Decoders Treat A Short Read As The End Of The Stream
+    let mut total = 0;
+    while total < count {
+        match inner.read(&mut buffer[total..]) {
+            Ok(0) => break,
+            Ok(read) => total += read,
+            Err(e) => {
+                println!("[-] Error: Failed To Read Asset Stream: {}", e);
+                break;
+            }
+        }
+    }
+    total
+}
+
+extern "C" fn asset_stream_seek(stream:*mut RawCAssetStream, offset:i64, origin:i32) -> i32 {
+    let Some(inner) = RawCAssetStream::inner(stream) else {
+        return -1;
+    };
+    let position = match origin {
+        ASSET_SEEK_SET if offset >= 0 => SeekFrom::Start(offset as u64),
+        ASSET_SEEK_CUR => SeekFrom::Current(offset),
+        ASSET_SEEK_END => SeekFrom::End(offset),
+        _ => return -1,
+    };
+    match inner.seek(position) {
+        Ok(_) => 0,
+        Err(_) => -1,
+    }
+}
+
+extern "C" fn asset_stream_tell(stream:*mut RawCAssetStream) -> i64 {
+    RawCAssetStream::inner(stream)
+        .and_then(|inner| inner.stream_position().ok())
+        .map(|position| position as i64)
+        .unwrap_or(-1)
+}
+
+
+/**
+ * API: - Open A Stream Over An Asset Of A Loaded Chunk, Nothing Is Copied.
+ * The Chunk Must Outlive The Stream. Returns NULL If The Asset Isn't In The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn open_asset_stream(asset_chunk:*const RawCAssetChunk, name:*const c_char) -> *mut RawCAssetStream {
+    if asset_chunk.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Asset Chunk Or Asset Name");
+        return std::ptr::null_mut();
+    }
+
+    let chunk = unsafe { &*asset_chunk };
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+
+    match find_raw_asset(chunk, asset_name) {
+        Some(asset) => {
+            let data:&'static [u8] = unsafe { std::slice::from_raw_parts(chunk.data as *const u8, chunk.size) };
+            RawCAssetStream::into_raw(AssetStream::new(Cursor::new(data), asset.location as u64, asset.size as u64))
+        },
+        None => {
+            println!("[-] Error: Asset Not Found: {}", asset_name);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Open A Stream Over An Asset Straight From The Chunk File, Without Loading The Chunk.
+ * Only The Bytes Actually Read Are Loaded From Disk. Returns NULL If The Asset Or Files Can't Be Found.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn open_asset_stream_file(filepath_chunk:*const c_char, filepath_manifest:*const c_char, name:*const c_char) -> *mut RawCAssetStream {
+    if filepath_chunk.is_null() || filepath_manifest.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Chunk Path, Manifest Path Or Asset Name");
+        return std::ptr::null_mut();
+    }
+
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+
+    let stream = std::fs::read_to_string(manifest_path)
+        .map_err(|e| format!("Failed To Load Asset Manifest: {}", e))
+        .and_then(|json| AssetManifest::try_from_json(&json))
+        .and_then(|manifest| manifest.open_asset_stream(chunk_path, asset_name));
+
+    match stream {
+        Ok(stream) => RawCAssetStream::into_raw(stream),
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Close A Stream Opened With `open_asset_stream` Or `open_asset_stream_file`
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn close_asset_stream(stream:*mut RawCAssetStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = unsafe { Box::from_raw(stream) };
+    drop(unsafe { Box::from_raw(stream.user_data as *mut Box<dyn ReadSeek>) });
+}
+
+
+/**
+ * Find An Asset Of A Loaded C Chunk By Name
+ */
+fn find_raw_asset<'a>(chunk:&'a RawCAssetChunk, name:&str) -> Option<&'a RawCAsset> {
+    let manifest = unsafe { &*chunk.manifest };
+    let assets = unsafe { std::slice::from_raw_parts(manifest.assets, manifest.asset_count) };
+    assets.iter().map(|asset| unsafe { &**asset }).find(|asset| {
+        unsafe { CStr::from_ptr(asset.name).to_str().unwrap() == name }
+    })
+}
+
+
+/**
+ * API: - A Tileset Used By A Tilemap, `image` Is The Name Of The Tileset Image Asset (Or Atlas Sprite)
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCTileset {
+    pub first_gid: u32,
+    pub name: *const c_char,
+    pub image: *const c_char,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub columns: u32,
+    pub tile_count: u32,
+}
+
+/**
+ * API: - A Layer Of Tiles, Row Major. 0 Is Empty, The Top 4 Bits Of A Tile Are Tiled's Flip Flags
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCTileLayer {
+    pub name: *const c_char,
+    pub width: u32,
+    pub height: u32,
+    pub tiles: *const u32, // width * height Global Tile IDs
+    pub properties: *const RawCAssetMetadata,
+    pub property_count: usize,
+}
+
+/**
+ * API: - An Object Of An Object Layer, Positions Are In Pixels
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCTilemapObject {
+    pub id: u32,
+    pub name: *const c_char,
+    pub class_name: *const c_char, // Tiled's `class`
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub rotation: f32,
+    pub gid: u32,
+    pub properties: *const RawCAssetMetadata,
+    pub property_count: usize,
+}
+
+/**
+ * API: - A Layer Of Objects
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCObjectLayer {
+    pub name: *const c_char,
+    pub objects: *const RawCTilemapObject,
+    pub object_count: usize,
+    pub properties: *const RawCAssetMetadata,
+    pub property_count: usize,
+}
+
+/**
+ * API: - A Tilemap Imported From Tiled
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCTilemap {
+    pub width: u32,
+    pub height: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub tilesets: *const RawCTileset,
+    pub tileset_count: usize,
+    pub layers: *const RawCTileLayer,
+    pub layer_count: usize,
+    pub object_layers: *const RawCObjectLayer,
+    pub object_layer_count: usize,
+    pub properties: *const RawCAssetMetadata,
+    pub property_count: usize,
+}
+
+impl RawCTilemap {
+
+    /**
+     * Build The C Representation Of A Tilemap, Everything Is Leaked Until `free_tilemap`
+     */
+    pub fn from_tilemap(map:&Tilemap) -> Self {
+        let c_string = |text:&str| CString::new(text).unwrap().into_raw() as *const c_char;
+
+        let tilesets = Box::leak(map.tilesets.iter().map(|tileset| RawCTileset {
+            first_gid: tileset.first_gid,
+            name: c_string(&tileset.name),
+            image: c_string(&tileset.image),
+            tile_width: tileset.tile_width,
+            tile_height: tileset.tile_height,
+            columns: tileset.columns,
+            tile_count: tileset.tile_count,
+        }).collect::<Vec<RawCTileset>>().into_boxed_slice());
+
+        let layers = Box::leak(map.layers.iter().map(|layer| {
+            let (properties, property_count) = RawCAssetMetadata::leak_map(&layer.properties);
+            RawCTileLayer {
+                name: c_string(&layer.name),
+                width: layer.width,
+                height: layer.height,
+                tiles: Box::leak(layer.tiles.clone().into_boxed_slice()).as_ptr(),
+                properties,
+                property_count,
+            }
+        }).collect::<Vec<RawCTileLayer>>().into_boxed_slice());
+
+        let object_layers = Box::leak(map.object_layers.iter().map(|layer| {
+            let objects = Box::leak(layer.objects.iter().map(|object| {
+                let (properties, property_count) = RawCAssetMetadata::leak_map(&object.properties);
+                RawCTilemapObject {
+                    id: object.id,
+                    name: c_string(&object.name),
+                    class_name: c_string(&object.class),
+                    x: object.x,
+                    y: object.y,
+                    width: object.width,
+                    height: object.height,
+                    rotation: object.rotation,
+                    gid: object.gid,
+                    properties,
+                    property_count,
+                }
+            }).collect::<Vec<RawCTilemapObject>>().into_boxed_slice());
+            let (properties, property_count) = RawCAssetMetadata::leak_map(&layer.properties);
+            RawCObjectLayer {
+                name: c_string(&layer.name),
+                objects: objects.as_ptr(),
+                object_count: objects.len(),
+                properties,
+                property_count,
+            }
+        }).collect::<Vec<RawCObjectLayer>>().into_boxed_slice());
+
+        let (properties, property_count) = RawCAssetMetadata::leak_map(&map.properties);
+        RawCTilemap {
+            width: map.width,
+            height: map.height,
+            tile_width: map.tile_width,
+            tile_height: map.tile_height,
+            tilesets: tilesets.as_ptr(),
+            tileset_count: tilesets.len(),
+            layers: layers.as_ptr(),
+            layer_count: layers.len(),
+            object_layers: object_layers.as_ptr(),
+            object_layer_count: object_layers.len(),
+            properties,
+            property_count,
+        }
+    }
+
+    /**
+     * Free Everything `from_tilemap` Leaked
+     */
+    unsafe fn free(&self) {
+        for tileset in take_boxed_slice(self.tilesets, self.tileset_count).iter() {
+            free_c_string(tileset.name);
+            free_c_string(tileset.image);
+        }
+        for layer in take_boxed_slice(self.layers, self.layer_count).iter() {
+            free_c_string(layer.name);
+            drop(take_boxed_slice(layer.tiles, layer.width as usize * layer.height as usize));
+            RawCAssetMetadata::free_map(layer.properties, layer.property_count);
+        }
+        for layer in take_boxed_slice(self.object_layers, self.object_layer_count).iter() {
+            free_c_string(layer.name);
+            for object in take_boxed_slice(layer.objects, layer.object_count).iter() {
+                free_c_string(object.name);
+                free_c_string(object.class_name);
+                RawCAssetMetadata::free_map(object.properties, object.property_count);
+            }
+            RawCAssetMetadata::free_map(layer.properties, layer.property_count);
+        }
+        RawCAssetMetadata::free_map(self.properties, self.property_count);
+    }
+}
+
+
+/**
+ * API: - Decode A Tilemap Asset Of A Loaded Chunk, Returns NULL If It Isn't In The Chunk Or Isn't A Tilemap.
+ * Each Call Decodes A New Copy, Free It With `free_tilemap`.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_tilemap(asset_chunk:*const RawCAssetChunk, name:*const c_char) -> *mut RawCTilemap {
+    if asset_chunk.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Asset Chunk Or Tilemap Name");
+        return std::ptr::null_mut();
+    }
+
+    let chunk = unsafe { &*asset_chunk };
+    let tilemap_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    let Some(asset) = find_raw_asset(chunk, tilemap_name) else {
+        println!("[-] Error: Tilemap Not Found: {}", tilemap_name);
+        return std::ptr::null_mut();
+    };
+
+    let data = unsafe { std::slice::from_raw_parts((chunk.data as *const u8).add(asset.location), asset.size) };
+    match Tilemap::from_bytes(data) {
+        Ok(map) => Box::into_raw(Box::new(RawCTilemap::from_tilemap(&map))),
+        Err(e) => {
+            println!("[-] Error: Failed To Read Tilemap {}: {}", tilemap_name, e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Free A Tilemap From `get_tilemap`
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn free_tilemap(tilemap:*mut RawCTilemap) {
+    if !tilemap.is_null() {
+        unsafe {
+            Box::from_raw(tilemap).free();
+        }
+    }
+}
+
+
+/**
+ * API: - Vertex Layout Flags Of A Mesh
+ */
+pub const MESH_LAYOUT_POSITION:u32 = Mesh::LAYOUT_POSITION;
+pub const MESH_LAYOUT_NORMAL:u32 = Mesh::LAYOUT_NORMAL;
+pub const MESH_LAYOUT_UV:u32 = Mesh::LAYOUT_UV;
+
+/**
+ * API: - A Run Of Indices Drawn With One Material
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCSubMesh {
+    pub material: *const c_char,
+    pub index_start: u32,
+    pub index_count: u32,
+}
+
+/**
+ * API: - An Indexed Triangle Mesh, Vertices Are Interleaved Position (3 Floats), Normal (3) And UV (2)
+ * With Only The Parts Set In `layout`
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCMesh {
+    pub layout: u32, // MESH_LAYOUT_* Flags
+    pub vertex_stride: u32, // Bytes Per Vertex
+    pub vertices: *const f32,
+    pub vertex_count: usize,
+    pub indices: *const u32,
+    pub index_count: usize,
+    pub bounds_min: [f32; 3],
+    pub bounds_max: [f32; 3],
+    pub submeshes: *const RawCSubMesh,
+    pub submesh_count: usize,
+    pub material_libraries: *const *const c_char, // Asset Names Of The Material Libraries
+    pub material_library_count: usize,
+}
+
+impl RawCMesh {
+
+    /**
+     * Build The C Representation Of A Mesh, Everything Is Leaked Until `free_mesh`
+     */
+    pub fn from_mesh(mesh:&Mesh) -> Self {
+        let submeshes = Box::leak(mesh.submeshes.iter().map(|submesh| RawCSubMesh {
+            material: CString::new(submesh.material.as_str()).unwrap().into_raw(),
+            index_start: submesh.index_start,
+            index_count: submesh.index_count,
+        }).collect::<Vec<RawCSubMesh>>().into_boxed_slice());
+        let material_libraries = Box::leak(mesh.material_libraries.iter()
+            .map(|library| CString::new(library.as_str()).unwrap().into_raw() as *const c_char)
+            .collect::<Vec<*const c_char>>().into_boxed_slice());
+
+        RawCMesh {
+            layout: mesh.layout,
+            vertex_stride: (mesh.vertex_stride() * std::mem::size_of::<f32>()) as u32,
+            vertices: Box::leak(mesh.vertices.clone().into_boxed_slice()).as_ptr(),
+            vertex_count: mesh.vertex_count(),
+            indices: Box::leak(mesh.indices.clone().into_boxed_slice()).as_ptr(),
+            index_count: mesh.indices.len(),
+            bounds_min: mesh.bounds_min,
+            bounds_max: mesh.bounds_max,
+            submeshes: submeshes.as_ptr(),
+            submesh_count: submeshes.len(),
+            material_libraries: material_libraries.as_ptr(),
+            material_library_count: material_libraries.len(),
+        }
+    }
+
+    /**
+     * Free Everything `from_mesh` Leaked
+     */
+    unsafe fn free(&self) {
+        let floats_per_vertex = self.vertex_stride as usize / std::mem::size_of::<f32>();
+        drop(take_boxed_slice(self.vertices, self.vertex_count * floats_per_vertex));
+        drop(take_boxed_slice(self.indices, self.index_count));
+        for submesh in take_boxed_slice(self.submeshes, self.submesh_count).iter() {
+            free_c_string(submesh.material);
+        }
+        free_c_strings(self.material_libraries, self.material_library_count);
+    }
+}
+
+
+/**
+ * API: - Decode A Mesh Asset Of A Loaded Chunk, Returns NULL If It Isn't In The Chunk Or Isn't A Mesh.
+ * Each Call Decodes A New Copy, Free It With `free_mesh`.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_mesh(asset_chunk:*const RawCAssetChunk, name:*const c_char) -> *mut RawCMesh {
+    if asset_chunk.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Asset Chunk Or Mesh Name");
+        return std::ptr::null_mut();
+    }
+
+    let chunk = unsafe { &*asset_chunk };
+    let mesh_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    let Some(asset) = find_raw_asset(chunk, mesh_name) else {
+        println!("[-] Error: Mesh Not Found: {}", mesh_name);
+        return std::ptr::null_mut();
+    };
+
+    let data = unsafe { std::slice::from_raw_parts((chunk.data as *const u8).add(asset.location), asset.size) };
+    match Mesh::from_bytes(data) {
+        Ok(mesh) => Box::into_raw(Box::new(RawCMesh::from_mesh(&mesh))),
+        Err(e) => {
+            println!("[-] Error: Failed To Read Mesh {}: {}", mesh_name, e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Free A Mesh From `get_mesh`
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn free_mesh(mesh:*mut RawCMesh) {
+    if !mesh.is_null() {
+        unsafe {
+            Box::from_raw(mesh).free();
+        }
+    }
+}
+
+
+/**
+ * API: - Assets Of A Loaded Chunk In The Order They Should Be Loaded, Dependencies First.
+ * The Pointers Are Into The Chunk's Manifest. Free It With `free_asset_load_order`.
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAssetLoadOrder {
+    pub assets: *const *mut RawCAsset,
+    pub asset_count: usize,
+}
+
+
+/**
+ * API: - Get An Asset And Everything It Depends On, Directly Or Not, Dependencies First.
+ * Returns NULL If The Asset Isn't In The Chunk Or Its Dependencies Form A Cycle.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_asset_load_order(asset_chunk:*const RawCAssetChunk, name:*const c_char) -> *mut RawCAssetLoadOrder {
+    if asset_chunk.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Asset Chunk Or Asset Name");
+        return std::ptr::null_mut();
+    }
+
+    let manifest = unsafe { &*(*asset_chunk).manifest };
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    let assets = unsafe { std::slice::from_raw_parts(manifest.assets, manifest.asset_count) };
+    let nodes:Vec<(&str, Vec<&str>)> = assets.iter().map(|asset| {
+        let asset = unsafe { &**asset };
+        let dependencies = unsafe { std::slice::from_raw_parts(asset.dependencies, asset.dependency_count) };
+        let name = unsafe { CStr::from_ptr(asset.name).to_str().unwrap() };
+        (name, dependencies.iter().map(|dependency| unsafe { CStr::from_ptr(*dependency).to_str().unwrap() }).collect())
+    }).collect();
+
+    let Some(root) = nodes.iter().position(|(name, _)| *name == asset_name) else {
+        println!("[-] Error: Asset Not Found: {}", asset_name);
+        return std::ptr::null_mut();
+    };
+    match dependency::topological_order(&nodes, [root]) {
+        Ok(order) => {
+            let ordered = Box::leak(order.into_iter().map(|index| assets[index]).collect::<Vec<*mut RawCAsset>>().into_boxed_slice());
+            Box::into_raw(Box::new(RawCAssetLoadOrder { assets: ordered.as_ptr(), asset_count: ordered.len() }))
+        },
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Free A Load Order From `get_asset_load_order`, The Assets It Points To Belong To The Chunk And Are Left Alone
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn free_asset_load_order(load_order:*mut RawCAssetLoadOrder) {
+    if !load_order.is_null() {
+        let load_order = unsafe { Box::from_raw(load_order) };
+        drop(unsafe { take_boxed_slice(load_order.assets, load_order.asset_count) });
+    }
+}
+
+
+/**
+ * API: - Read Only One Group's Byte Range Out Of A Chunk File, Into One Contiguous Block.
+ * The Result Is A Chunk Of Just The Group, So `get_asset` And Friends Work On It.
+ * Returns NULL If The Group Doesn't Exist Or Can't Be Read.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn load_asset_group(filepath_chunk:*const c_char, filepath_manifest:*const c_char, group:*const c_char) -> *mut RawCAssetChunk {
+    if filepath_chunk.is_null() || filepath_manifest.is_null() || group.is_null() {
+        println!("[-] Error: Invalid Chunk Path, Manifest Path Or Group Name");
+        return std::ptr::null_mut();
+    }
+
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    let group_name = unsafe { CStr::from_ptr(group).to_str().unwrap() };
+
+    match LoadedChunk::load_group(chunk_path, manifest_path, group_name) {
+        Ok(loaded) => RawCAssetChunk::from_loaded(&loaded.manifest, loaded.data),
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Create An Empty Asset VFS, Chunks Are Added With `vfs_mount`
+ */
+#[no_mangle]
+pub extern "C" fn create_asset_vfs() -> *mut AssetVfs {
+    Box::into_raw(Box::new(AssetVfs::new()))
+}
+
+
+/**
+ * API: - Destroy An Asset VFS And Everything Mounted In It, Assets Looked Up From It Become Invalid
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn destroy_asset_vfs(vfs:*mut AssetVfs) {
+    if !vfs.is_null() {
+        drop(unsafe { Box::from_raw(vfs) });
+    }
+}
+
+
+/**
+ * API: - Mount A Chunk And Its Manifest, Higher Priorities Override Lower Ones And Later Mounts Win Ties.
+ * Returns A Handle For `vfs_unmount`, Or -1 If The Chunk Can't Be Loaded.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn vfs_mount(vfs:*mut AssetVfs, filepath_chunk:*const c_char, filepath_manifest:*const c_char, priority:i32) -> i64 {
+    if vfs.is_null() || filepath_chunk.is_null() || filepath_manifest.is_null() {
+        println!("[-] Error: Invalid VFS, Chunk Path Or Manifest Path");
+        return -1;
+    }
+
+    let vfs = unsafe { &mut *vfs };
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    match vfs.mount(chunk_path, manifest_path, priority) {
+        Ok(id) => id as i64,
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            -1
+        }
+    }
+}
+
+
+/**
+ * API: - Unmount A Chunk, Assets Looked Up From It Become Invalid. Returns False If The Handle Isn't Mounted.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn vfs_unmount(vfs:*mut AssetVfs, mount:i64) -> bool {
+    if vfs.is_null() {
+        return false;
+    }
+    let Ok(mount) = u32::try_from(mount) else {
+        println!("[-] Error: Invalid Mount Handle {}", mount);
+        return false;
+    };
+    unsafe { &mut *vfs }.unmount(mount)
+}
+
+
+/**
+ * API: - Find An Asset In The Highest Priority Chunk That Has It And Fill `out`, Nothing Is Allocated.
+ * `data` Points Into That Chunk, Valid Until It Is Unmounted. Returns False If No Mounted Chunk Has The Asset.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn vfs_lookup(vfs:*const AssetVfs, name:*const c_char, out:*mut RawCAssetView) -> bool {
+    if vfs.is_null() || name.is_null() || out.is_null() {
+        println!("[-] Error: Invalid VFS, Asset Name Or Output");
+        return false;
+    }
+
+    let vfs = unsafe { &*vfs };
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    match vfs.lookup(asset_name) {
+        Some(entry) => {
+            let view = match override_asset(&entry.asset.get_virtual_path()) {
+                Some(found) => RawCAssetView::new(found, found.get_data().as_deref().unwrap_or_default()),
+                None => RawCAssetView::new(entry.asset, entry.data),
+            };
+            unsafe { *out = view };
+            true
+        },
+        None => {
+            println!("[-] Error: Asset Not Found: {}", asset_name);
+            false
+        }
+    }
+}
+
+
+/**
+ * The Loose File Overriding An Asset, Never One In Builds Without The `overrides` Feature
+ */
+#[cfg(feature = "overrides")]
+fn override_asset(virtual_path:&str) -> Option<&'static Asset> {
+    read_override(virtual_path)
+}
+
+#[cfg(not(feature = "overrides"))]
+fn override_asset(_virtual_path:&str) -> Option<&'static Asset> {
+    None
+}
+
+
+/**
+ * The C Form Of Each Loose File Overriding An Asset, Built Once Since C Holds Pointers Into It
+ */
+#[cfg(feature = "overrides")]
+struct RawCOverrides(BTreeMap<usize, RawCAsset>); // Keyed By The Address Of The Loose File's `Asset`
+
+#[cfg(feature = "overrides")]
+unsafe impl Send for RawCOverrides {} // The Pointers Are Leaked For Good And Never Written Through
+
+#[cfg(feature = "overrides")]
+static RAW_OVERRIDES: std::sync::Mutex<RawCOverrides> = std::sync::Mutex::new(RawCOverrides(BTreeMap::new()));
+
+
+/**
+ * The C Form Of The Loose File Overriding An Asset, Never One In Builds Without The `overrides` Feature
+ */
+#[cfg(feature = "overrides")]
+fn raw_override(virtual_path:&str) -> Option<RawCAsset> {
+    let found = override_asset(virtual_path)?;
+    let mut raw_overrides = RAW_OVERRIDES.lock().unwrap();
+    let raw = raw_overrides.0.entry(found as *const Asset as usize).or_insert_with(|| {
+        let mut raw = RawCAsset::from_asset(found);
+        raw.data = found.get_data().as_deref().unwrap_or_default().as_ptr() as *mut c_void;
+        raw
+    });
+    Some(*raw)
+}
+
+#[cfg(not(feature = "overrides"))]
+fn raw_override(_virtual_path:&str) -> Option<RawCAsset> {
+    None
+}
+
+
+/**
+ * Point An Asset At The Loose File Overriding It, If There Is One.
+ * The Kind, Format, Extension And Metadata Become The Loose File's, Which Can Differ From What Was Packed.
+ */
+fn apply_override(asset:&mut RawCAsset) {
+    let virtual_path = unsafe { CStr::from_ptr(asset.virtual_path).to_str().unwrap() };
+    if let Some(raw) = raw_override(virtual_path) {
+        *asset = RawCAsset {
+            data: raw.data,
+            size: raw.size,
+            kind: raw.kind,
+            kind_name: raw.kind_name,
+            format: raw.format,
+            extension: raw.extension,
+            metadata: raw.metadata,
+            metadata_count: raw.metadata_count,
+            ..*asset
+        };
+    }
+}
+
+
+/**
+ * Sprites Packed Into An Atlas Page Aren't Assets Of Their Own, So Warn If A Loose File Tries To Override One
+ */
+#[cfg(feature = "overrides")]
+fn warn_sprite_override(sprite:&str) {
+    overrides::warn_unusable_override(&virtual_path(sprite, ".png"), "Sprites Packed Into An Atlas Page Can Only Be Overridden Through The Page");
+}
+
+#[cfg(not(feature = "overrides"))]
+fn warn_sprite_override(_sprite:&str) {}
+
+
+/**
+ * API: - Check A Directory For Loose Files Before The Packed Chunk When Getting Assets (For Mods).
+ * `hero.png` In The Directory Replaces The Asset `hero` Packed From `hero.png`, Even If Packing Converted It.
+ * The Asset Then Takes The Loose File's Kind, Format And Metadata. NULL Turns Overrides Off.
+ * Does Nothing In Builds Without The `overrides` Feature.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn set_asset_override_dir(directory:*const c_char) {
+    let directory = match directory.is_null() {
+        true => None,
+        false => Some(PathBuf::from(unsafe { CStr::from_ptr(directory).to_str().unwrap() })),
+    };
+    #[cfg(feature = "overrides")]
+    set_override_directory(directory);
+    #[cfg(not(feature = "overrides"))]
+    if directory.is_some() {
+        println!("[!] Warning: Asset Overrides Are Disabled In This Build");
+    }
+}
+
+
+/**
+ * API: - Called With Every Reloaded Asset. `data` Stays Valid Until The Asset Reloads Again Or The Reloader Is Destroyed.
+ */
+#[cfg(feature = "hot-reload")]
+pub type RawCReloadCallback = extern "C" fn(name:*const c_char, data:*const c_void, size:usize, user_data:*mut c_void);
+
+
+/**
+ * The Host's `user_data`, Only Ever Handed Back To The Host's Callback
+ */
+struct RawCUserData(*mut c_void);
+
+unsafe impl Send for RawCUserData {}
+
+impl RawCUserData {
+    fn get(&self) -> *mut c_void {
+        self.0
+    }
+}
+
+
+/**
+ * API: - Watch A Source Directory For Changed Assets During Development, Call `hot_reload_poll` Every Frame.
+ * `config_path` Is An Optional Pack Config (NULL For Defaults). Returns NULL If The Directory Or Config Can't Be Read.
+ */
+#[cfg(feature = "hot-reload")]
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn create_hot_reloader(directory:*const c_char, config_path:*const c_char, callback:RawCReloadCallback, user_data:*mut c_void) -> *mut HotReloader {
+    if directory.is_null() {
+        println!("[-] Error: Invalid Hot Reload Directory");
+        return std::ptr::null_mut();
+    }
+
+    let directory = unsafe { CStr::from_ptr(directory).to_str().unwrap() };
+    let config = match config_path.is_null() {
+        true => Ok(PackConfig::default()),
+        false => PackConfig::load(unsafe { CStr::from_ptr(config_path).to_str().unwrap() }),
+    };
+    let reloader = config.and_then(|config| HotReloader::new(&PathBuf::from(directory), config));
+    let mut reloader = match reloader {
+        Ok(reloader) => reloader,
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            return std::ptr::null_mut();
+        }
+    };
+
+    // Keep The Latest Data Of Each Asset Alive For The Host
+    let user_data = RawCUserData(user_data);
+    let mut latest:std::collections::HashMap<String, (CString, Vec<u8>)> = std::collections::HashMap::new();
+    reloader.on_reload(move |asset| {
+        let entry = (CString::new(asset.get_name()).unwrap(), asset.get_data().clone().unwrap_or_default());
+        let (name, data) = &*latest.entry(asset.get_name().to_string()).insert_entry(entry).into_mut();
+        callback(name.as_ptr(), data.as_ptr() as *const c_void, data.len(), user_data.get());
+    });
+    Box::into_raw(Box::new(reloader))
+}
+
+
+/**
+ * API: - Re-Import Assets Changed Since The Last Poll, Calling The Callback For Each. Returns How Many Were Reloaded.
+ */
+#[cfg(feature = "hot-reload")]
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn hot_reload_poll(reloader:*mut HotReloader) -> usize {
+    if reloader.is_null() {
+        return 0;
+    }
+    unsafe { &mut *reloader }.poll().len()
+}
+
+
+/**
+ * API: - Stop Watching, Data Handed To The Callback Becomes Invalid
+ */
+#[cfg(feature = "hot-reload")]
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn destroy_hot_reloader(reloader:*mut HotReloader) {
+    if !reloader.is_null() {
+        drop(unsafe { Box::from_raw(reloader) });
+    }
+}
+
+
+/**
+ * API: - Connect To An Asset Server (`assetchunk serve`) At An Address Such As "192.168.1.20:7878".
+ * Returns NULL If It Can't Connect Or The Server Speaks Another Protocol Version.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn connect_asset_server(address:*const c_char) -> *mut AssetClient {
+    if address.is_null() {
+        println!("[-] Error: Invalid Asset Server Address");
+        return std::ptr::null_mut();
+    }
+
+    let address = unsafe { CStr::from_ptr(address).to_str().unwrap() };
+    match AssetClient::connect(address) {
+        Ok(client) => Box::into_raw(Box::new(client)),
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Fetch An Asset From The Asset Server, The Caller Owns It And Frees It With `free_fetched_asset`.
+ * Returns NULL If The Server Doesn't Have It Or The Connection Failed.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn fetch_asset(client:*mut AssetClient, name:*const c_char) -> *mut RawCAsset {
+    if client.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Asset Client Or Asset Name");
+        return std::ptr::null_mut();
+    }
+
+    let client = unsafe { &mut *client };
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    match client.get_asset(asset_name) {
+        Ok(Some(asset)) => {
+            let mut raw_asset = RawCAsset::from_asset(&asset);
+            let data = Box::leak(asset.get_data().clone().unwrap_or_default().into_boxed_slice());
+            raw_asset.data = data.as_mut_ptr() as *mut c_void;
+            raw_asset.size = data.len();
+            Box::into_raw(Box::new(raw_asset))
+        },
+        Ok(None) => {
+            println!("[-] Error: Asset Not Found: {}", asset_name);
+            std::ptr::null_mut()
+        },
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Free An Asset From `fetch_asset` Along With Its Data
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn free_fetched_asset(asset:*mut RawCAsset) {
+    if asset.is_null() {
+        return;
+    }
+    let asset = unsafe { Box::from_raw(asset) };
+    unsafe {
+        drop(take_boxed_slice(asset.data as *const u8, asset.size));
+        asset.free();
+    }
+}
+
+
+/**
+ * API: - Disconnect From The Asset Server
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn disconnect_asset_server(client:*mut AssetClient) {
+    if !client.is_null() {
+        drop(unsafe { Box::from_raw(client) });
+    }
+}
+
+
+/**
+ * API: - Where A Background Load Has Got To
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawCLoadStatus {
+    Queued = 0,
+    Loading = 1,
+    Loaded = 2,
+    Failed = 3,
+}
+
+impl From<LoadStatus> for RawCLoadStatus {
+    fn from(status:LoadStatus) -> Self {
+        match status {
+            LoadStatus::Queued => RawCLoadStatus::Queued,
+            LoadStatus::Loading => RawCLoadStatus::Loading,
+            LoadStatus::Loaded => RawCLoadStatus::Loaded,
+            LoadStatus::Failed => RawCLoadStatus::Failed,
+        }
+    }
+}
+
+
+/**
+ * API: - Called On A Worker Thread When A Background Load Finishes, `chunk` Is NULL If It Failed.
+ * The Chunk Then Belongs To The Callback, Which Frees It With `free_asset_chunk`, And Can't Also Be Taken From The Ticket.
+ */
+pub type RawCLoadCallback = extern "C" fn(chunk:*mut RawCAssetChunk, user_data:*mut c_void);
+
+
+/**
+ * API: - Start Loading Chunks On Worker Threads, 0 Threads Picks A Default
+ */
+#[no_mangle]
+pub extern "C" fn create_asset_loader(threads:usize) -> *mut AssetLoader {
+    let loader = match threads {
+        0 => AssetLoader::default(),
+        threads => AssetLoader::new(threads),
+    };
+    Box::into_raw(Box::new(loader))
+}
+
+
+/**
+ * API: - Finish Any Queued Loads And Stop The Worker Threads
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn destroy_asset_loader(loader:*mut AssetLoader) {
+    if !loader.is_null() {
+        drop(unsafe { Box::from_raw(loader) });
+    }
+}
+
+
+/**
+ * Wrap A C Load Callback So It Gets The Loaded Chunk, Or NULL If The Load Failed
+ */
+fn raw_load_callback(callback:Option<RawCLoadCallback>, user_data:*mut c_void) -> Option<LoadCallback> {
+    let callback = callback?;
+    let user_data = RawCUserData(user_data);
+    Some(Box::new(move |result| {
+        let chunk = match result {
+            Ok(loaded) => RawCAssetChunk::from_loaded(&loaded.manifest, loaded.data),
+            Err(e) => {
+                println!("[-] Error: {}", e);
+                std::ptr::null_mut()
+            }
+        };
+        callback(chunk, user_data.get());
+    }))
+}
+
+
+/**
+ * API: - Load A Chunk On The Loader's Worker Threads, Returning A Ticket Straight Away.
+ * `callback` Is Optional (NULL To Poll The Ticket Instead) And Always Runs On A Worker Thread. Returns NULL On Invalid Arguments.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn load_asset_chunk_async(loader:*const AssetLoader, filepath_chunk:*const c_char, filepath_manifest:*const c_char, callback:Option<RawCLoadCallback>, user_data:*mut c_void) -> *mut LoadTicket {
+    if loader.is_null() || filepath_chunk.is_null() || filepath_manifest.is_null() {
+        println!("[-] Error: Invalid Asset Loader, Chunk Path Or Manifest Path");
+        return std::ptr::null_mut();
+    }
+
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    let loader = unsafe { &*loader };
+    let ticket = match raw_load_callback(callback, user_data) {
+        Some(callback) => loader.load_chunk_with_callback(chunk_path, manifest_path, callback),
+        None => loader.load_chunk(chunk_path, manifest_path),
+    };
+    Box::into_raw(Box::new(ticket))
+}
+
+
+/**
+ * API: - Load One Group Of A Chunk On The Loader's Worker Threads, Returning A Ticket Straight Away.
+ * `callback` Is Optional (NULL To Poll The Ticket Instead) And Always Runs On A Worker Thread. Returns NULL On Invalid Arguments.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn load_asset_group_async(loader:*const AssetLoader, filepath_chunk:*const c_char, filepath_manifest:*const c_char, group:*const c_char, callback:Option<RawCLoadCallback>, user_data:*mut c_void) -> *mut LoadTicket {
+    if loader.is_null() || filepath_chunk.is_null() || filepath_manifest.is_null() || group.is_null() {
+        println!("[-] Error: Invalid Asset Loader, Chunk Path, Manifest Path Or Group Name");
+        return std::ptr::null_mut();
+    }
+
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    let group_name = unsafe { CStr::from_ptr(group).to_str().unwrap() };
+    let loader = unsafe { &*loader };
+    let ticket = match raw_load_callback(callback, user_data) {
+        Some(callback) => loader.load_group_with_callback(chunk_path, manifest_path, group_name, callback),
+        None => loader.load_group(chunk_path, manifest_path, group_name),
+    };
+    Box::into_raw(Box::new(ticket))
+}
+
+
+/**
+ * API: - Get Where A Background Load Has Got To
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_load_status(ticket:*const LoadTicket) -> RawCLoadStatus {
+    if ticket.is_null() {
+        return RawCLoadStatus::Failed;
+    }
+    unsafe { &*ticket }.status().into()
+}
+
+
+/**
+ * Hand A Finished Load's Result To C
+ */
+fn raw_loaded_chunk(result:Option<Result<LoadedChunk, String>>) -> *mut RawCAssetChunk {
+    match result {
+        Some(Ok(loaded)) => RawCAssetChunk::from_loaded(&loaded.manifest, loaded.data),
+        Some(Err(e)) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        },
+        None => std::ptr::null_mut(),
+    }
+}
+
+
+/**
+ * API: - Take The Chunk Of A Finished Load Without Blocking, The Caller Owns It And Frees It With `free_asset_chunk`.
+ * Returns NULL While It Is Still Loading, If It Failed, Or If It Was Already Taken.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn take_loaded_chunk(ticket:*const LoadTicket) -> *mut RawCAssetChunk {
+    if ticket.is_null() {
+        println!("[-] Error: Invalid Load Ticket");
+        return std::ptr::null_mut();
+    }
+    raw_loaded_chunk(unsafe { &*ticket }.try_take())
+}
+
+
+/**
+ * API: - Block Until A Load Finishes And Take Its Chunk, Returns NULL If It Failed Or Was Already Taken.
+ * The Caller Owns The Chunk And Frees It With `free_asset_chunk`.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn wait_for_load(ticket:*const LoadTicket) -> *mut RawCAssetChunk {
+    if ticket.is_null() {
+        println!("[-] Error: Invalid Load Ticket");
+        return std::ptr::null_mut();
+    }
+    raw_loaded_chunk(Some(unsafe { &*ticket }.wait()))
+}
+
+
+/**
+ * API: - Free A Load Ticket, The Load Still Finishes (And Calls Its Callback) In The Background
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn release_load_ticket(ticket:*mut LoadTicket) {
+    if !ticket.is_null() {
+        drop(unsafe { Box::from_raw(ticket) });
+    }
+}
+
+
+/**
+ * API: - An Asset Looked Up Through A Shared Chunk Or A VFS. `data` Points Into The Chunk, So It Is Valid
+ * For As Long As The Caller Holds A Reference To The Chunk (Or Keeps It Mounted).
+ */
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawCAssetView {
+    pub data: *const c_void,
+    pub size: usize,
+    pub kind: RawCAssetKind,
+}
+
+impl RawCAssetView {
+    fn new(asset:&Asset, data:&[u8]) -> Self {
+        RawCAssetView { data: data.as_ptr() as *const c_void, size: data.len(), kind: RawCAssetKind::from(asset.get_type()) }
+    }
+}
+
+
+/**
+ * API: - Load A Chunk As A Reference Counted Handle That Any Thread Can Read, Starting With One Reference.
+ * Unlike `get_asset`, Lookups Through The Handle Never Allocate Or Change It, So Threads Don't Need To Lock.
+ * Returns NULL If The Chunk Or Manifest Can't Be Read.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn assetchunk_open(filepath_chunk:*const c_char, filepath_manifest:*const c_char) -> *const SharedChunkData {
+    if filepath_chunk.is_null() || filepath_manifest.is_null() {
+        println!("[-] Error: Invalid Chunk Path Or Manifest Path");
+        return std::ptr::null();
+    }
+
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    match SharedChunk::load(chunk_path, manifest_path) {
+        Ok(chunk) => Arc::into_raw(chunk.0),
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null()
+        }
+    }
+}
+
+
+/**
+ * API: - Take Another Reference To A Shared Chunk, e.g. Before Handing It To Another Thread. Returns The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn assetchunk_retain(chunk:*const SharedChunkData) -> *const SharedChunkData {
+    if !chunk.is_null() {
+        unsafe { Arc::increment_strong_count(chunk) };
+    }
+    chunk
+}
+
+
+/**
+ * API: - Drop A Reference To A Shared Chunk, The Chunk Is Freed With Its Last Reference
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn assetchunk_release(chunk:*const SharedChunkData) {
+    if !chunk.is_null() {
+        unsafe { Arc::decrement_strong_count(chunk) };
+    }
+}
+
+
+/**
+ * API: - Look An Asset Up In A Shared Chunk, Filling In `asset`. Safe To Call From Many Threads At Once.
+ * Returns False If The Asset Isn't In The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn assetchunk_get_asset(chunk:*const SharedChunkData, name:*const c_char, asset:*mut RawCAssetView) -> bool {
+    if chunk.is_null() || name.is_null() || asset.is_null() {
+        println!("[-] Error: Invalid Shared Chunk, Asset Name Or Asset View");
+        return false;
+    }
+
+    let chunk = unsafe { &*chunk };
+    let Ok(asset_name) = unsafe { CStr::from_ptr(name) }.to_str() else {
+        return false;
+    };
+    let (Some(entry), Some(data)) = (chunk.get_asset(asset_name), chunk.get_asset_data(asset_name)) else {
+        return false;
+    };
+    unsafe {
+        *asset = RawCAssetView::new(entry, data);
+    }
+    true
+}
+
+
+/**
+ * API: - Keep Only Part Of A Chunk In Memory Within A Byte Budget, Loading Groups On Demand And
+ * Evicting The Least Recently Used. Returns NULL If The Manifest Can't Be Read.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn create_residency_manager(filepath_chunk:*const c_char, filepath_manifest:*const c_char, budget:usize) -> *mut ResidencyManager {
+    if filepath_chunk.is_null() || filepath_manifest.is_null() {
+        println!("[-] Error: Invalid Chunk Path Or Manifest Path");
+        return std::ptr::null_mut();
+    }
+
+    let chunk_path = unsafe { CStr::from_ptr(filepath_chunk).to_str().unwrap() };
+    let manifest_path = unsafe { CStr::from_ptr(filepath_manifest).to_str().unwrap() };
+    match ResidencyManager::new(chunk_path, manifest_path, budget) {
+        Ok(manager) => Box::into_raw(Box::new(manager)),
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+
+/**
+ * API: - Free A Residency Manager And Everything It Holds
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn destroy_residency_manager(manager:*mut ResidencyManager) {
+    if !manager.is_null() {
+        drop(unsafe { Box::from_raw(manager) });
+    }
+}
+
+
+/**
+ * API: - Look An Asset Up, Loading Its Group First If Needed, Filling In `asset`.
+ * `data` Is Valid Until The Group Is Evicted, Pin The Asset To Keep It.
+ * Returns False If The Asset Doesn't Exist Or Can't Fit In The Budget.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn residency_get_asset(manager:*mut ResidencyManager, name:*const c_char, asset:*mut RawCAssetView) -> bool {
+    if manager.is_null() || name.is_null() || asset.is_null() {
+        println!("[-] Error: Invalid Residency Manager, Asset Name Or Asset View");
+        return false;
+    }
+
+    let manager = unsafe { &mut *manager };
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    let chunk = match manager.get_chunk(asset_name) {
+        Ok(chunk) => chunk,
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            return false;
+        }
+    };
+    let (Some(entry), Some(data)) = (chunk.get_asset(asset_name), chunk.get_asset_data(asset_name)) else {
+        return false;
+    };
+    unsafe {
+        *asset = RawCAssetView::new(entry, data);
+    }
+    true
+}
+
+
+/**
+ * API: - Keep An Asset's Group Resident Until `residency_unpin`, Loading It First If Needed.
+ * Returns False If The Asset Doesn't Exist Or Can't Fit In The Budget.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn residency_pin(manager:*mut ResidencyManager, name:*const c_char) -> bool {
+    if manager.is_null() || name.is_null() {
+        println!("[-] Error: Invalid Residency Manager Or Asset Name");
+        return false;
+    }
+
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    match unsafe { &mut *manager }.pin(asset_name) {
+        Ok(()) => true,
+        Err(e) => {
+            println!("[-] Error: {}", e);
+            false
+        }
+    }
+}
+
+
+/**
+ * API: - Drop A Pin On An Asset's Group, Returns False If It Wasn't Pinned
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn residency_unpin(manager:*mut ResidencyManager, name:*const c_char) -> bool {
+    if manager.is_null() || name.is_null() {
+        return false;
+    }
+    let asset_name = unsafe { CStr::from_ptr(name).to_str().unwrap() };
+    unsafe { &mut *manager }.unpin(asset_name)
+}
+
+
+/**
+ * API: - Change The Byte Budget, Evicting Unpinned Groups Until Back Within It
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn residency_set_budget(manager:*mut ResidencyManager, budget:usize) {
+    if !manager.is_null() {
+        unsafe { &mut *manager }.set_budget(budget);
+    }
+}
+
+
+/**
+ * API: - Get How Many Bytes Of The Chunk Are In Memory
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn residency_resident_bytes(manager:*const ResidencyManager) -> usize {
+    if manager.is_null() {
+        return 0;
+    }
+    unsafe { &*manager }.resident_bytes()
+}
+
+
+/**
+ * API: - Get The ID Of The Asset Named `name`, For Names Only Known At Runtime (e.g. From Scripts)
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_asset_id(name:*const c_char) -> u32 {
+    if name.is_null() {
+        return AssetId::INVALID.0;
+    }
+    AssetId::from_name(unsafe { CStr::from_ptr(name).to_str().unwrap() }).0
+}
+
+
+/**
+ * API: - Get An Asset By Its ID Instead Of Its Name And Fill `out`, Nothing Is Allocated.
+ * `data` Points Into The Chunk (Or An Override), Valid Until The Chunk Is Freed. Returns False If The Asset Isn't In The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn get_asset_by_id(asset_chunk:*const RawCAssetChunk, id:u32, out:*mut RawCAsset) -> bool {
+    if asset_chunk.is_null() || out.is_null() {
+        println!("[-] Error: Invalid Asset Chunk Or Output");
+        return false;
+    }
+
+    let chunk = unsafe { &*asset_chunk };
+    let manifest = unsafe { &*chunk.manifest };
+    let Some(found) = manifest.find_by_id(AssetId(id)) else {
+        println!("[-] Error: Asset Not Found: {}", AssetId(id));
+        return false;
+    };
+
+    let mut asset = unsafe { *found };
+    asset.data = unsafe { chunk.data.add(asset.location) };
+    apply_override(&mut asset);
+    unsafe { *out = asset };
+    true
+}
+
+
+/**
+ * API: - Look An Asset Up In A Shared Chunk By Its ID, Filling In `asset`. Safe To Call From Many Threads At Once.
+ * Returns False If The Asset Isn't In The Chunk.
+ */
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn assetchunk_get_asset_by_id(chunk:*const SharedChunkData, id:u32, asset:*mut RawCAssetView) -> bool {
+    if chunk.is_null() || asset.is_null() {
+        println!("[-] Error: Invalid Shared Chunk Or Asset View");
+        return false;
+    }
+
+    let chunk = unsafe { &*chunk };
+    let (Some(entry), Some(data)) = (chunk.get_asset_by_id(AssetId(id)), chunk.get_asset_data_by_id(AssetId(id))) else {
+        return false;
+    };
+    unsafe {
+        *asset = RawCAssetView::new(entry, data);
+    }
+    true
+}
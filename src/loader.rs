@@ -0,0 +1,353 @@
+use std::any::Any;
+use std::fs::{read, read_to_string};
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::JoinHandle;
+
+use crate::AssetManifest;
+
+
+/**
+ * A Chunk (Or One Group Of It) Loaded Into Memory Along With Its Manifest.
+ * For A Group `manifest` Only Holds The Group's Assets, Their Locations Are Relative To `data`.
+ */
+#[derive(Debug)]
+pub struct LoadedChunk {
+    pub manifest: AssetManifest,
+    pub data: Vec<u8>,
+}
+
+
+impl LoadedChunk {
+
+    /**
+     * Read A Chunk File And Its Manifest, Blocking Until Both Are In Memory
+     */
+    pub fn load(chunk_path:&str, manifest_path:&str) -> Result<Self, String> {
+        let manifest = read_manifest(manifest_path)?;
+        let data = read(chunk_path).map_err(|e| format!("Failed To Load Asset Chunk {}: {}", chunk_path, e))?;
+        Ok(LoadedChunk { manifest, data })
+    }
+
+
+    /**
+     * Read One Group Of A Chunk File, Blocking Until It Is In Memory
+     */
+    pub fn load_group(chunk_path:&str, manifest_path:&str, group:&str) -> Result<Self, String> {
+        read_manifest(manifest_path)?.load_asset_group(chunk_path, group)
+    }
+
+
+    /**
+     * Get The Data Of An Asset In The Chunk
+     */
+    pub fn get_asset_data(&self, name:&str) -> Option<&[u8]> {
+        let asset = self.manifest.get_assets().iter().find(|asset| asset.get_name() == name)?;
+        self.data.get(asset.get_chunk_location()..asset.get_chunk_location() + asset.get_size())
+    }
+}
+
+
+/**
+ * Read A Manifest File
+ */
+pub(crate) fn read_manifest(manifest_path:&str) -> Result<AssetManifest, String> {
+    let json = read_to_string(manifest_path).map_err(|e| format!("Failed To Load Asset Manifest {}: {}", manifest_path, e))?;
+    AssetManifest::try_from_json(&json)
+}
+
+
+/**
+ * Where A Background Load Has Got To
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadStatus {
+    Queued, // Waiting For A Free Worker
+    Loading, // A Worker Is Reading It
+    Loaded,
+    Failed,
+}
+
+
+/**
+ * Called Once With The Result Of A Background Load, On The Worker Thread That Loaded It
+ */
+pub type LoadCallback = Box<dyn FnOnce(Result<LoadedChunk, String>) + Send>;
+
+
+/**
+ * State Shared Between A Ticket And The Worker Loading It
+ */
+struct LoadSlot {
+    status: LoadStatus,
+    result: Option<Result<LoadedChunk, String>>, // None Until Finished, Or Once Taken
+    callback: Option<LoadCallback>,
+    waker: Option<Waker>,
+}
+
+
+struct LoadState {
+    slot: Mutex<LoadSlot>,
+    finished: Condvar,
+}
+
+
+impl LoadState {
+
+    fn set_loading(&self) {
+        self.slot.lock().unwrap().status = LoadStatus::Loading;
+    }
+
+    /**
+     * Store The Result, Or Hand It Straight To The Callback, And Wake Anyone Waiting
+     */
+    fn finish(&self, result:Result<LoadedChunk, String>) {
+        let mut slot = self.slot.lock().unwrap();
+        slot.status = match result.is_ok() {
+            true => LoadStatus::Loaded,
+            false => LoadStatus::Failed,
+        };
+        let callback = slot.callback.take();
+        let waker = slot.waker.take();
+        let result = match callback {
+            Some(_) => Some(result),
+            None => {
+                slot.result = Some(result);
+                None
+            },
+        };
+        drop(slot);
+        self.finished.notify_all();
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        if let (Some(callback), Some(result)) = (callback, result) {
+            callback(result);
+        }
+    }
+}
+
+
+/**
+ * A Handle To A Background Load, Returned As Soon As The Load Is Queued.
+ * Poll `status`, Block On `wait`, `.await` It, Or Register A Callback With `on_complete`.
+ * The Result Can Only Be Taken Once, Whichever Way It Is Taken.
+ */
+#[derive(Clone)]
+pub struct LoadTicket {
+    state: Arc<LoadState>,
+}
+
+
+impl LoadTicket {
+
+    fn new() -> Self {
+        LoadTicket {
+            state: Arc::new(LoadState {
+                slot: Mutex::new(LoadSlot { status: LoadStatus::Queued, result: None, callback: None, waker: None }),
+                finished: Condvar::new(),
+            }),
+        }
+    }
+
+
+    /**
+     * Get Where The Load Has Got To
+     */
+    pub fn status(&self) -> LoadStatus {
+        self.state.slot.lock().unwrap().status
+    }
+
+
+    /**
+     * Check Whether The Load Has Finished, Successfully Or Not
+     */
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status(), LoadStatus::Loaded | LoadStatus::Failed)
+    }
+
+
+    /**
+     * Take The Result If The Load Has Finished, None While It Is Still Going Or Once Taken
+     */
+    pub fn try_take(&self) -> Option<Result<LoadedChunk, String>> {
+        self.state.slot.lock().unwrap().result.take()
+    }
+
+
+    /**
+     * Block Until The Load Finishes And Take Its Result
+     */
+    pub fn wait(&self) -> Result<LoadedChunk, String> {
+        let mut slot = self.state.slot.lock().unwrap();
+        while matches!(slot.status, LoadStatus::Queued | LoadStatus::Loading) {
+            slot = self.state.finished.wait(slot).unwrap();
+        }
+        slot.result.take().unwrap_or(Err("Load Result Was Already Taken".to_string()))
+    }
+
+
+    /**
+     * Hand The Result To A Callback When The Load Finishes, On The Worker Thread.
+     * Runs Straight Away On This Thread If It Already Finished, Never If The Result Was Already Taken.
+     */
+    pub fn on_complete(&self, callback:impl FnOnce(Result<LoadedChunk, String>) + Send + 'static) {
+        let mut slot = self.state.slot.lock().unwrap();
+        match slot.status {
+            LoadStatus::Queued | LoadStatus::Loading => slot.callback = Some(Box::new(callback)),
+            LoadStatus::Loaded | LoadStatus::Failed => {
+                let result = slot.result.take();
+                drop(slot);
+                if let Some(result) = result {
+                    callback(result);
+                }
+            },
+        }
+    }
+}
+
+
+impl Future for LoadTicket {
+    type Output = Result<LoadedChunk, String>;
+
+    fn poll(self: Pin<&mut Self>, context:&mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.state.slot.lock().unwrap();
+        match slot.status {
+            LoadStatus::Queued | LoadStatus::Loading => {
+                slot.waker = Some(context.waker().clone());
+                Poll::Pending
+            },
+            LoadStatus::Loaded | LoadStatus::Failed => Poll::Ready(slot.result.take().unwrap_or(Err("Load Result Was Already Taken".to_string()))),
+        }
+    }
+}
+
+
+type LoadJob = Box<dyn FnOnce() + Send>;
+
+
+fn panic_message(panic:&(dyn Any + Send)) -> &str {
+    match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        _ => "Unknown Panic",
+    }
+}
+
+
+/**
+ * Loads Chunks And Groups On A Pool Of Worker Threads So The Game Thread Never Blocks On Disk.
+ * Dropping The Loader Finishes The Queued Loads, Then Stops The Workers.
+ */
+pub struct AssetLoader {
+    jobs: Option<Sender<LoadJob>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+
+impl AssetLoader {
+
+    /**
+     * Start A Loader With Some Worker Threads (At Least One)
+     */
+    pub fn new(threads:usize) -> Self {
+        let (jobs, queue) = channel::<LoadJob>();
+        let queue:Arc<Mutex<Receiver<LoadJob>>> = Arc::new(Mutex::new(queue));
+        let workers = (0..threads.max(1)).map(|_| {
+            let queue = queue.clone();
+            std::thread::spawn(move || loop {
+                let job = queue.lock().unwrap().recv();
+                match job {
+                    Ok(job) => {
+                        // A Panicking Callback Mustn't Take The Worker Down With It
+                        let _ = catch_unwind(AssertUnwindSafe(job));
+                    },
+                    Err(_) => break, // Loader Dropped
+                }
+            })
+        }).collect();
+        AssetLoader { jobs: Some(jobs), workers }
+    }
+
+
+    /**
+     * Queue A Load, Returning Its Ticket Straight Away.
+     * The Callback Is In Place Before The Job Is Sent, So Even A Load That Fails Straight Away Calls It On The Worker.
+     */
+    fn queue(&self, load:impl FnOnce() -> Result<LoadedChunk, String> + Send + 'static, callback:Option<LoadCallback>) -> LoadTicket {
+        let ticket = LoadTicket::new();
+        ticket.state.slot.lock().unwrap().callback = callback;
+        let state = ticket.state.clone();
+        let job:LoadJob = Box::new(move || {
+            state.set_loading();
+            // A Panicking Load Fails Its Ticket Instead Of Taking The Worker Down With It
+            let result = catch_unwind(AssertUnwindSafe(load)).unwrap_or_else(|panic| Err(format!("Load Panicked: {}", panic_message(panic.as_ref()))));
+            state.finish(result);
+        });
+        if let Some(jobs) = self.jobs.as_ref() {
+            if let Err(e) = jobs.send(job) {
+                ticket.state.finish(Err(format!("Asset Loader Has Stopped: {}", e)));
+            }
+        }
+        ticket
+    }
+
+
+    /**
+     * Load A Chunk File And Its Manifest In The Background
+     */
+    pub fn load_chunk(&self, chunk_path:&str, manifest_path:&str) -> LoadTicket {
+        let (chunk_path, manifest_path) = (chunk_path.to_string(), manifest_path.to_string());
+        self.queue(move || LoadedChunk::load(&chunk_path, &manifest_path), None)
+    }
+
+
+    /**
+     * Load A Chunk File And Its Manifest In The Background, Handing The Result To A Callback On The Worker Thread.
+     * Unlike `on_complete` The Callback Never Runs On This Thread, However Quickly The Load Finishes.
+     */
+    pub fn load_chunk_with_callback(&self, chunk_path:&str, manifest_path:&str, callback:LoadCallback) -> LoadTicket {
+        let (chunk_path, manifest_path) = (chunk_path.to_string(), manifest_path.to_string());
+        self.queue(move || LoadedChunk::load(&chunk_path, &manifest_path), Some(callback))
+    }
+
+
+    /**
+     * Load One Group Of A Chunk File In The Background, e.g. The Area The Player Is Walking Into
+     */
+    pub fn load_group(&self, chunk_path:&str, manifest_path:&str, group:&str) -> LoadTicket {
+        let (chunk_path, manifest_path, group) = (chunk_path.to_string(), manifest_path.to_string(), group.to_string());
+        self.queue(move || LoadedChunk::load_group(&chunk_path, &manifest_path, &group), None)
+    }
+
+
+    /**
+     * Load One Group Of A Chunk File In The Background, Handing The Result To A Callback On The Worker Thread
+     */
+    pub fn load_group_with_callback(&self, chunk_path:&str, manifest_path:&str, group:&str, callback:LoadCallback) -> LoadTicket {
+        let (chunk_path, manifest_path, group) = (chunk_path.to_string(), manifest_path.to_string(), group.to_string());
+        self.queue(move || LoadedChunk::load_group(&chunk_path, &manifest_path, &group), Some(callback))
+    }
+}
+
+
+impl Default for AssetLoader {
+    fn default() -> Self {
+        AssetLoader::new(std::thread::available_parallelism().map(|threads| threads.get()).unwrap_or(2).min(4))
+    }
+}
+
+
+impl Drop for AssetLoader {
+    fn drop(&mut self) {
+        self.jobs.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
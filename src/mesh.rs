@@ -0,0 +1,342 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::binary::{Reader, Writer};
+use crate::{asset_name_of, Asset, AssetType};
+
+
+/**
+ * A Run Of Indices Drawn With One Material
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SubMesh {
+    pub material: String, // Material Name From `usemtl`, Empty If None Was Set
+    pub index_start: u32,
+    pub index_count: u32,
+}
+
+
+/**
+ * An Indexed Triangle Mesh With Interleaved Vertices, Stored In The Chunk In A Packed Binary Form (See `to_bytes`).
+ * Every Vertex Is A Position (3 Floats), Then A Normal (3 Floats) And A UV (2 Floats) If The Layout Has Them.
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+    pub layout: u32, // `Mesh::LAYOUT_*` Flags
+    pub vertices: Vec<f32>,
+    pub indices: Vec<u32>,
+    pub bounds_min: [f32; 3],
+    pub bounds_max: [f32; 3],
+    pub material_libraries: Vec<String>, // Asset Names Of The `.mtl` Files
+    pub submeshes: Vec<SubMesh>,
+}
+
+
+impl Mesh {
+
+    pub const LAYOUT_POSITION:u32 = 1;
+    pub const LAYOUT_NORMAL:u32 = 2;
+    pub const LAYOUT_UV:u32 = 4;
+
+    const MAGIC:&'static [u8; 4] = b"MESH";
+    const VERSION:u32 = 1;
+
+    /**
+     * Number Of Floats In A Vertex
+     */
+    pub fn vertex_stride(&self) -> usize {
+        let mut stride = 3;
+        if self.layout & Self::LAYOUT_NORMAL != 0 {
+            stride += 3;
+        }
+        if self.layout & Self::LAYOUT_UV != 0 {
+            stride += 2;
+        }
+        stride
+    }
+
+    /**
+     * Number Of Vertices
+     */
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() / self.vertex_stride()
+    }
+
+    /**
+     * Parse A Wavefront OBJ. Polygons Are Triangulated As Fans, Identical Corners Share A Vertex,
+     * And The Layout Only Has Normals / UVs If The Faces Reference Them.
+     */
+    pub fn parse_obj(text:&str) -> Result<Self, String> {
+        let mut positions:Vec<[f32; 3]> = Vec::new();
+        let mut normals:Vec<[f32; 3]> = Vec::new();
+        let mut uvs:Vec<[f32; 2]> = Vec::new();
+        let mut corners:Vec<(usize, Option<usize>, Option<usize>)> = Vec::new(); // (Position, UV, Normal), Three Per Triangle
+        let mut material_libraries:Vec<String> = Vec::new();
+        let mut submeshes:Vec<SubMesh> = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let error = |message:&str| format!("Line {}: {}", number + 1, message);
+            let mut tokens = line.split_whitespace();
+            let Some(keyword) = tokens.next() else {
+                continue;
+            };
+            let floats = |tokens:std::str::SplitWhitespace| tokens.map(|token| token.parse::<f32>().map_err(|_| error(&format!("Invalid Number {:?}", token)))).collect::<Result<Vec<f32>, String>>();
+
+            match keyword {
+                "v" => match floats(tokens)?[..] {
+                    [x, y, z, ..] => positions.push([x, y, z]),
+                    _ => return Err(error("Vertex Needs 3 Coordinates")),
+                },
+                "vn" => match floats(tokens)?[..] {
+                    [x, y, z, ..] => normals.push([x, y, z]),
+                    _ => return Err(error("Normal Needs 3 Coordinates")),
+                },
+                "vt" => match floats(tokens)?[..] {
+                    [u] => uvs.push([u, 0.0]),
+                    [u, v, ..] => uvs.push([u, v]),
+                    _ => return Err(error("Texture Coordinate Needs A Value")),
+                },
+                "f" => {
+                    let face = tokens.map(|corner| parse_corner(corner, positions.len(), uvs.len(), normals.len()).map_err(|e| error(&e)))
+                        .collect::<Result<Vec<_>, String>>()?;
+                    if face.len() < 3 {
+                        return Err(error("Face Needs At Least 3 Corners"));
+                    }
+                    for index in 1..face.len() - 1 {
+                        corners.extend([face[0], face[index], face[index + 1]]);
+                    }
+                },
+                "mtllib" => material_libraries.extend(tokens.map(asset_name_of)),
+                "usemtl" => {
+                    let material = tokens.collect::<Vec<&str>>().join(" ");
+                    if let Some(last) = submeshes.last_mut().filter(|last| last.index_start as usize == corners.len()) {
+                        last.material = material; // Nothing Was Drawn With The Previous Material
+                    } else {
+                        submeshes.push(SubMesh { material, index_start: corners.len() as u32, index_count: 0 });
+                    }
+                },
+                _ => {} // Comments, Objects, Groups, Smoothing Groups, Lines, ...
+            }
+        }
+
+        let mut layout = Self::LAYOUT_POSITION;
+        if corners.iter().any(|corner| corner.2.is_some()) {
+            layout |= Self::LAYOUT_NORMAL;
+        }
+        if corners.iter().any(|corner| corner.1.is_some()) {
+            layout |= Self::LAYOUT_UV;
+        }
+
+        let mut mesh = Mesh {
+            layout,
+            material_libraries,
+            ..Default::default()
+        };
+        let mut lookup:HashMap<(usize, Option<usize>, Option<usize>), u32> = HashMap::new();
+        for corner in corners.iter() {
+            let index = *lookup.entry(*corner).or_insert_with(|| {
+                let index = mesh.vertex_count() as u32;
+                let (position, uv, normal) = *corner;
+                mesh.vertices.extend_from_slice(&positions[position]);
+                if layout & Self::LAYOUT_NORMAL != 0 {
+                    mesh.vertices.extend_from_slice(&normal.map(|normal| normals[normal]).unwrap_or_default());
+                }
+                if layout & Self::LAYOUT_UV != 0 {
+                    mesh.vertices.extend_from_slice(&uv.map(|uv| uvs[uv]).unwrap_or_default());
+                }
+                index
+            });
+            mesh.indices.push(index);
+        }
+
+        // Close Off The Material Runs
+        if submeshes.first().is_none_or(|first| first.index_start != 0) {
+            submeshes.insert(0, SubMesh { material: String::new(), index_start: 0, index_count: 0 });
+        }
+        let ends:Vec<u32> = submeshes.iter().skip(1).map(|submesh| submesh.index_start).chain([mesh.indices.len() as u32]).collect();
+        for (submesh, end) in submeshes.iter_mut().zip(ends) {
+            submesh.index_count = end - submesh.index_start;
+        }
+        mesh.submeshes = submeshes.into_iter().filter(|submesh| submesh.index_count > 0).collect();
+
+        // Bounds Of The Positions Faces Actually Use
+        let mut used = corners.iter().map(|corner| positions[corner.0]);
+        if let Some(first) = used.next() {
+            mesh.bounds_min = first;
+            mesh.bounds_max = first;
+        }
+        for position in used {
+            for (axis, value) in position.into_iter().enumerate() {
+                mesh.bounds_min[axis] = mesh.bounds_min[axis].min(value);
+                mesh.bounds_max[axis] = mesh.bounds_max[axis].max(value);
+            }
+        }
+
+        Ok(mesh)
+    }
+
+    /**
+     * Encode The Mesh, Little Endian: `MESH`, Version, Layout, Vertex Count, Index Count, Bounds,
+     * Material Libraries, Sub-Meshes, Then The Vertex And Index Buffers Ready To Upload
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer(Self::MAGIC.to_vec());
+        writer.u32(Self::VERSION);
+        writer.u32(self.layout);
+        writer.u32(self.vertex_count() as u32);
+        writer.u32(self.indices.len() as u32);
+        for value in self.bounds_min.iter().chain(self.bounds_max.iter()) {
+            writer.f32(*value);
+        }
+        writer.u32(self.material_libraries.len() as u32);
+        for library in self.material_libraries.iter() {
+            writer.string(library);
+        }
+        writer.u32(self.submeshes.len() as u32);
+        for submesh in self.submeshes.iter() {
+            writer.string(&submesh.material);
+            writer.u32(submesh.index_start);
+            writer.u32(submesh.index_count);
+        }
+        for value in self.vertices.iter() {
+            writer.f32(*value);
+        }
+        for index in self.indices.iter() {
+            writer.u32(*index);
+        }
+        writer.0
+    }
+
+    /**
+     * Decode A Mesh Written By `to_bytes`
+     */
+    pub fn from_bytes(data:&[u8]) -> Result<Self, String> {
+        let mut reader = Reader::new(data);
+        if reader.take(4)? != Self::MAGIC {
+            return Err("Not A Packed Mesh".to_string());
+        }
+        let version = reader.u32()?;
+        if version != Self::VERSION {
+            return Err(format!("Unsupported Mesh Version {}", version));
+        }
+
+        let mut mesh = Mesh {
+            layout: reader.u32()?,
+            ..Default::default()
+        };
+        let vertex_count = reader.u32()? as usize;
+        let index_count = reader.u32()? as usize;
+        for axis in 0..3 {
+            mesh.bounds_min[axis] = reader.f32()?;
+        }
+        for axis in 0..3 {
+            mesh.bounds_max[axis] = reader.f32()?;
+        }
+        for _ in 0..reader.u32()? {
+            mesh.material_libraries.push(reader.string()?);
+        }
+        for _ in 0..reader.u32()? {
+            mesh.submeshes.push(SubMesh {
+                material: reader.string()?,
+                index_start: reader.u32()?,
+                index_count: reader.u32()?,
+            });
+        }
+        mesh.vertices = (0..vertex_count * mesh.vertex_stride()).map(|_| reader.f32()).collect::<Result<Vec<f32>, String>>()?;
+        mesh.indices = (0..index_count).map(|_| reader.u32()).collect::<Result<Vec<u32>, String>>()?;
+        Ok(mesh)
+    }
+}
+
+
+/**
+ * Parse A Face Corner (`v`, `v/vt`, `v//vn` Or `v/vt/vn`) Into Zero Based Indices,
+ * Negative Indices Count Back From The Last Element Defined So Far
+ */
+fn parse_corner(corner:&str, positions:usize, uvs:usize, normals:usize) -> Result<(usize, Option<usize>, Option<usize>), String> {
+    let resolve = |token:&str, count:usize| -> Result<usize, String> {
+        let index:i64 = token.parse().map_err(|_| format!("Invalid Index {:?}", token))?;
+        let resolved = if index < 0 { count as i64 + index } else { index - 1 };
+        if resolved < 0 || resolved >= count as i64 {
+            return Err(format!("Index {} Is Out Of Range", index));
+        }
+        Ok(resolved as usize)
+    };
+
+    let mut parts = corner.split('/');
+    let position = resolve(parts.next().unwrap_or_default(), positions)?;
+    let uv = match parts.next() {
+        Some(token) if !token.is_empty() => Some(resolve(token, uvs)?),
+        _ => None,
+    };
+    let normal = match parts.next() {
+        Some(token) if !token.is_empty() => Some(resolve(token, normals)?),
+        _ => None,
+    };
+    Ok((position, uv, normal))
+}
+
+
+/**
+ * Get The Names Of The Texture Assets A Material Library (`.mtl`) Uses
+ */
+pub fn material_textures(text:&str) -> Vec<String> {
+    let mut textures:Vec<String> = Vec::new();
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+        if !(keyword.starts_with("map_") || matches!(keyword, "bump" | "disp" | "decal" | "refl" | "norm")) {
+            continue;
+        }
+        // Options Like `-s 1 1 1` Come First, The File Is Last
+        if let Some(texture) = tokens.last().map(asset_name_of).filter(|texture| !textures.contains(texture)) {
+            textures.push(texture);
+        }
+    }
+    textures
+}
+
+
+/**
+ * Convert Every OBJ Model Into A Packed Mesh.
+ * Every Material Library The Model Uses, And Every Texture Those Use, Must Be One Of The Assets Being Packed.
+ * Returns How Many Meshes Were Converted.
+ */
+pub fn import_meshes(assets:&mut [Asset]) -> Result<usize, String> {
+    let obj = AssetType::Model("OBJ".to_string());
+    let mtl = AssetType::Other("MTL".to_string());
+    let names:HashSet<String> = assets.iter().map(|asset| asset.get_name().to_string()).collect();
+
+    for asset in assets.iter_mut().filter(|asset| *asset.get_type() == mtl) {
+        let textures = material_textures(&String::from_utf8_lossy(asset.get_data().as_deref().unwrap_or_default()));
+        if let Some(texture) = textures.iter().find(|texture| !names.contains(*texture)) {
+            return Err(format!("Material Library {} Uses Texture {:?} Which Is Not Being Packed", asset.get_name(), texture));
+        }
+        for texture in textures.iter() {
+            asset.add_dependency(texture);
+        }
+    }
+
+    let mut count = 0;
+    for asset in assets.iter_mut().filter(|asset| *asset.get_type() == obj) {
+        let data = asset.get_data().as_deref().ok_or(format!("Mesh {} Has No Data", asset.get_name()))?;
+        let text = std::str::from_utf8(data).map_err(|_| format!("Mesh {} Is Not UTF-8", asset.get_name()))?;
+        let mesh = Mesh::parse_obj(text).map_err(|e| format!("Mesh {}: {}", asset.get_name(), e))?;
+        if let Some(library) = mesh.material_libraries.iter().find(|library| !names.contains(*library)) {
+            return Err(format!("Mesh {} Uses Material Library {:?} Which Is Not Being Packed", asset.get_name(), library));
+        }
+
+        println!("[+] Mesh {}: {} Vertices, {} Triangles", asset.get_name(), mesh.vertex_count(), mesh.indices.len() / 3);
+        asset.set_metadata("vertex_count", &mesh.vertex_count().to_string());
+        asset.set_metadata("index_count", &mesh.indices.len().to_string());
+        asset.set_metadata("vertex_layout", &mesh.layout.to_string());
+        for library in mesh.material_libraries.iter() {
+            asset.add_dependency(library);
+        }
+        asset.set_type(AssetType::Model("MESH".to_string()));
+        asset.set_data(mesh.to_bytes());
+        count += 1;
+    }
+    Ok(count)
+}
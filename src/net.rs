@@ -0,0 +1,324 @@
+/*!
+ * Asset Server Protocol, Version 1
+ *
+ * Lets A Running Game Fetch Assets Over TCP From A Dev Machine Instead Of Reading Them From Disk.
+ *
+ * Every Message Is A Frame: A u32 Length Followed By That Many Bytes. Inside A Frame Everything Is
+ * Little Endian, Strings And Byte Blobs Are A u32 Length Followed By The Bytes, Maps Are A u32 Count
+ * Followed By Key-Value Strings.
+ *
+ * Handshake, Sent Once After Connecting:
+ *   Client -> `"ACNP" u32:version`
+ *   Server -> `"ACNP" u32:version u8:accepted` (1 If It Speaks The Client's Version, Else It Hangs Up)
+ *
+ * Requests Start With An Opcode, Each Gets Exactly One Response:
+ *   0x01 GET   `string:name`
+ *              -> `u8:0 string:type map:metadata bytes:data` Found, `type` Is `Kind:FORMAT`
+ *              -> `u8:1` Not Found
+ *              -> `u8:2 string:message` The Server Failed To Load It
+ *   0x02 LIST  -> `u32:count string:name...` Every Asset The Server Has
+ */
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::binary::{Reader, Writer};
+use crate::import::source_files;
+use crate::{asset_name_of, Asset, AssetManifest, AssetType, ImportPipeline, LoadedChunk, PackConfig};
+
+
+/**
+ * Version Of The Asset Server Protocol Spoken By This Library
+ */
+pub const PROTOCOL_VERSION:u32 = 1;
+
+const PROTOCOL_MAGIC:&[u8; 4] = b"ACNP";
+const MAX_FRAME_SIZE:usize = 1 << 30; // Nothing We Send Comes Close, Stops A Bad Length Allocating Forever
+
+const REQUEST_GET:u8 = 0x01;
+const REQUEST_LIST:u8 = 0x02;
+
+const STATUS_FOUND:u8 = 0;
+const STATUS_NOT_FOUND:u8 = 1;
+const STATUS_ERROR:u8 = 2;
+
+
+/**
+ * Send One Frame
+ */
+fn write_frame(stream:&mut TcpStream, body:&[u8]) -> Result<(), String> {
+    stream.write_all(&(body.len() as u32).to_le_bytes()).and_then(|_| stream.write_all(body)).map_err(|e| format!("Failed To Send: {}", e))
+}
+
+
+/**
+ * Receive One Frame, None If The Other Side Hung Up Between Frames
+ */
+fn read_frame(stream:&mut TcpStream) -> Result<Option<Vec<u8>>, String> {
+    let mut length = [0; 4];
+    match stream.read_exact(&mut length) {
+        Ok(_) => {},
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(format!("Failed To Receive: {}", e)),
+    }
+    let length = u32::from_le_bytes(length) as usize;
+    if length > MAX_FRAME_SIZE {
+        return Err(format!("Frame Of {} Bytes Is Too Large", length));
+    }
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).map_err(|e| format!("Failed To Receive: {}", e))?;
+    Ok(Some(body))
+}
+
+
+/**
+ * Where An Asset Server Gets Its Assets From
+ */
+pub enum AssetSource {
+    Chunk { manifest: AssetManifest, data: Vec<u8> }, // A Packed Chunk, Served As Packed
+    Directory { directory: PathBuf, config: Box<PackConfig>, pipeline: ImportPipeline }, // Source Files, Imported On Every Request So Edits Show Up Immediately
+}
+
+
+impl AssetSource {
+
+    /**
+     * Serve The Assets Of A Packed Chunk
+     */
+    pub fn chunk(chunk_path:&str, manifest_path:&str) -> Result<Self, String> {
+        let loaded = LoadedChunk::load(chunk_path, manifest_path)?;
+        Ok(AssetSource::Chunk { manifest: loaded.manifest, data: loaded.data })
+    }
+
+    /**
+     * Serve The Source Files Of A Directory, Imported With The Built-In Importers
+     */
+    pub fn directory(directory:&Path, config:PackConfig) -> Result<Self, String> {
+        if !directory.is_dir() {
+            return Err(format!("Asset Directory {:?} Does Not Exist", directory));
+        }
+        Ok(AssetSource::Directory { directory: directory.to_path_buf(), config: Box::new(config), pipeline: ImportPipeline::with_defaults() })
+    }
+
+    /**
+     * Get An Asset With Its Data, None If The Source Doesn't Have It
+     */
+    pub fn get(&self, name:&str) -> Result<Option<Asset>, String> {
+        match self {
+            AssetSource::Chunk { manifest, data } => {
+                let Some(asset) = manifest.get_assets().iter().find(|asset| asset.get_name() == name) else {
+                    return Ok(None);
+                };
+                let bytes = data.get(asset.get_chunk_location()..asset.get_chunk_location() + asset.get_size()).ok_or(format!("Asset {} Lies Outside The Chunk", name))?;
+                let mut asset = asset.clone();
+                asset.set_data(bytes.to_vec());
+                Ok(Some(asset))
+            },
+            AssetSource::Directory { directory, config, pipeline } => {
+                let path = source_files(directory).into_iter().find(|path| path.to_str().map(asset_name_of).as_deref() == Some(name));
+                path.map(|path| pipeline.import_file(&path, directory, config)).transpose()
+            },
+        }
+    }
+
+    /**
+     * Get The Names Of Every Asset The Source Has
+     */
+    pub fn list(&self) -> Vec<String> {
+        match self {
+            AssetSource::Chunk { manifest, .. } => manifest.get_assets().iter().map(|asset| asset.get_name().to_string()).collect(),
+            AssetSource::Directory { directory, .. } => source_files(directory).iter().filter_map(|path| path.to_str().map(asset_name_of)).collect(),
+        }
+    }
+}
+
+
+/**
+ * Serves Assets To Games Over TCP, See The Module Docs For The Protocol
+ */
+pub struct AssetServer {
+    listener: TcpListener,
+    source: Arc<AssetSource>,
+}
+
+
+impl AssetServer {
+
+    /**
+     * Listen On An Address Such As `127.0.0.1:7878` (Port 0 Picks A Free Port)
+     */
+    pub fn bind(address:&str, source:AssetSource) -> Result<Self, String> {
+        let listener = TcpListener::bind(address).map_err(|e| format!("Failed To Listen On {}: {}", address, e))?;
+        Ok(AssetServer { listener, source: Arc::new(source) })
+    }
+
+    /**
+     * Get The Address The Server Is Listening On
+     */
+    pub fn local_addr(&self) -> Result<SocketAddr, String> {
+        self.listener.local_addr().map_err(|e| e.to_string())
+    }
+
+    /**
+     * Serve Clients Until The Process Ends, Each On Its Own Thread
+     */
+    pub fn run(&self) {
+        for stream in self.listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    println!("[-] Error: Failed To Accept Client: {}", e);
+                    continue;
+                }
+            };
+            let source = self.source.clone();
+            std::thread::spawn(move || {
+                let peer = stream.peer_addr().map(|peer| peer.to_string()).unwrap_or_default();
+                println!("[+] Client Connected: {}", peer);
+                if let Err(e) = serve_client(&mut stream, &source) {
+                    println!("[-] Error: Client {}: {}", peer, e);
+                }
+                println!("[+] Client Disconnected: {}", peer);
+            });
+        }
+    }
+}
+
+
+/**
+ * Handshake With A Client Then Answer Its Requests Until It Hangs Up
+ */
+fn serve_client(stream:&mut TcpStream, source:&AssetSource) -> Result<(), String> {
+    let Some(hello) = read_frame(stream)? else {
+        return Ok(());
+    };
+    let mut reader = Reader::new(&hello);
+    if reader.take(4)? != PROTOCOL_MAGIC {
+        return Err("Not An Asset Client".to_string());
+    }
+    let version = reader.u32()?;
+    let mut answer = Writer(PROTOCOL_MAGIC.to_vec());
+    answer.u32(PROTOCOL_VERSION);
+    answer.u8((version == PROTOCOL_VERSION) as u8);
+    write_frame(stream, &answer.0)?;
+    if version != PROTOCOL_VERSION {
+        return Err(format!("Client Speaks Protocol Version {}, We Speak {}", version, PROTOCOL_VERSION));
+    }
+
+    while let Some(request) = read_frame(stream)? {
+        let mut reader = Reader::new(&request);
+        let mut response = Writer(Vec::new());
+        match reader.u8()? {
+            REQUEST_GET => {
+                let name = reader.string()?;
+                match source.get(&name) {
+                    Ok(Some(asset)) => {
+                        println!("[+] Serving: {}", name);
+                        response.u8(STATUS_FOUND);
+                        response.string(&format!("{}:{}", asset.get_type().kind_name(), asset.get_type().format()));
+                        response.properties(asset.get_metadata());
+                        response.bytes(asset.get_data().as_deref().unwrap_or_default());
+                    },
+                    Ok(None) => response.u8(STATUS_NOT_FOUND),
+                    Err(e) => {
+                        response.u8(STATUS_ERROR);
+                        response.string(&e);
+                    },
+                }
+            },
+            REQUEST_LIST => {
+                let names = source.list();
+                response.u32(names.len() as u32);
+                for name in names.iter() {
+                    response.string(name);
+                }
+            },
+            opcode => return Err(format!("Unknown Request 0x{:02x}", opcode)),
+        }
+        write_frame(stream, &response.0)?;
+    }
+    Ok(())
+}
+
+
+/**
+ * Fetches Assets From An `AssetServer` Instead Of Reading Them From Disk
+ */
+pub struct AssetClient {
+    stream: TcpStream,
+}
+
+
+impl AssetClient {
+
+    /**
+     * Connect To An Asset Server And Check It Speaks Our Protocol Version
+     */
+    pub fn connect(address:&str) -> Result<Self, String> {
+        let mut stream = TcpStream::connect(address).map_err(|e| format!("Failed To Connect To {}: {}", address, e))?;
+        stream.set_nodelay(true).map_err(|e| e.to_string())?;
+
+        let mut hello = Writer(PROTOCOL_MAGIC.to_vec());
+        hello.u32(PROTOCOL_VERSION);
+        write_frame(&mut stream, &hello.0)?;
+
+        let answer = read_frame(&mut stream)?.ok_or("Asset Server Hung Up During The Handshake")?;
+        let mut reader = Reader::new(&answer);
+        if reader.take(4)? != PROTOCOL_MAGIC {
+            return Err(format!("{} Is Not An Asset Server", address));
+        }
+        let version = reader.u32()?;
+        if reader.u8()? != 1 {
+            return Err(format!("Asset Server Speaks Protocol Version {}, We Speak {}", version, PROTOCOL_VERSION));
+        }
+        Ok(AssetClient { stream })
+    }
+
+    /**
+     * Send A Request And Wait For Its Response
+     */
+    fn request(&mut self, request:Writer) -> Result<Vec<u8>, String> {
+        write_frame(&mut self.stream, &request.0)?;
+        read_frame(&mut self.stream)?.ok_or("Asset Server Hung Up".to_string())
+    }
+
+    /**
+     * Fetch An Asset With Its Data, None If The Server Doesn't Have It
+     */
+    pub fn get_asset(&mut self, name:&str) -> Result<Option<Asset>, String> {
+        let mut request = Writer(Vec::new());
+        request.u8(REQUEST_GET);
+        request.string(name);
+        let response = self.request(request)?;
+
+        let mut reader = Reader::new(&response);
+        match reader.u8()? {
+            STATUS_FOUND => {
+                let asset_type:AssetType = reader.string()?.parse()?;
+                let metadata = reader.properties()?;
+                let mut asset = Asset::from_data(name, asset_type, reader.bytes()?.to_vec());
+                for (key, value) in metadata.iter() {
+                    asset.set_metadata(key, value);
+                }
+                Ok(Some(asset))
+            },
+            STATUS_NOT_FOUND => Ok(None),
+            STATUS_ERROR => Err(reader.string()?),
+            status => Err(format!("Unknown Response Status {}", status)),
+        }
+    }
+
+    /**
+     * Get The Names Of Every Asset The Server Has
+     */
+    pub fn list_assets(&mut self) -> Result<Vec<String>, String> {
+        let mut request = Writer(Vec::new());
+        request.u8(REQUEST_LIST);
+        let response = self.request(request)?;
+
+        let mut reader = Reader::new(&response);
+        (0..reader.u32()?).map(|_| reader.string()).collect()
+    }
+}
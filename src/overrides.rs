@@ -0,0 +1,101 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::{asset_name_of, identify, Asset};
+
+
+/**
+ * Directory Checked For Loose Files Before The Packed Chunk, None When Overrides Are Off
+ */
+static OVERRIDE_DIRECTORY: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/**
+ * Virtual Paths Of Every Asset That Was Served From The Override Directory
+ */
+static OVERRIDDEN_ASSETS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/**
+ * Loose Files Looked For So Far, None Where There Was No File. Each Is Read And Identified Once
+ * And Kept For The Rest Of The Program Since C Holds Pointers Into It.
+ */
+static OVERRIDE_DATA: Mutex<BTreeMap<PathBuf, Option<&'static Asset>>> = Mutex::new(BTreeMap::new());
+
+/**
+ * Virtual Paths Of Loose Files Already Warned About, See `warn_unusable_override`
+ */
+static WARNED_OVERRIDES: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+
+/**
+ * Set The Directory Loose Files Override Packed Assets From, None Turns Overrides Off.
+ * A File Overrides An Asset When Its Path Inside The Directory Is The Asset's Virtual Path (`hero.png`).
+ */
+pub fn set_override_directory(directory:Option<PathBuf>) {
+    if let Some(directory) = directory.as_ref() {
+        println!("[+] Overriding Assets From {:?}", directory);
+    }
+    *OVERRIDE_DIRECTORY.lock().unwrap() = directory;
+}
+
+
+/**
+ * Get The Directory Loose Files Override Packed Assets From
+ */
+pub fn get_override_directory() -> Option<PathBuf> {
+    OVERRIDE_DIRECTORY.lock().unwrap().clone()
+}
+
+
+/**
+ * Read And Identify A Loose File The First Time It Is Looked For
+ */
+fn read_cached(path:&Path, virtual_path:&str) -> Option<&'static Asset> {
+    let mut cache = OVERRIDE_DATA.lock().unwrap();
+    if let Some(asset) = cache.get(path) {
+        return *asset;
+    }
+    let asset = std::fs::read(path).ok().map(|data| {
+        let mut asset = Asset::from_data(&asset_name_of(virtual_path), identify(Some(virtual_path), &data).asset_type, data);
+        asset.extract_metadata();
+        &*Box::leak(Box::new(asset))
+    });
+    cache.insert(path.to_path_buf(), asset);
+    asset
+}
+
+
+/**
+ * Get The Loose File Overriding An Asset, Identified With Its Metadata Extracted Like A Packed Asset.
+ * None If Overrides Are Off Or There Is No Such File.
+ * Each Path Is Only Looked For The First Time, Later Edits (Or New Files) Aren't Picked Up.
+ */
+pub fn read_override(virtual_path:&str) -> Option<&'static Asset> {
+    let path = get_override_directory()?.join(virtual_path);
+    let asset = read_cached(&path, virtual_path)?;
+    if OVERRIDDEN_ASSETS.lock().unwrap().insert(virtual_path.to_string()) {
+        println!("[+] Asset Overridden: {} -> {:?}", virtual_path, path);
+    }
+    Some(asset)
+}
+
+
+/**
+ * Warn Once If There Is A Loose File Named After Something It Can't Override, e.g. A Sprite Packed Into An Atlas Page
+ */
+pub fn warn_unusable_override(virtual_path:&str, reason:&str) {
+    let Some(directory) = get_override_directory() else {
+        return;
+    };
+    if read_cached(&directory.join(virtual_path), virtual_path).is_some() && WARNED_OVERRIDES.lock().unwrap().insert(virtual_path.to_string()) {
+        println!("[!] Warning: {} Doesn't Override Anything, {}", virtual_path, reason);
+    }
+}
+
+
+/**
+ * Get The Virtual Paths Of Every Asset Served From The Override Directory So Far
+ */
+pub fn get_overridden_assets() -> Vec<String> {
+    OVERRIDDEN_ASSETS.lock().unwrap().iter().cloned().collect()
+}
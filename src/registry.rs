@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use crate::{identify, Asset, AssetType, Identification, IdentificationSource};
+
+
+/**
+ * Looks At Asset Data And Returns The Format Name If It Belongs To The Kind
+ */
+pub type AssetDetector = Box<dyn Fn(&[u8]) -> Option<String> + Send + Sync>;
+
+/**
+ * Checks That Asset Data Is Well Formed For The Kind, Returning A Description Of The Problem If Not
+ */
+pub type AssetValidator = Box<dyn Fn(&[u8]) -> Result<(), String> + Send + Sync>;
+
+
+/**
+ * A Game Defined Asset Kind, e.g. `Dialogue`, `Tilemap` Or `Palette`
+ */
+pub struct AssetKind {
+    name: String,
+    extensions: Vec<String>,
+    detector: Option<AssetDetector>,
+    validator: Option<AssetValidator>,
+}
+
+
+impl AssetKind {
+
+    /**
+     * Create A New Asset Kind With No Extensions, Detector Or Validator
+     */
+    pub fn new(name:&str) -> Self {
+        AssetKind {
+            name: name.to_string(),
+            extensions: Vec::new(),
+            detector: None,
+            validator: None,
+        }
+    }
+
+    /**
+     * File Extensions (Without The Dot) That Belong To This Kind
+     */
+    pub fn with_extensions(mut self, extensions:&[&str]) -> Self {
+        self.extensions = extensions.iter().map(|ext| ext.trim_start_matches('.').to_ascii_lowercase()).collect();
+        self
+    }
+
+    /**
+     * Detector Run Against The Asset Data, If Set It Must Accept The Data For The Kind To Match
+     */
+    pub fn with_detector(mut self, detector:impl Fn(&[u8]) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.detector = Some(Box::new(detector));
+        self
+    }
+
+    /**
+     * Validator Run On Every Asset Of This Kind At Pack Time
+     */
+    pub fn with_validator(mut self, validator:impl Fn(&[u8]) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /**
+     * Get The Kind Name
+     */
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /**
+     * Get The File Extensions Of The Kind
+     */
+    pub fn get_extensions(&self) -> &Vec<String> {
+        &self.extensions
+    }
+
+    /**
+     * Try To Match Asset Data (And Optionally Its Filename) Against This Kind
+     */
+    fn matches(&self, filename:Option<&str>, data:&[u8]) -> Option<Identification> {
+        let extension = filename
+            .and_then(|filename| Path::new(filename).extension())
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase())
+            .filter(|extension| self.extensions.contains(extension));
+
+        let (format, confidence, source) = match (&self.detector, extension) {
+            (Some(detector), Some(_)) => (detector(data)?, 1.0, IdentificationSource::ExtensionAndContent),
+            (Some(detector), None) => (detector(data)?, 0.9, IdentificationSource::Content),
+            (None, Some(extension)) => (extension.to_ascii_uppercase(), 0.75, IdentificationSource::Extension),
+            (None, None) => return None,
+        };
+
+        Some(Identification {
+            asset_type: AssetType::Custom { kind: self.name.clone(), format },
+            confidence,
+            source,
+        })
+    }
+}
+
+
+/**
+ * Registry Of Game Defined Asset Kinds, Consulted Before The Built-In Detector
+ */
+#[derive(Default)]
+pub struct AssetRegistry {
+    kinds: Vec<AssetKind>,
+}
+
+
+impl AssetRegistry {
+
+    /**
+     * Create An Empty Registry, Which Identifies Exactly Like The Built-In Detector
+     */
+    pub fn new() -> Self {
+        AssetRegistry {
+            kinds: Vec::new(),
+        }
+    }
+
+    /**
+     * Register A Custom Asset Kind, Replacing Any Kind Already Registered Under The Same Name
+     */
+    pub fn register(&mut self, kind:AssetKind) {
+        self.kinds.retain(|existing| existing.name != kind.name);
+        self.kinds.push(kind);
+    }
+
+    /**
+     * Get A Registered Kind By Name
+     */
+    pub fn get(&self, name:&str) -> Option<&AssetKind> {
+        self.kinds.iter().find(|kind| kind.name == name)
+    }
+
+    /**
+     * Get All Registered Kinds
+     */
+    pub fn get_kinds(&self) -> &Vec<AssetKind> {
+        &self.kinds
+    }
+
+    /**
+     * Identify An Asset, Trying Registered Kinds In Registration Order Before The Built-In Detector
+     */
+    pub fn identify(&self, filename:Option<&str>, data:&[u8]) -> Identification {
+        self.kinds.iter()
+            .find_map(|kind| kind.matches(filename, data))
+            .unwrap_or_else(|| identify(filename, data))
+    }
+
+    /**
+     * Identify Every Asset That Has Data Loaded And No Type Yet, Recording Header Metadata
+     */
+    pub fn identify_assets(&self, assets:&mut [Asset]) {
+        for asset in assets.iter_mut().filter(|asset| !asset.get_type().is_identified()) {
+            if let Some(data) = asset.get_data() {
+                let identification = self.identify(Some(asset.get_name()), data);
+                asset.set_type(identification.asset_type);
+                asset.extract_metadata();
+            }
+        }
+    }
+
+    /**
+     * Run The Validator Of A Custom Kind Against Asset Data.
+     * Built-In Types And Kinds Without A Validator Always Pass.
+     */
+    pub fn validate(&self, asset_type:&AssetType, data:&[u8]) -> Result<(), String> {
+        let AssetType::Custom { kind, .. } = asset_type else {
+            return Ok(());
+        };
+        match self.get(kind).and_then(|kind| kind.validator.as_ref()) {
+            Some(validator) => validator(data),
+            None => Ok(()),
+        }
+    }
+}
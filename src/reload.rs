@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+use crate::{Asset, ImportPipeline, PackConfig};
+
+
+/**
+ * Called With Every Asset That Was Re-Imported
+ */
+pub type ReloadCallback = Box<dyn FnMut(&Asset) + Send>;
+
+
+/**
+ * Watches A Source Directory During Development And Re-Imports Files As They Change.
+ * Polling Based, Call `poll` Every Frame Or Let `watch` Do It On A Thread.
+ */
+pub struct HotReloader {
+    directory: PathBuf,
+    config: PackConfig,
+    pipeline: ImportPipeline,
+    seen: HashMap<PathBuf, (SystemTime, u64)>, // File -> (Modified Time, Size) When Last Checked
+    callbacks: Vec<ReloadCallback>,
+}
+
+
+impl HotReloader {
+
+    /**
+     * Start Watching A Directory, Files Already In It Aren't Reported Until They Change
+     */
+    pub fn new(directory:&Path, config:PackConfig) -> Result<Self, String> {
+        if !directory.is_dir() {
+            return Err(format!("Hot Reload Directory {:?} Does Not Exist", directory));
+        }
+        let mut reloader = HotReloader {
+            directory: directory.to_path_buf(),
+            config,
+            pipeline: ImportPipeline::with_defaults(),
+            seen: HashMap::new(),
+            callbacks: Vec::new(),
+        };
+        reloader.seen = reloader.scan();
+        Ok(reloader)
+    }
+
+
+    /**
+     * Use Another Import Pipeline, e.g. One With The Game's Own Importers Registered
+     */
+    pub fn set_pipeline(&mut self, pipeline:ImportPipeline) {
+        self.pipeline = pipeline;
+    }
+
+
+    /**
+     * Register A Callback For Reloaded Assets
+     */
+    pub fn on_reload(&mut self, callback:impl FnMut(&Asset) + Send + 'static) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+
+    /**
+     * Modified Time And Size Of Every File Under The Directory
+     */
+    fn scan(&self) -> HashMap<PathBuf, (SystemTime, u64)> {
+        let mut files = HashMap::new();
+        let mut directories = vec![self.directory.clone()];
+        while let Some(directory) = directories.pop() {
+            let Ok(entries) = std::fs::read_dir(&directory) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    directories.push(path);
+                } else if !path.to_string_lossy().ends_with(".anim.toml") {
+                    files.insert(path, (metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH), metadata.len()));
+                }
+            }
+        }
+        files
+    }
+
+
+    /**
+     * Re-Import Every File That Was Added Or Changed Since The Last Poll And Notify The Callbacks.
+     * Files That Fail To Import Are Reported And Tried Again When They Next Change.
+     * Returns The Names Of The Reloaded Assets.
+     */
+    pub fn poll(&mut self) -> Vec<String> {
+        let files = self.scan();
+        let mut changed:Vec<&PathBuf> = files.iter()
+            .filter(|(path, stamp)| self.seen.get(*path) != Some(stamp))
+            .map(|(path, _)| path)
+            .collect();
+        changed.sort();
+
+        let mut reloaded = Vec::new();
+        for path in changed {
+            match self.pipeline.import_file(path, &self.directory, &self.config) {
+                Ok(asset) => {
+                    println!("[+] Hot Reloaded: {}", asset.get_name());
+                    for callback in self.callbacks.iter_mut() {
+                        callback(&asset);
+                    }
+                    reloaded.push(asset.get_name().to_string());
+                },
+                Err(e) => println!("[-] Error: Hot Reload Of {:?} Failed: {}", path, e),
+            }
+        }
+        self.seen = files;
+        reloaded
+    }
+
+
+    /**
+     * Poll On A Background Thread Every `interval`, Callbacks Run On That Thread
+     */
+    pub fn watch(mut self, interval:Duration) -> HotReloadWatch {
+        let running = Arc::new(AtomicBool::new(true));
+        let thread_running = running.clone();
+        let thread = std::thread::spawn(move || {
+            while thread_running.load(Ordering::Relaxed) {
+                self.poll();
+                std::thread::sleep(interval);
+            }
+        });
+        HotReloadWatch { running, thread: Some(thread) }
+    }
+}
+
+
+/**
+ * A Hot Reloader Polling On A Background Thread, Stops When Dropped
+ */
+pub struct HotReloadWatch {
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+
+impl HotReloadWatch {
+
+    /**
+     * Stop Watching And Wait For The Thread To Finish
+     */
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+
+impl Drop for HotReloadWatch {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use crate::loader::read_manifest;
+use crate::{AssetGroup, AssetManifest, SharedChunk};
+
+
+/**
+ * What Gets Loaded And Evicted As One: A Whole Group, Or A Single Asset That Isn't In A Group
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ResidencyUnit {
+    Group(String),
+    Asset(String),
+}
+
+
+impl ResidencyUnit {
+    fn name(&self) -> &str {
+        match self {
+            ResidencyUnit::Group(name) | ResidencyUnit::Asset(name) => name,
+        }
+    }
+}
+
+
+/**
+ * A Unit Held In Memory
+ */
+#[derive(Debug)]
+struct Resident {
+    chunk: SharedChunk,
+    size: usize,
+    last_used: u64,
+    pins: usize, // Never Evicted While Above Zero
+}
+
+
+/**
+ * Keeps Only Part Of A Chunk In Memory, Loading Groups On Demand Within A Byte Budget.
+ * When A Load Would Go Over Budget The Least Recently Used Groups Are Evicted First.
+ * Pinned Groups (e.g. In Use By The Renderer) Are Never Evicted. Assets Outside Any Group
+ * Are Loaded And Evicted One By One.
+ */
+#[derive(Debug)]
+pub struct ResidencyManager {
+    chunk_path: String,
+    manifest: AssetManifest,
+    budget: usize,
+    resident: HashMap<ResidencyUnit, Resident>,
+    clock: u64, // Bumped On Every Use, Orders Residents By Recency
+}
+
+
+impl ResidencyManager {
+
+    /**
+     * Manage A Chunk File Within A Budget, Nothing Is Loaded Until It Is Asked For
+     */
+    pub fn new(chunk_path:&str, manifest_path:&str, budget:usize) -> Result<Self, String> {
+        Ok(ResidencyManager {
+            chunk_path: chunk_path.to_string(),
+            manifest: read_manifest(manifest_path)?,
+            budget,
+            resident: HashMap::new(),
+            clock: 0,
+        })
+    }
+
+
+    /**
+     * Get The Byte Budget
+     */
+    pub fn get_budget(&self) -> usize {
+        self.budget
+    }
+
+
+    /**
+     * Change The Byte Budget, Evicting Unpinned Groups Until Back Within It
+     */
+    pub fn set_budget(&mut self, budget:usize) {
+        self.budget = budget;
+        self.evict(0);
+        if self.pinned_bytes() > budget {
+            println!("[!] Warning: {} Bytes Are Pinned, Over The Budget Of {} Bytes", self.pinned_bytes(), budget);
+        }
+    }
+
+
+    /**
+     * Get How Many Bytes Are Resident
+     */
+    pub fn resident_bytes(&self) -> usize {
+        self.resident.values().map(|resident| resident.size).sum()
+    }
+
+
+    fn pinned_bytes(&self) -> usize {
+        self.resident.values().filter(|resident| resident.pins > 0).map(|resident| resident.size).sum()
+    }
+
+
+    /**
+     * Get The Names Of The Resident Groups, And Of Resident Assets That Aren't In A Group
+     */
+    pub fn resident_groups(&self) -> Vec<String> {
+        let mut names:Vec<String> = self.resident.keys().map(|unit| unit.name().to_string()).collect();
+        names.sort();
+        names
+    }
+
+
+    /**
+     * Check Whether An Asset Is In Memory
+     */
+    pub fn is_resident(&self, name:&str) -> bool {
+        self.unit_of(name).is_ok_and(|(unit, _)| self.resident.contains_key(&unit))
+    }
+
+
+    /**
+     * Work Out Which Unit An Asset Is Loaded With, And That Unit's Byte Range
+     */
+    fn unit_of(&self, name:&str) -> Result<(ResidencyUnit, AssetGroup), String> {
+        let asset = self.manifest.get_assets().iter().find(|asset| asset.get_name() == name).ok_or(format!("No Asset Named {}", name))?;
+        match asset.get_group() {
+            Some(group) => self.group_unit(group),
+            None => Ok((ResidencyUnit::Asset(name.to_string()), AssetGroup {
+                name: name.to_string(),
+                offset: asset.get_chunk_location(),
+                size: asset.get_size(),
+                assets: vec![name.to_string()],
+            })),
+        }
+    }
+
+
+    fn group_unit(&self, group:&str) -> Result<(ResidencyUnit, AssetGroup), String> {
+        let range = self.manifest.get_group(group).ok_or(format!("No Asset Group Named {}", group))?;
+        Ok((ResidencyUnit::Group(group.to_string()), range.clone()))
+    }
+
+
+    /**
+     * Evict Least Recently Used Unpinned Units Until `size` More Bytes Fit In The Budget, Or Only Pinned Ones Are Left
+     */
+    fn evict(&mut self, size:usize) {
+        while self.resident_bytes() + size > self.budget {
+            let Some(unit) = self.resident.iter()
+                .filter(|(_, resident)| resident.pins == 0)
+                .min_by_key(|(_, resident)| resident.last_used)
+                .map(|(unit, _)| unit.clone()) else {
+                break;
+            };
+            if let Some(resident) = self.resident.remove(&unit) {
+                println!("[+] Evicted {} ({} Bytes)", unit.name(), resident.size);
+            }
+        }
+    }
+
+
+    /**
+     * Evict Until `size` More Bytes Fit In The Budget.
+     * Evicts Nothing If Even Evicting Everything Unpinned Wouldn't Be Enough.
+     */
+    fn make_room(&mut self, size:usize) -> Result<(), String> {
+        let pinned = self.pinned_bytes();
+        if pinned + size > self.budget {
+            return Err(format!("Loading {} Bytes Would Go Over The Budget Of {} Bytes, {} Bytes Are Pinned", size, self.budget, pinned));
+        }
+        self.evict(size);
+        Ok(())
+    }
+
+
+    /**
+     * Load A Unit If It Isn't Resident And Mark It As Just Used
+     */
+    fn make_resident(&mut self, unit:ResidencyUnit, range:AssetGroup) -> Result<&mut Resident, String> {
+        self.clock += 1;
+        if !self.resident.contains_key(&unit) {
+            self.make_room(range.size)?;
+            let chunk = SharedChunk::try_from(self.manifest.load_group_range(&self.chunk_path, &range)?)?;
+            self.resident.insert(unit.clone(), Resident { chunk, size: range.size, last_used: 0, pins: 0 });
+        }
+        let resident = self.resident.get_mut(&unit).unwrap();
+        resident.last_used = self.clock;
+        Ok(resident)
+    }
+
+
+    /**
+     * Get The Resident Chunk Holding An Asset, Loading Its Group First If Needed.
+     * The Returned Handle Keeps The Data Alive Even If The Group Is Evicted Afterwards.
+     */
+    pub fn get_chunk(&mut self, name:&str) -> Result<SharedChunk, String> {
+        let (unit, range) = self.unit_of(name)?;
+        Ok(self.make_resident(unit, range)?.chunk.clone())
+    }
+
+
+    /**
+     * Get The Data Of An Asset, Loading Its Group First If Needed
+     */
+    pub fn get_asset_data(&mut self, name:&str) -> Result<&[u8], String> {
+        let (unit, range) = self.unit_of(name)?;
+        let resident = self.make_resident(unit, range)?;
+        resident.chunk.get_asset_data(name).ok_or(format!("No Asset Named {}", name))
+    }
+
+
+    /**
+     * Load A Whole Group Ahead Of Time, e.g. The Area The Player Is Walking Into
+     */
+    pub fn load_group(&mut self, group:&str) -> Result<(), String> {
+        let (unit, range) = self.group_unit(group)?;
+        self.make_resident(unit, range).map(|_| ())
+    }
+
+
+    /**
+     * Keep An Asset's Group Resident Until It Is Unpinned, Loading It First If Needed.
+     * Pins Are Counted, Each `pin` Needs Its Own `unpin`.
+     */
+    pub fn pin(&mut self, name:&str) -> Result<(), String> {
+        let (unit, range) = self.unit_of(name)?;
+        self.make_resident(unit, range)?.pins += 1;
+        Ok(())
+    }
+
+
+    /**
+     * Keep A Whole Group Resident Until It Is Unpinned, Loading It First If Needed
+     */
+    pub fn pin_group(&mut self, group:&str) -> Result<(), String> {
+        let (unit, range) = self.group_unit(group)?;
+        self.make_resident(unit, range)?.pins += 1;
+        Ok(())
+    }
+
+
+    fn unpin_unit(&mut self, unit:&ResidencyUnit) -> bool {
+        match self.resident.get_mut(unit) {
+            Some(resident) if resident.pins > 0 => {
+                resident.pins -= 1;
+                true
+            },
+            _ => false,
+        }
+    }
+
+
+    /**
+     * Drop A Pin On An Asset's Group, Returns False If It Wasn't Pinned
+     */
+    pub fn unpin(&mut self, name:&str) -> bool {
+        self.unit_of(name).is_ok_and(|(unit, _)| self.unpin_unit(&unit))
+    }
+
+
+    /**
+     * Drop A Pin On A Group, Returns False If It Wasn't Pinned
+     */
+    pub fn unpin_group(&mut self, group:&str) -> bool {
+        self.unpin_unit(&ResidencyUnit::Group(group.to_string()))
+    }
+}
@@ -0,0 +1,339 @@
+use mlua::Lua;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+use crate::{asset_name_of, identify_by_extension, Asset, AssetType};
+
+
+/**
+ * What To Do With Lua Bytecode
+ */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BytecodeMode {
+    #[default]
+    None, // Only Check The Syntax, Pack The Source
+    Replace, // Pack Bytecode Instead Of The Source
+    Alongside, // Pack The Source And Its Bytecode As `<name>_luac`
+}
+
+
+/**
+ * Script Settings, From The `[scripts]` Table Of The Pack Config:
+ *
+ * ```toml
+ * [scripts]
+ * bytecode = "replace"           # "none", "replace" Or "alongside"
+ * strip_debug = true             # Drop Line Numbers And Local Names From The Bytecode
+ * external_modules = ["socket"]  # Modules The Game Provides, `require` Doesn't Need A Script For These
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ScriptConfig {
+    pub bytecode: BytecodeMode,
+    pub strip_debug: bool,
+    pub external_modules: Vec<String>,
+}
+
+
+/**
+ * Version Of Lua The Bytecode Is Compiled For, The Game Must Embed The Same One
+ */
+pub const LUA_VERSION:&str = "5.4";
+
+
+/**
+ * Syntax Check A Lua Script Without Running It.
+ * Errors Read Like `event_script.lua:12: 'end' expected near <eof>`.
+ */
+pub fn check_lua(name:&str, source:&[u8]) -> Result<(), String> {
+    compile_lua(name, source, false).map(|_| ())
+}
+
+
+/**
+ * Compile A Lua Script To Bytecode Without Running It
+ */
+pub fn compile_lua(name:&str, source:&[u8], strip_debug:bool) -> Result<Vec<u8>, String> {
+    let lua = Lua::new();
+    let function = lua.load(source)
+        .set_name(format!("@{}.lua", name)) // `@` Makes Lua Report The Name As A File
+        .into_function()
+        .map_err(|e| match e {
+            mlua::Error::SyntaxError { message, .. } => message,
+            other => other.to_string(),
+        })?;
+    Ok(function.dump(strip_debug))
+}
+
+
+/**
+ * Check Every Lua Script And Compile It If The Config Asks For Bytecode.
+ * Every Broken Script Is Reported Before Failing. Returns How Many Scripts Were Checked.
+ */
+pub fn compile_scripts(assets:&mut Vec<Asset>, config:&ScriptConfig) -> Result<usize, String> {
+    let lua = AssetType::Script("LUA".to_string());
+    let mut errors:Vec<String> = Vec::new();
+    let mut bytecode_assets:Vec<Asset> = Vec::new();
+    let mut count = 0;
+
+    for asset in assets.iter_mut().filter(|asset| *asset.get_type() == lua) {
+        count += 1;
+        let source = asset.get_data().as_deref().unwrap_or_default();
+        let bytecode = match compile_lua(asset.get_name(), source, config.strip_debug) {
+            Ok(bytecode) => bytecode,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+
+        match config.bytecode {
+            BytecodeMode::None => {},
+            BytecodeMode::Replace => {
+                asset.set_type(AssetType::Script("LUAC".to_string()));
+                asset.set_metadata("lua_version", LUA_VERSION);
+                asset.set_data(bytecode);
+            },
+            BytecodeMode::Alongside => {
+                let name = format!("{}_luac", asset.get_name());
+                asset.set_metadata("bytecode", &name);
+                let mut compiled = Asset::from_data(&name, AssetType::Script("LUAC".to_string()), bytecode);
+                compiled.set_metadata("lua_version", LUA_VERSION);
+                compiled.set_metadata("source", asset.get_name());
+                compiled.set_dependencies(asset.get_dependencies().clone());
+                compiled.set_group(asset.get_group().clone());
+                bytecode_assets.push(compiled);
+            },
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(format!("{} Lua Script(s) Failed To Compile:\n{}", errors.len(), errors.join("\n")));
+    }
+    assets.extend(bytecode_assets);
+    Ok(count)
+}
+
+
+/**
+ * What A Lua Script Refers To, Found Without Running It
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScriptReferences {
+    pub requires: Vec<(String, usize)>, // (Module, Line) Of Every `require "module"` Call
+    pub strings: Vec<(String, usize)>, // (Text, Line) Of Every Other String Literal
+}
+
+
+/**
+ * Tokens Of A Lua Script That Matter For Finding References
+ */
+enum Token {
+    Name(String),
+    Str(String, usize),
+    Symbol(char),
+}
+
+
+/**
+ * Count The `=` Of A Long Bracket Opening At `chars[index]` (`[[`, `[=[`, ...), None If It Isn't One
+ */
+fn long_bracket_level(chars:&[char], index:usize) -> Option<usize> {
+    let level = chars[index + 1..].iter().take_while(|c| **c == '=').count();
+    (chars.get(index + 1 + level) == Some(&'[')).then_some(level)
+}
+
+
+/**
+ * Split Lua Source Into Names, String Literals And Symbols, Skipping Comments, Numbers And Whitespace
+ */
+fn tokenize_lua(source:&str) -> Vec<Token> {
+    let chars:Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut index = 0;
+
+    // Skip A Long Bracket Body Starting After Its Opening, Returning Its Contents
+    let long_bracket = |index:&mut usize, line:&mut usize, level:usize| -> String {
+        let close:String = format!("]{}]", "=".repeat(level));
+        let close:Vec<char> = close.chars().collect();
+        let start = *index;
+        while *index < chars.len() && !chars[*index..].starts_with(&close) {
+            if chars[*index] == '\n' {
+                *line += 1;
+            }
+            *index += 1;
+        }
+        let text:String = chars[start..*index].iter().collect();
+        *index = (*index + close.len()).min(chars.len());
+        text.strip_prefix('\n').map(str::to_string).unwrap_or(text) // A Newline Right After The Opening Is Skipped
+    };
+
+    while index < chars.len() {
+        let c = chars[index];
+        if c == '\n' {
+            line += 1;
+            index += 1;
+        } else if c.is_whitespace() {
+            index += 1;
+        } else if c == '-' && chars.get(index + 1) == Some(&'-') {
+            index += 2;
+            match (chars.get(index) == Some(&'[')).then(|| long_bracket_level(&chars, index)).flatten() {
+                Some(level) => {
+                    index += level + 2;
+                    long_bracket(&mut index, &mut line, level);
+                },
+                None => {
+                    while index < chars.len() && chars[index] != '\n' {
+                        index += 1;
+                    }
+                }
+            }
+        } else if c == '"' || c == '\'' {
+            let start_line = line;
+            let mut text = String::new();
+            index += 1;
+            while index < chars.len() && chars[index] != c && chars[index] != '\n' {
+                if chars[index] == '\\' && index + 1 < chars.len() {
+                    index += 1;
+                    match chars[index] {
+                        'n' => text.push('\n'),
+                        't' => text.push('\t'),
+                        '\n' => {
+                            line += 1;
+                            text.push('\n');
+                        },
+                        other => text.push(other), // Quotes, Backslashes And Escapes We Don't Need To Decode
+                    }
+                } else {
+                    text.push(chars[index]);
+                }
+                index += 1;
+            }
+            index += 1;
+            tokens.push(Token::Str(text, start_line));
+        } else if c == '[' && long_bracket_level(&chars, index).is_some() {
+            let level = long_bracket_level(&chars, index).unwrap();
+            let start_line = line;
+            index += level + 2;
+            let text = long_bracket(&mut index, &mut line, level);
+            tokens.push(Token::Str(text, start_line));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = index;
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+            tokens.push(Token::Name(chars[start..index].iter().collect()));
+        } else if c.is_ascii_digit() {
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '.') {
+                index += 1;
+            }
+        } else {
+            tokens.push(Token::Symbol(c));
+            index += 1;
+        }
+    }
+    tokens
+}
+
+
+/**
+ * Find The `require` Calls And String Literals Of A Lua Script.
+ * `require "a"`, `require 'a'`, `require("a")` And `require [[a]]` Are Recognized,
+ * Method Calls Like `loader:require("a")` Are Not.
+ */
+pub fn scan_lua(source:&str) -> ScriptReferences {
+    let tokens = tokenize_lua(source);
+    let mut references = ScriptReferences::default();
+    let mut required:HashSet<usize> = HashSet::new(); // Token Indices Of Module Names
+
+    for (index, token) in tokens.iter().enumerate() {
+        let Token::Name(name) = token else {
+            continue;
+        };
+        let accessed = index > 0 && matches!(tokens[index - 1], Token::Symbol('.') | Token::Symbol(':'));
+        if name != "require" || accessed {
+            continue;
+        }
+        let argument = match tokens.get(index + 1) {
+            Some(Token::Symbol('(')) => index + 2,
+            _ => index + 1,
+        };
+        if let Some(Token::Str(module, line)) = tokens.get(argument) {
+            references.requires.push((module.clone(), *line));
+            required.insert(argument);
+        }
+    }
+
+    for (index, token) in tokens.iter().enumerate() {
+        if let Token::Str(text, line) = token {
+            if !required.contains(&index) {
+                references.strings.push((text.clone(), *line));
+            }
+        }
+    }
+    references
+}
+
+
+/**
+ * Record What Every Lua Script Depends On:
+ * - `require "events.common"` Needs A Script Named `common` (The Last Part Of The Module Path),
+ *   Unless The Module Is One Of The Config's `external_modules`. The Pack Fails If There Is None.
+ * - A String Literal That Is Exactly An Asset Name Is A Dependency On That Asset.
+ * - A String Literal Naming An Asset File (`"Tileset_Terrain1.png"`) Is A Dependency On That Asset.
+ *   Literals That Only Look Like One (A Save File Name, A URL) Are Just Warned About.
+ */
+pub fn scan_script_dependencies(assets:&mut [Asset], config:&ScriptConfig) -> Result<usize, String> {
+    let lua = AssetType::Script("LUA".to_string());
+    let names:HashSet<String> = assets.iter().map(|asset| asset.get_name().to_string()).collect();
+    let scripts:HashSet<String> = assets.iter()
+        .filter(|asset| matches!(asset.get_type(), AssetType::Script(_)))
+        .map(|asset| asset.get_name().to_string())
+        .collect();
+
+    let mut missing:Vec<String> = Vec::new();
+    let mut edges = 0;
+    for asset in assets.iter_mut().filter(|asset| *asset.get_type() == lua) {
+        let source = String::from_utf8_lossy(asset.get_data().as_deref().unwrap_or_default()).into_owned();
+        let references = scan_lua(&source);
+
+        let mut dependencies:Vec<String> = Vec::new();
+        for (module, line) in references.requires.iter() {
+            if config.external_modules.contains(module) {
+                continue;
+            }
+            let target = module.rsplit('.').next().unwrap_or(module);
+            if scripts.contains(target) {
+                dependencies.push(target.to_string());
+            } else {
+                missing.push(format!("{}.lua:{}: require {:?} Has No Matching Script", asset.get_name(), line, module));
+            }
+        }
+        for (text, line) in references.strings.iter() {
+            if names.contains(text) {
+                dependencies.push(text.clone());
+            } else if !text.contains(char::is_whitespace) && identify_by_extension(text).is_some() {
+                let target = asset_name_of(text);
+                if names.contains(&target) {
+                    dependencies.push(target);
+                } else {
+                    println!("[!] Warning: {}.lua:{}: {:?} Looks Like An Asset But Is Not Being Packed", asset.get_name(), line, text);
+                }
+            }
+        }
+
+        dependencies.retain(|dependency| dependency != asset.get_name());
+        for dependency in dependencies.iter() {
+            asset.add_dependency(dependency);
+        }
+        edges += asset.get_dependencies().len();
+    }
+
+    if !missing.is_empty() {
+        return Err(format!("{} Missing Script Reference(s):\n{}", missing.len(), missing.join("\n")));
+    }
+    Ok(edges)
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{Asset, AssetId, AssetManifest, LoadedChunk};
+
+
+/**
+ * The Contents Of A Shared Chunk, Never Changed After It Is Built
+ */
+#[derive(Debug)]
+pub struct SharedChunkData {
+    manifest: AssetManifest,
+    data: Vec<u8>,
+    index: HashMap<String, usize>, // Asset Name -> Index In The Manifest
+    ids: HashMap<AssetId, usize>, // Asset ID -> Index In The Manifest
+}
+
+
+impl SharedChunkData {
+
+    /**
+     * Get The Chunk's Manifest
+     */
+    pub fn get_manifest(&self) -> &AssetManifest {
+        &self.manifest
+    }
+
+
+    /**
+     * Get The Whole Chunk
+     */
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+
+    /**
+     * Find An Asset's Manifest Entry
+     */
+    pub fn get_asset(&self, name:&str) -> Option<&Asset> {
+        self.index.get(name).map(|&index| &self.manifest.get_assets()[index])
+    }
+
+
+    /**
+     * Find An Asset's Manifest Entry By Its ID
+     */
+    pub fn get_asset_by_id(&self, id:AssetId) -> Option<&Asset> {
+        self.ids.get(&id).map(|&index| &self.manifest.get_assets()[index])
+    }
+
+
+    /**
+     * Get The Data Of An Asset, Borrowed Straight From The Chunk
+     */
+    pub fn get_asset_data(&self, name:&str) -> Option<&[u8]> {
+        self.get_asset(name).map(|asset| self.data_of(asset))
+    }
+
+
+    /**
+     * Get The Data Of An Asset By Its ID, Borrowed Straight From The Chunk
+     */
+    pub fn get_asset_data_by_id(&self, id:AssetId) -> Option<&[u8]> {
+        self.get_asset_by_id(id).map(|asset| self.data_of(asset))
+    }
+
+
+    fn data_of(&self, asset:&Asset) -> &[u8] {
+        &self.data[asset.get_chunk_location()..asset.get_chunk_location() + asset.get_size()]
+    }
+}
+
+
+/**
+ * A Reference Counted Handle To A Loaded Chunk, For Engines Reading Assets From Several Threads.
+ *
+ * Thread Safety: The Chunk Is Immutable Once Built, So A Handle Is `Send + Sync` And Any Number Of
+ * Threads Can Look Assets Up Through It (Or Clones Of It) At Once Without Locking. Data Borrowed From
+ * A Handle Stays Valid As Long As That Handle. The Chunk Is Freed When The Last Handle Is Dropped.
+ * Mod Overrides Don't Apply To Shared Chunks.
+ */
+#[derive(Debug, Clone)]
+pub struct SharedChunk(pub(crate) Arc<SharedChunkData>);
+
+
+impl SharedChunk {
+
+    /**
+     * Share A Chunk, Checking Every Asset Lies Inside It
+     */
+    pub fn new(manifest:AssetManifest, data:Vec<u8>) -> Result<Self, String> {
+        if let Some(asset) = manifest.get_assets().iter().find(|asset| asset.get_chunk_location() + asset.get_size() > data.len()) {
+            return Err(format!("Asset {} Lies Outside The Chunk", asset.get_name()));
+        }
+        let index = manifest.get_assets().iter().enumerate().map(|(index, asset)| (asset.get_name().to_string(), index)).collect();
+        let ids = manifest.get_assets().iter().enumerate().map(|(index, asset)| (asset.get_id(), index)).collect();
+        Ok(SharedChunk(Arc::new(SharedChunkData { manifest, data, index, ids })))
+    }
+
+
+    /**
+     * Read A Chunk File And Its Manifest Into A Shared Chunk
+     */
+    pub fn load(chunk_path:&str, manifest_path:&str) -> Result<Self, String> {
+        LoadedChunk::load(chunk_path, manifest_path).and_then(SharedChunk::try_from)
+    }
+
+
+    /**
+     * Get How Many Handles To The Chunk Exist, Including This One
+     */
+    pub fn handle_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+}
+
+
+impl TryFrom<LoadedChunk> for SharedChunk {
+    type Error = String;
+
+    fn try_from(loaded:LoadedChunk) -> Result<Self, String> {
+        SharedChunk::new(loaded.manifest, loaded.data)
+    }
+}
+
+
+impl Deref for SharedChunk {
+    type Target = SharedChunkData;
+
+    fn deref(&self) -> &SharedChunkData {
+        &self.0
+    }
+}
@@ -0,0 +1,102 @@
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+
+use crate::AssetManifest;
+
+
+/**
+ * Reads A Single Asset's Byte Range Out Of A Chunk Without Copying The Rest Of It.
+ * Positions Are Relative To The Start Of The Asset, Reads Stop At Its End.
+ */
+#[derive(Debug)]
+pub struct AssetStream<R> {
+    inner: R,
+    start: u64, // Offset Of The Asset Within `inner`
+    size: u64,
+    position: u64, // Relative To `start`, May Be Past `size` After Seeking
+    synced: bool, // Whether `inner` Is Already At `start + position`
+}
+
+
+impl<R:Read + Seek> AssetStream<R> {
+
+    /**
+     * Stream `size` Bytes Starting At `start` Of The Underlying Reader
+     */
+    pub fn new(inner:R, start:u64, size:u64) -> Self {
+        AssetStream {
+            inner,
+            start,
+            size,
+            position: 0,
+            synced: false,
+        }
+    }
+
+    /**
+     * Size Of The Asset In Bytes
+     */
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /**
+     * Get Back The Underlying Reader
+     */
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+
+impl<R:Read + Seek> Read for AssetStream<R> {
+    fn read(&mut self, buffer:&mut [u8]) -> io::Result<usize> {
+        let remaining = self.size.saturating_sub(self.position);
+        let count = (buffer.len() as u64).min(remaining) as usize;
+        if count == 0 {
+            return Ok(0);
+        }
+        // Only Seek The Underlying Reader After We Were Moved, Seeking Throws Away Buffered Data
+        if !self.synced {
+            self.inner.seek(SeekFrom::Start(self.start + self.position))?;
+            self.synced = true;
+        }
+        let read = self.inner.read(&mut buffer[..count])?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+
+impl<R:Read + Seek> Seek for AssetStream<R> {
+    fn seek(&mut self, position:SeekFrom) -> io::Result<u64> {
+        let position = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+        };
+        match position {
+            Some(position) => {
+                self.synced &= position == self.position;
+                self.position = position;
+                Ok(position)
+            },
+            None => Err(io::Error::new(io::ErrorKind::InvalidInput, "Seek Before The Start Of The Asset")),
+        }
+    }
+}
+
+
+impl AssetManifest {
+
+    /**
+     * Open A Stream Over An Asset In A Chunk File, Only The Bytes Actually Read Are Loaded
+     */
+    pub fn open_asset_stream(&self, chunk_path:&str, name:&str) -> Result<AssetStream<BufReader<File>>, String> {
+        let asset = self.get_assets().iter()
+            .find(|asset| asset.get_name() == name)
+            .ok_or(format!("Asset Not Found: {}", name))?;
+        let file = File::open(chunk_path).map_err(|e| format!("Failed To Open Chunk {}: {}", chunk_path, e))?;
+        Ok(AssetStream::new(BufReader::new(file), asset.get_chunk_location() as u64, asset.get_size() as u64))
+    }
+}
@@ -0,0 +1,584 @@
+use base64::Engine;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::io::Read;
+
+use crate::binary::{Reader, Writer};
+use crate::{asset_name_of, Asset, AssetType};
+
+
+/**
+ * A Tiled Map, Stored In The Chunk In A Compact Binary Form (See `to_bytes`)
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tilemap {
+    pub width: u32, // In Tiles
+    pub height: u32,
+    pub tile_width: u32, // In Pixels
+    pub tile_height: u32,
+    pub tilesets: Vec<TilemapTileset>,
+    pub layers: Vec<TileLayer>,
+    pub object_layers: Vec<ObjectLayer>,
+    pub properties: BTreeMap<String, String>,
+}
+
+
+/**
+ * A Tileset Used By A Map, Its Image Is Referenced By Asset Name
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TilemapTileset {
+    pub first_gid: u32, // Global Tile ID Of The First Tile In The Set
+    pub name: String,
+    pub image: String, // Asset Name Of The Tileset Image
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub columns: u32,
+    pub tile_count: u32,
+}
+
+
+/**
+ * A Layer Of Tiles, Row Major. 0 Is An Empty Cell, Otherwise A Global Tile ID
+ * Whose Top 4 Bits Are Tiled's Flip / Rotation Flags.
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TileLayer {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    pub tiles: Vec<u32>,
+    pub properties: BTreeMap<String, String>,
+}
+
+
+/**
+ * A Layer Of Free Placed Objects (Spawn Points, Triggers, Collision Shapes, ...)
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ObjectLayer {
+    pub name: String,
+    pub objects: Vec<TilemapObject>,
+    pub properties: BTreeMap<String, String>,
+}
+
+
+/**
+ * A Single Object Of An Object Layer, Positions Are In Pixels
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TilemapObject {
+    pub id: u32,
+    pub name: String,
+    pub class: String, // Tiled's `class` (Called `type` Before Tiled 1.9)
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub rotation: f32, // Degrees Clockwise
+    pub gid: u32, // Tile Objects Only, 0 Otherwise
+    pub properties: BTreeMap<String, String>,
+}
+
+
+impl Tilemap {
+
+    const MAGIC:&'static [u8; 4] = b"TMAP";
+    const VERSION:u32 = 1;
+
+    /**
+     * Parse A TMX (XML) Or TMJ (JSON) Map.
+     * `external` Loads Tilesets The Map References By Path (`.tsx` / `.tsj`).
+     */
+    pub fn parse(data:&[u8], external:&dyn Fn(&str) -> Result<Vec<u8>, String>) -> Result<Self, String> {
+        let text = std::str::from_utf8(data).map_err(|_| "Tilemap Is Not UTF-8")?.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with('{') {
+            parse_tmj(text, external)
+        } else {
+            parse_tmx(text, external)
+        }
+    }
+
+    /**
+     * Get A Tile Layer By Name
+     */
+    pub fn get_layer(&self, name:&str) -> Option<&TileLayer> {
+        self.layers.iter().find(|layer| layer.name == name)
+    }
+
+    /**
+     * Get An Object Layer By Name
+     */
+    pub fn get_object_layer(&self, name:&str) -> Option<&ObjectLayer> {
+        self.object_layers.iter().find(|layer| layer.name == name)
+    }
+
+    /**
+     * Find The Tileset A Global Tile ID Belongs To, Ignoring The Flip Flags
+     */
+    pub fn tileset_for(&self, gid:u32) -> Option<&TilemapTileset> {
+        let gid = gid & 0x0FFF_FFFF;
+        if gid == 0 {
+            return None;
+        }
+        self.tilesets.iter().filter(|tileset| tileset.first_gid <= gid).max_by_key(|tileset| tileset.first_gid)
+    }
+
+    /**
+     * Encode The Map, Little Endian: `TMAP`, Version, Header, Properties, Tilesets, Tile Layers, Object Layers.
+     * Strings Are A u32 Length Followed By UTF-8, Lists Are A u32 Count Followed By The Items.
+     */
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer(Self::MAGIC.to_vec());
+        writer.u32(Self::VERSION);
+        for value in [self.width, self.height, self.tile_width, self.tile_height] {
+            writer.u32(value);
+        }
+        writer.properties(&self.properties);
+
+        writer.u32(self.tilesets.len() as u32);
+        for tileset in self.tilesets.iter() {
+            writer.u32(tileset.first_gid);
+            writer.string(&tileset.name);
+            writer.string(&tileset.image);
+            for value in [tileset.tile_width, tileset.tile_height, tileset.columns, tileset.tile_count] {
+                writer.u32(value);
+            }
+        }
+
+        writer.u32(self.layers.len() as u32);
+        for layer in self.layers.iter() {
+            writer.string(&layer.name);
+            writer.u32(layer.width);
+            writer.u32(layer.height);
+            writer.properties(&layer.properties);
+            for tile in layer.tiles.iter() {
+                writer.u32(*tile);
+            }
+        }
+
+        writer.u32(self.object_layers.len() as u32);
+        for layer in self.object_layers.iter() {
+            writer.string(&layer.name);
+            writer.properties(&layer.properties);
+            writer.u32(layer.objects.len() as u32);
+            for object in layer.objects.iter() {
+                writer.u32(object.id);
+                writer.string(&object.name);
+                writer.string(&object.class);
+                for value in [object.x, object.y, object.width, object.height, object.rotation] {
+                    writer.f32(value);
+                }
+                writer.u32(object.gid);
+                writer.properties(&object.properties);
+            }
+        }
+
+        writer.0
+    }
+
+    /**
+     * Decode A Map Written By `to_bytes`
+     */
+    pub fn from_bytes(data:&[u8]) -> Result<Self, String> {
+        let mut reader = Reader::new(data);
+        if reader.take(4)? != Self::MAGIC {
+            return Err("Not A Packed Tilemap".to_string());
+        }
+        let version = reader.u32()?;
+        if version != Self::VERSION {
+            return Err(format!("Unsupported Tilemap Version {}", version));
+        }
+
+        let mut map = Tilemap {
+            width: reader.u32()?,
+            height: reader.u32()?,
+            tile_width: reader.u32()?,
+            tile_height: reader.u32()?,
+            properties: reader.properties()?,
+            ..Default::default()
+        };
+
+        for _ in 0..reader.u32()? {
+            map.tilesets.push(TilemapTileset {
+                first_gid: reader.u32()?,
+                name: reader.string()?,
+                image: reader.string()?,
+                tile_width: reader.u32()?,
+                tile_height: reader.u32()?,
+                columns: reader.u32()?,
+                tile_count: reader.u32()?,
+            });
+        }
+
+        for _ in 0..reader.u32()? {
+            let name = reader.string()?;
+            let width = reader.u32()?;
+            let height = reader.u32()?;
+            let properties = reader.properties()?;
+            let tiles = (0..width as usize * height as usize).map(|_| reader.u32()).collect::<Result<Vec<u32>, String>>()?;
+            map.layers.push(TileLayer { name, width, height, tiles, properties });
+        }
+
+        for _ in 0..reader.u32()? {
+            let name = reader.string()?;
+            let properties = reader.properties()?;
+            let mut objects = Vec::new();
+            for _ in 0..reader.u32()? {
+                objects.push(TilemapObject {
+                    id: reader.u32()?,
+                    name: reader.string()?,
+                    class: reader.string()?,
+                    x: reader.f32()?,
+                    y: reader.f32()?,
+                    width: reader.f32()?,
+                    height: reader.f32()?,
+                    rotation: reader.f32()?,
+                    gid: reader.u32()?,
+                    properties: reader.properties()?,
+                });
+            }
+            map.object_layers.push(ObjectLayer { name, objects, properties });
+        }
+
+        Ok(map)
+    }
+}
+
+
+/**
+ * Decode Base64 Tile Data, Optionally Compressed, Into Global Tile IDs
+ */
+fn decode_base64_tiles(text:&str, compression:Option<&str>) -> Result<Vec<u32>, String> {
+    let compressed = base64::engine::general_purpose::STANDARD.decode(text.trim()).map_err(|e| format!("Invalid Base64 Tile Data: {}", e))?;
+    let bytes = match compression.unwrap_or("") {
+        "" => compressed,
+        "zlib" => {
+            let mut bytes = Vec::new();
+            ZlibDecoder::new(compressed.as_slice()).read_to_end(&mut bytes).map_err(|e| format!("Invalid Zlib Tile Data: {}", e))?;
+            bytes
+        },
+        "gzip" => {
+            let mut bytes = Vec::new();
+            GzDecoder::new(compressed.as_slice()).read_to_end(&mut bytes).map_err(|e| format!("Invalid Gzip Tile Data: {}", e))?;
+            bytes
+        },
+        other => return Err(format!("Unsupported Tile Data Compression {:?}", other)),
+    };
+    Ok(bytes.chunks_exact(4).map(|gid| u32::from_le_bytes(gid.try_into().unwrap())).collect())
+}
+
+
+/**
+ * Check A Layer Has One Tile Per Cell
+ */
+fn check_layer(layer:&TileLayer) -> Result<(), String> {
+    if layer.tiles.len() != layer.width as usize * layer.height as usize {
+        return Err(format!("Layer {} Has {} Tiles, Expected {}x{}", layer.name, layer.tiles.len(), layer.width, layer.height));
+    }
+    Ok(())
+}
+
+
+// ---------------------------------------------------------------- TMX ----------------------------------------------------------------
+
+
+fn xml_u32(node:roxmltree::Node, attribute:&str) -> u32 {
+    node.attribute(attribute).and_then(|value| value.parse().ok()).unwrap_or(0)
+}
+
+fn xml_f32(node:roxmltree::Node, attribute:&str) -> f32 {
+    node.attribute(attribute).and_then(|value| value.parse().ok()).unwrap_or(0.0)
+}
+
+fn xml_string(node:roxmltree::Node, attribute:&str) -> String {
+    node.attribute(attribute).unwrap_or_default().to_string()
+}
+
+/**
+ * Read The `<properties>` Child Of A Node, Multi-Line String Values Are Stored As Text
+ */
+fn xml_properties(node:roxmltree::Node) -> BTreeMap<String, String> {
+    node.children()
+        .filter(|child| child.has_tag_name("properties"))
+        .flat_map(|properties| properties.children().filter(|property| property.has_tag_name("property")))
+        .map(|property| (xml_string(property, "name"), property.attribute("value").or(property.text()).unwrap_or_default().to_string()))
+        .collect()
+}
+
+fn xml_tileset(node:roxmltree::Node, first_gid:u32) -> TilemapTileset {
+    let image = node.children().find(|child| child.has_tag_name("image"));
+    TilemapTileset {
+        first_gid,
+        name: xml_string(node, "name"),
+        image: image.and_then(|image| image.attribute("source")).map(asset_name_of).unwrap_or_default(),
+        tile_width: xml_u32(node, "tilewidth"),
+        tile_height: xml_u32(node, "tileheight"),
+        columns: xml_u32(node, "columns"),
+        tile_count: xml_u32(node, "tilecount"),
+    }
+}
+
+fn parse_tmx(text:&str, external:&dyn Fn(&str) -> Result<Vec<u8>, String>) -> Result<Tilemap, String> {
+    let document = roxmltree::Document::parse(text).map_err(|e| format!("Invalid TMX: {}", e))?;
+    let root = document.root_element();
+    if !root.has_tag_name("map") {
+        return Err("TMX Root Element Is Not <map>".to_string());
+    }
+    if root.attribute("infinite") == Some("1") {
+        return Err("Infinite Maps Are Not Supported".to_string());
+    }
+
+    let mut map = Tilemap {
+        width: xml_u32(root, "width"),
+        height: xml_u32(root, "height"),
+        tile_width: xml_u32(root, "tilewidth"),
+        tile_height: xml_u32(root, "tileheight"),
+        properties: xml_properties(root),
+        ..Default::default()
+    };
+
+    for node in root.children().filter(|child| child.has_tag_name("tileset")) {
+        let first_gid = xml_u32(node, "firstgid");
+        let tileset = match node.attribute("source") {
+            Some(source) => parse_external_tileset(&external(source)?, first_gid)?,
+            None => xml_tileset(node, first_gid),
+        };
+        map.tilesets.push(tileset);
+    }
+
+    parse_tmx_layers(root, &mut map)?;
+    Ok(map)
+}
+
+/**
+ * Collect The Layers Of A Map Or Group, Layers In Groups Are Flattened In Order
+ */
+fn parse_tmx_layers(parent:roxmltree::Node, map:&mut Tilemap) -> Result<(), String> {
+    for node in parent.children().filter(|child| child.is_element()) {
+        match node.tag_name().name() {
+            "layer" => {
+                let data = node.children().find(|child| child.has_tag_name("data")).ok_or(format!("Layer {} Has No Data", xml_string(node, "name")))?;
+                let text = data.text().unwrap_or_default();
+                let tiles = match data.attribute("encoding") {
+                    Some("csv") => text.split(',').map(str::trim).filter(|gid| !gid.is_empty())
+                        .map(|gid| gid.parse::<u32>().map_err(|_| format!("Invalid Tile ID {:?}", gid)))
+                        .collect::<Result<Vec<u32>, String>>()?,
+                    Some("base64") => decode_base64_tiles(text, data.attribute("compression"))?,
+                    Some(other) => return Err(format!("Unsupported Tile Data Encoding {:?}", other)),
+                    None => data.children().filter(|child| child.has_tag_name("tile")).map(|tile| xml_u32(tile, "gid")).collect(),
+                };
+                let layer = TileLayer {
+                    name: xml_string(node, "name"),
+                    width: xml_u32(node, "width"),
+                    height: xml_u32(node, "height"),
+                    tiles,
+                    properties: xml_properties(node),
+                };
+                check_layer(&layer)?;
+                map.layers.push(layer);
+            },
+            "objectgroup" => {
+                let objects = node.children().filter(|child| child.has_tag_name("object")).map(|object| TilemapObject {
+                    id: xml_u32(object, "id"),
+                    name: xml_string(object, "name"),
+                    class: object.attribute("class").or(object.attribute("type")).unwrap_or_default().to_string(),
+                    x: xml_f32(object, "x"),
+                    y: xml_f32(object, "y"),
+                    width: xml_f32(object, "width"),
+                    height: xml_f32(object, "height"),
+                    rotation: xml_f32(object, "rotation"),
+                    gid: xml_u32(object, "gid"),
+                    properties: xml_properties(object),
+                }).collect();
+                map.object_layers.push(ObjectLayer { name: xml_string(node, "name"), objects, properties: xml_properties(node) });
+            },
+            "group" => parse_tmx_layers(node, map)?,
+            _ => {} // Image Layers, Editor Settings, ...
+        }
+    }
+    Ok(())
+}
+
+
+// ---------------------------------------------------------------- TMJ ----------------------------------------------------------------
+
+
+fn json_u32(value:&Value, key:&str) -> u32 {
+    value.get(key).and_then(Value::as_u64).unwrap_or(0) as u32
+}
+
+fn json_f32(value:&Value, key:&str) -> f32 {
+    value.get(key).and_then(Value::as_f64).unwrap_or(0.0) as f32
+}
+
+fn json_string(value:&Value, key:&str) -> String {
+    value.get(key).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/**
+ * Read A `properties` Array, Non-String Values Are Stored As Their JSON Text
+ */
+fn json_properties(value:&Value) -> BTreeMap<String, String> {
+    value.get("properties").and_then(Value::as_array).into_iter().flatten()
+        .map(|property| {
+            let value = match property.get("value") {
+                Some(Value::String(text)) => text.clone(),
+                Some(other) => other.to_string(),
+                None => String::new(),
+            };
+            (json_string(property, "name"), value)
+        })
+        .collect()
+}
+
+fn json_tileset(value:&Value, first_gid:u32) -> TilemapTileset {
+    TilemapTileset {
+        first_gid,
+        name: json_string(value, "name"),
+        image: value.get("image").and_then(Value::as_str).map(asset_name_of).unwrap_or_default(),
+        tile_width: json_u32(value, "tilewidth"),
+        tile_height: json_u32(value, "tileheight"),
+        columns: json_u32(value, "columns"),
+        tile_count: json_u32(value, "tilecount"),
+    }
+}
+
+fn parse_tmj(text:&str, external:&dyn Fn(&str) -> Result<Vec<u8>, String>) -> Result<Tilemap, String> {
+    let root:Value = serde_json::from_str(text).map_err(|e| format!("Invalid TMJ: {}", e))?;
+    if root.get("infinite").and_then(Value::as_bool) == Some(true) {
+        return Err("Infinite Maps Are Not Supported".to_string());
+    }
+
+    let mut map = Tilemap {
+        width: json_u32(&root, "width"),
+        height: json_u32(&root, "height"),
+        tile_width: json_u32(&root, "tilewidth"),
+        tile_height: json_u32(&root, "tileheight"),
+        properties: json_properties(&root),
+        ..Default::default()
+    };
+
+    for value in root.get("tilesets").and_then(Value::as_array).into_iter().flatten() {
+        let first_gid = json_u32(value, "firstgid");
+        let tileset = match value.get("source").and_then(Value::as_str) {
+            Some(source) => parse_external_tileset(&external(source)?, first_gid)?,
+            None => json_tileset(value, first_gid),
+        };
+        map.tilesets.push(tileset);
+    }
+
+    parse_tmj_layers(&root, &mut map)?;
+    Ok(map)
+}
+
+/**
+ * Collect The Layers Of A Map Or Group, Layers In Groups Are Flattened In Order
+ */
+fn parse_tmj_layers(parent:&Value, map:&mut Tilemap) -> Result<(), String> {
+    for value in parent.get("layers").and_then(Value::as_array).into_iter().flatten() {
+        match value.get("type").and_then(Value::as_str).unwrap_or_default() {
+            "tilelayer" => {
+                let tiles = match value.get("data") {
+                    Some(Value::Array(gids)) => gids.iter().map(|gid| gid.as_u64().unwrap_or(0) as u32).collect(),
+                    Some(Value::String(text)) => decode_base64_tiles(text, value.get("compression").and_then(Value::as_str))?,
+                    _ => return Err(format!("Layer {} Has No Data", json_string(value, "name"))),
+                };
+                let layer = TileLayer {
+                    name: json_string(value, "name"),
+                    width: json_u32(value, "width"),
+                    height: json_u32(value, "height"),
+                    tiles,
+                    properties: json_properties(value),
+                };
+                check_layer(&layer)?;
+                map.layers.push(layer);
+            },
+            "objectgroup" => {
+                let objects = value.get("objects").and_then(Value::as_array).into_iter().flatten().map(|object| TilemapObject {
+                    id: json_u32(object, "id"),
+                    name: json_string(object, "name"),
+                    class: object.get("class").or(object.get("type")).and_then(Value::as_str).unwrap_or_default().to_string(),
+                    x: json_f32(object, "x"),
+                    y: json_f32(object, "y"),
+                    width: json_f32(object, "width"),
+                    height: json_f32(object, "height"),
+                    rotation: json_f32(object, "rotation"),
+                    gid: json_u32(object, "gid"),
+                    properties: json_properties(object),
+                }).collect();
+                map.object_layers.push(ObjectLayer { name: json_string(value, "name"), objects, properties: json_properties(value) });
+            },
+            "group" => parse_tmj_layers(value, map)?,
+            _ => {} // Image Layers
+        }
+    }
+    Ok(())
+}
+
+
+/**
+ * Parse A TSX Or TSJ Tileset File Referenced By A Map
+ */
+fn parse_external_tileset(data:&[u8], first_gid:u32) -> Result<TilemapTileset, String> {
+    let text = std::str::from_utf8(data).map_err(|_| "Tileset Is Not UTF-8")?.trim_start_matches('\u{feff}').trim_start();
+    if text.starts_with('{') {
+        let value:Value = serde_json::from_str(text).map_err(|e| format!("Invalid TSJ: {}", e))?;
+        Ok(json_tileset(&value, first_gid))
+    } else {
+        let document = roxmltree::Document::parse(text).map_err(|e| format!("Invalid TSX: {}", e))?;
+        Ok(xml_tileset(document.root_element(), first_gid))
+    }
+}
+
+
+/**
+ * Convert Every Tilemap Asset (TMX / TMJ) Into A Packed Tilemap.
+ * External Tilesets Are Read From The `Tileset` Assets Of The Same Name, And Every Tileset Image
+ * Must Be One Of The Assets Being Packed. Returns How Many Maps Were Converted.
+ */
+pub fn import_tilemaps(assets:&mut [Asset]) -> Result<usize, String> {
+    let names:HashSet<&str> = assets.iter().map(|asset| asset.get_name()).collect();
+    let tileset_data = |source:&str| -> Result<Vec<u8>, String> {
+        let name = asset_name_of(source);
+        assets.iter()
+            .find(|asset| asset.get_name() == name && asset.get_type().kind_name() == "Tileset")
+            .and_then(|asset| asset.get_data().clone())
+            .ok_or(format!("Tileset {} Is Not Being Packed", source))
+    };
+
+    let mut converted:Vec<(usize, Tilemap)> = Vec::new();
+    for (index, asset) in assets.iter().enumerate() {
+        if !matches!(asset.get_type(), AssetType::Custom { kind, format } if kind == "Tilemap" && format != "BIN") {
+            continue;
+        }
+        let data = asset.get_data().as_deref().ok_or(format!("Tilemap {} Has No Data", asset.get_name()))?;
+        let map = Tilemap::parse(data, &tileset_data).map_err(|e| format!("Tilemap {}: {}", asset.get_name(), e))?;
+        for tileset in map.tilesets.iter() {
+            if tileset.image.is_empty() {
+                return Err(format!("Tilemap {} Uses Tileset {:?} Which Is An Image Collection, Only Tilesets Cut From One Image Are Supported", asset.get_name(), tileset.name));
+            }
+            if !names.contains(tileset.image.as_str()) {
+                return Err(format!("Tilemap {} Uses Tileset Image {:?} Which Is Not Being Packed", asset.get_name(), tileset.image));
+            }
+        }
+        converted.push((index, map));
+    }
+
+    let count = converted.len();
+    for (index, map) in converted {
+        let asset = &mut assets[index];
+        println!("[+] Tilemap {}: {}x{} Tiles, {} Layers, {} Object Layers", asset.get_name(), map.width, map.height, map.layers.len(), map.object_layers.len());
+        asset.set_metadata("width", &map.width.to_string());
+        asset.set_metadata("height", &map.height.to_string());
+        asset.set_metadata("tile_width", &map.tile_width.to_string());
+        asset.set_metadata("tile_height", &map.tile_height.to_string());
+        for tileset in map.tilesets.iter() {
+            asset.add_dependency(&tileset.image);
+        }
+        asset.set_type(AssetType::Custom { kind: "Tilemap".to_string(), format: "BIN".to_string() });
+        asset.set_data(map.to_bytes());
+    }
+    Ok(count)
+}
@@ -0,0 +1,117 @@
+use crate::{Asset, AssetManifest, LoadedChunk};
+
+
+/**
+ * A Chunk And Its Manifest Mounted Into An `AssetVfs`
+ */
+#[derive(Debug)]
+pub struct MountedChunk {
+    pub id: u32, // Handle Returned By `mount`, Used To Unmount
+    pub name: String, // Where The Chunk Came From, For Messages
+    pub priority: i32, // Higher Priorities Override Lower Ones
+    pub manifest: AssetManifest,
+    pub data: Vec<u8>,
+}
+
+
+/**
+ * An Asset Found In The VFS, Along With The Chunk It Was Found In
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct VfsEntry<'a> {
+    pub mount: &'a MountedChunk,
+    pub asset: &'a Asset,
+    pub data: &'a [u8],
+}
+
+
+/**
+ * Several Chunks Mounted Together, e.g. Base Game, DLC And Patches.
+ * Looking Up A Name Finds The Asset In The Highest Priority Chunk That Has It,
+ * Chunks Mounted Later Win Ties, So A Patch Mounted At The Same Priority Still Overrides.
+ */
+#[derive(Debug, Default)]
+pub struct AssetVfs {
+    mounts: Vec<MountedChunk>, // Highest Priority First
+    next_id: u32,
+}
+
+
+impl AssetVfs {
+
+    /**
+     * Create An Empty VFS
+     */
+    pub fn new() -> Self {
+        AssetVfs::default()
+    }
+
+
+    /**
+     * Mount A Chunk File And Its Manifest, Returning The Mount's Handle
+     */
+    pub fn mount(&mut self, chunk_path:&str, manifest_path:&str, priority:i32) -> Result<u32, String> {
+        let loaded = LoadedChunk::load(chunk_path, manifest_path)?;
+        self.mount_data(chunk_path, loaded.manifest, loaded.data, priority)
+    }
+
+
+    /**
+     * Mount A Chunk Already In Memory, Returning The Mount's Handle
+     */
+    pub fn mount_data(&mut self, name:&str, manifest:AssetManifest, data:Vec<u8>, priority:i32) -> Result<u32, String> {
+        if let Some(asset) = manifest.get_assets().iter().find(|asset| asset.get_chunk_location() + asset.get_size() > data.len()) {
+            return Err(format!("Asset {} Lies Outside Chunk {}", asset.get_name(), name));
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        // Goes Before Every Mount Of The Same Or Lower Priority, So The Newest Wins Ties
+        let index = self.mounts.iter().position(|mount| mount.priority <= priority).unwrap_or(self.mounts.len());
+        self.mounts.insert(index, MountedChunk { id, name: name.to_string(), priority, manifest, data });
+        Ok(id)
+    }
+
+
+    /**
+     * Unmount A Chunk, Returns False If Nothing Was Mounted With That Handle
+     */
+    pub fn unmount(&mut self, id:u32) -> bool {
+        let count = self.mounts.len();
+        self.mounts.retain(|mount| mount.id != id);
+        self.mounts.len() != count
+    }
+
+
+    /**
+     * Get The Mounted Chunks, Highest Priority First
+     */
+    pub fn get_mounts(&self) -> &Vec<MountedChunk> {
+        &self.mounts
+    }
+
+
+    /**
+     * Find An Asset In The Highest Priority Chunk That Has It
+     */
+    pub fn lookup(&self, name:&str) -> Option<VfsEntry<'_>> {
+        self.mounts.iter().find_map(|mount| {
+            let asset = mount.manifest.get_assets().iter().find(|asset| asset.get_name() == name)?;
+            let data = &mount.data[asset.get_chunk_location()..asset.get_chunk_location() + asset.get_size()];
+            Some(VfsEntry { mount, asset, data })
+        })
+    }
+
+
+    /**
+     * Get The Names Of Every Asset Visible Through The VFS
+     */
+    pub fn asset_names(&self) -> Vec<&str> {
+        let mut names:Vec<&str> = self.mounts.iter()
+            .flat_map(|mount| mount.manifest.get_assets().iter().map(Asset::get_name))
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use assetchunk::{detect_animations, free_asset_chunk, get_animation, remap_animations_to_atlas, Asset, AnimationConfig, AssetManifest, AssetType, LoopMode, RawCAnimation, RawCAssetChunk, RawCAssetManifest, RawCLoopMode, SpriteRect};
+use std::ffi::{CStr, CString};
+use std::collections::BTreeMap;
+use common::fixture_asset;
+
+#[test]
+fn sidecar_definitions_cut_the_grid() {
+    let config = AnimationConfig::from_toml("frame_width = 16\nframes = [3, 1]\ndurations_ms = [50, 150]\nloop_mode = \"once\"\n").unwrap();
+    let animation = config.build("strip", 64, 16).unwrap();
+    assert_eq!(animation.loop_mode, LoopMode::Once);
+    assert_eq!((animation.frames[0].x, animation.frames[0].duration_ms), (48, 50));
+    assert_eq!((animation.frames[1].x, animation.frames[1].duration_ms), (16, 150));
+
+    assert!(AnimationConfig::from_toml("frames = [4]").unwrap().build("strip", 64, 16).is_err());
+}
+
+#[test]
+fn naming_conventions_are_detected() {
+    let assets:Vec<Asset> = [
+        "ctest/assets/adept_necromancer_idle.png",
+        "ctest/assets/characters_general.png",
+        "test/Tileset_Water_Frame2.png",
+        "test/Tileset_Water_Frame1.png",
+        "test/Tileset_Water_Frame3.png",
+        "test/Tileset_Water_Frame4.png",
+    ].iter().map(|path| fixture_asset(path, AssetType::Image("PNG".to_string()))).collect();
+
+    let animations = detect_animations(&assets, &BTreeMap::new()).unwrap();
+    assert_eq!(animations.len(), 2);
+
+    let idle = animations.iter().find(|animation| animation.name == "adept_necromancer_idle").unwrap();
+    assert_eq!(idle.frames.len(), 4);
+    assert_eq!(idle.frames[1].width, 16);
+
+    let water = animations.iter().find(|animation| animation.name == "Tileset_Water").unwrap();
+    let frame_images:Vec<&str> = water.frames.iter().map(|frame| frame.image.as_str()).collect();
+    assert_eq!(frame_images, ["Tileset_Water_Frame1", "Tileset_Water_Frame2", "Tileset_Water_Frame3", "Tileset_Water_Frame4"]);
+
+    let mut manifest = AssetManifest::new(Vec::new());
+    manifest.set_animations(animations.clone());
+    let manifest = Box::into_raw(Box::new(RawCAssetManifest::from_manifest(&manifest)));
+    let chunk = Box::into_raw(Box::new(RawCAssetChunk { data: std::ptr::null_mut(), size: 0, manifest }));
+    let mut raw_animation = RawCAnimation { name: std::ptr::null(), image: std::ptr::null(), loop_mode: RawCLoopMode::Once, frames: std::ptr::null(), frame_count: 0 };
+    assert!(get_animation(chunk, CString::new("adept_necromancer_idle").unwrap().as_ptr(), &mut raw_animation));
+    assert_eq!(unsafe { CStr::from_ptr(raw_animation.image) }.to_str().unwrap(), idle.image);
+    assert_eq!(raw_animation.frame_count, 4);
+    assert_eq!(unsafe { (*raw_animation.frames.add(1)).width }, 16);
+    assert!(!get_animation(chunk, CString::new("walk").unwrap().as_ptr(), &mut raw_animation));
+    free_asset_chunk(chunk);
+}
+
+#[test]
+fn zero_sized_pngs_are_skipped() {
+    let mut header = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+    header.extend(32u32.to_be_bytes());
+    header.extend(0u32.to_be_bytes());
+    let assets = vec![Asset::from_data("broken_strip", AssetType::Image("PNG".to_string()), header)];
+    assert!(detect_animations(&assets, &BTreeMap::new()).unwrap().is_empty());
+}
+
+#[test]
+fn atlas_remap_moves_the_animation_image_too() {
+    let assets = vec![fixture_asset("ctest/assets/adept_necromancer_idle.png", AssetType::Image("PNG".to_string()))];
+    let mut animations = detect_animations(&assets, &BTreeMap::new()).unwrap();
+    let sprite = SpriteRect { name: "adept_necromancer_idle".to_string(), atlas: "characters_0".to_string(), x: 8, y: 4, width: 64, height: 16, u0: 0.0, v0: 0.0, u1: 1.0, v1: 1.0 };
+    remap_animations_to_atlas(&mut animations, &[sprite]);
+    assert_eq!(animations[0].image, "characters_0");
+    assert_eq!((animations[0].frames[1].image.as_str(), animations[0].frames[1].x, animations[0].frames[1].y), ("characters_0", 24, 4));
+}
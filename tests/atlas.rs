@@ -0,0 +1,53 @@
+mod common;
+
+use assetchunk::{build_atlas, decode_png_rgba, free_asset_chunk, get_sprite_rect, AssetManifest, AtlasConfig, PackConfig, RawCAssetChunk, RawCAssetManifest, RawCSpriteRect, RgbaImage};
+use std::ffi::{CStr, CString};
+use common::fixture;
+
+fn atlas_config(page_size:u32) -> AtlasConfig {
+    let config = PackConfig::from_toml(&format!("[[atlases]]\nname = \"test\"\nsprites = [\"*\"]\npage_size = {}\n", page_size)).unwrap();
+    config.atlases[0].clone()
+}
+
+#[test]
+fn sprites_do_not_overlap_and_spill_onto_new_pages() {
+    let sprites:Vec<(String, RgbaImage)> = (0..10).map(|i| (format!("sprite{}", i), RgbaImage::new(10 + i, 12))).collect();
+    let (pages, rects) = build_atlas(&atlas_config(40), sprites).unwrap();
+    assert_eq!(rects.len(), 10);
+    assert!(pages.len() > 1);
+
+    for a in rects.iter() {
+        let page = pages.iter().find(|page| page.name == a.atlas).unwrap();
+        assert!(a.x + a.width <= page.image.width && a.y + a.height <= page.image.height);
+        assert_eq!(a.u1, (a.x + a.width) as f32 / page.image.width as f32);
+        for b in rects.iter().filter(|b| b.name != a.name && b.atlas == a.atlas) {
+            let overlaps = a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height;
+            assert!(!overlaps, "{} overlaps {}", a.name, b.name);
+        }
+    }
+}
+
+#[test]
+fn sprite_pixels_are_copied_into_the_page() {
+    let sprite_data = fixture("ctest/assets/sorcerer_sprite.png").1;
+    let sprite = decode_png_rgba(&sprite_data).unwrap();
+    let (pages, rects) = build_atlas(&atlas_config(256), vec![("sorcerer".to_string(), sprite.clone())]).unwrap();
+
+    let rect = &rects[0];
+    let page = &pages[0].image;
+    let row = (rect.y as usize * page.width as usize + rect.x as usize) * 4;
+    assert_eq!(&page.pixels[row..row + sprite.width as usize * 4], &sprite.pixels[..sprite.width as usize * 4]);
+
+    let mut manifest = AssetManifest::new(Vec::new());
+    manifest.set_sprites(rects.clone());
+    assert_eq!(manifest.get_sprite_rect("sorcerer"), Some(&rects[0]));
+
+    let manifest = Box::into_raw(Box::new(RawCAssetManifest::from_manifest(&manifest)));
+    let chunk = Box::into_raw(Box::new(RawCAssetChunk { data: std::ptr::null_mut(), size: 0, manifest }));
+    let mut raw_rect = RawCSpriteRect { name: std::ptr::null(), atlas: std::ptr::null(), x: 0, y: 0, width: 0, height: 0, u0: 0.0, v0: 0.0, u1: 0.0, v1: 0.0 };
+    assert!(get_sprite_rect(chunk, CString::new("sorcerer").unwrap().as_ptr(), &mut raw_rect));
+    assert_eq!(unsafe { CStr::from_ptr(raw_rect.atlas) }.to_str().unwrap(), rect.atlas);
+    assert_eq!((raw_rect.x, raw_rect.y, raw_rect.width, raw_rect.u1), (rect.x, rect.y, rect.width, rect.u1));
+    assert!(!get_sprite_rect(chunk, CString::new("knight").unwrap().as_ptr(), &mut raw_rect));
+    free_asset_chunk(chunk);
+}
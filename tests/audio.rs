@@ -0,0 +1,82 @@
+use assetchunk::{check_audio_rules, get_audio_info, Asset, AssetRegistry, AssetType, AudioInfo, PackConfig, RawCAsset, RawCAudioInfo};
+
+fn wav(sample_rate:u32, channels:u16, bit_depth:u16, frames:u32) -> Vec<u8> {
+    let block_align = channels * bit_depth / 8;
+    let data_size = frames * block_align as u32;
+    let mut wav = b"RIFF".to_vec();
+    wav.extend_from_slice(&(36 + data_size).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&channels.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+    wav.extend_from_slice(&block_align.to_le_bytes());
+    wav.extend_from_slice(&bit_depth.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_size.to_le_bytes());
+    wav.resize(wav.len() + data_size as usize, 0);
+    wav
+}
+
+fn ogg_page(granule:i64, body:&[u8]) -> Vec<u8> {
+    let mut page = b"OggS\x00\x02".to_vec();
+    page.extend_from_slice(&granule.to_le_bytes());
+    page.extend_from_slice(&[0; 12]); // Serial, Sequence, Checksum
+    page.push(1);
+    page.push(body.len() as u8);
+    page.extend_from_slice(body);
+    page
+}
+
+#[test]
+fn headers_are_parsed() {
+    assert_eq!(AudioInfo::from_data(&wav(22050, 2, 16, 11025)), Some(AudioInfo { sample_rate: 22050, channels: 2, bit_depth: 16, duration_ms: 500 }));
+
+    let mut identification = b"\x01vorbis".to_vec();
+    identification.extend_from_slice(&0u32.to_le_bytes());
+    identification.push(1);
+    identification.extend_from_slice(&44100u32.to_le_bytes());
+    identification.extend_from_slice(&[0; 14]);
+    let mut ogg = ogg_page(0, &identification);
+    ogg.extend(ogg_page(-1, b"\x03vorbis"));
+    ogg.extend(ogg_page(88200, &[0; 8]));
+    assert_eq!(AudioInfo::from_data(&ogg), Some(AudioInfo { sample_rate: 44100, channels: 1, bit_depth: 0, duration_ms: 2000 }));
+
+    // STREAMINFO: 48000 Hz, 2 Channels, 24 Bits, 144000 Samples
+    let packed:u64 = (48000 << 44) | (1 << 41) | (23 << 36) | 144000;
+    let mut flac = b"fLaC\x80\x00\x00\x22".to_vec();
+    flac.extend_from_slice(&[0; 10]);
+    flac.extend_from_slice(&packed.to_be_bytes());
+    flac.extend_from_slice(&[0; 16]);
+    assert_eq!(AudioInfo::from_data(&flac), Some(AudioInfo { sample_rate: 48000, channels: 2, bit_depth: 24, duration_ms: 3000 }));
+}
+
+#[test]
+fn strict_rules_fail_on_wrong_sample_rate() {
+    let mut assets = vec![
+        Asset::from_data("music", AssetType::Audio("WAV".to_string()), wav(48000, 2, 16, 480)),
+        Asset::from_data("hit", AssetType::Audio("WAV".to_string()), wav(22050, 1, 16, 220)),
+    ];
+    for asset in assets.iter_mut() {
+        asset.extract_metadata();
+    }
+    assert_eq!(assets[1].get_metadata().get("sample_rate").map(String::as_str), Some("22050"));
+
+    let lenient = PackConfig::from_toml("[audio]\nsample_rates = [48000]\n").unwrap();
+    assert!(check_audio_rules(&assets, &lenient.audio).is_ok());
+
+    let strict = PackConfig::from_toml("[audio]\nsample_rates = [48000]\nstrict = true\n").unwrap();
+    let error = check_audio_rules(&assets, &strict.audio).unwrap_err();
+    assert!(error.contains("hit") && !error.contains("music"));
+}
+
+#[test]
+fn c_api_fills_the_audio_info() {
+    let mut assets = vec![Asset::from_data("jump", AssetType::Other("UNIDENTIFIED".to_string()), wav(22050, 2, 16, 11025))];
+    AssetRegistry::new().identify_assets(&mut assets);
+    let mut info = RawCAudioInfo { sample_rate: 0, channels: 0, bit_depth: 0, duration_ms: 0 };
+    assert!(get_audio_info(&RawCAsset::from_asset(&assets[0]), &mut info));
+    assert_eq!((info.sample_rate, info.channels, info.bit_depth, info.duration_ms), (22050, 2, 16, 500));
+    assert!(!get_audio_info(&RawCAsset::from_asset(&Asset::from_data("notes", AssetType::Other("TXT".to_string()), Vec::new())), &mut info));
+}
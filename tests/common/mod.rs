@@ -0,0 +1,38 @@
+#![allow(dead_code)] // Each Test Only Uses Some Of The Helpers
+
+use assetchunk::{Asset, AssetType};
+use std::path::PathBuf;
+
+/**
+ * Full Path Of A File Checked Into The Repo
+ */
+pub fn fixture_path(path:&str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/**
+ * Read A File Checked Into The Repo, Returning Its Full Path And Data
+ */
+pub fn fixture(path:&str) -> (String, Vec<u8>) {
+    let full_path = fixture_path(path);
+    let data = std::fs::read(&full_path).unwrap();
+    (full_path.to_str().unwrap().to_string(), data)
+}
+
+/**
+ * Load A File Checked Into The Repo As An Asset Named After The File
+ */
+pub fn fixture_asset(path:&str, asset_type:AssetType) -> Asset {
+    let full_path = fixture_path(path);
+    let name = full_path.file_stem().unwrap().to_str().unwrap().to_string();
+    Asset::load(full_path.to_str().unwrap(), &name, asset_type)
+}
+
+/**
+ * A Directory Under The System Temp Directory For One Test, Created If Missing
+ */
+pub fn temp_directory(name:&str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("assetchunk_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
@@ -0,0 +1,66 @@
+use assetchunk::{free_asset_load_order, free_asset_manifest, get_asset_load_order, import_meshes, remap_dependencies_to_atlas, sort_assets_by_dependencies, Asset, AssetManifest, AssetType, RawCAssetChunk, RawCAssetManifest, SpriteRect};
+use std::ffi::{CStr, CString};
+
+fn asset(name:&str, dependencies:&[&str]) -> Asset {
+    let mut asset = Asset::from_data(name, AssetType::Other("JSON".to_string()), Vec::new());
+    for dependency in dependencies {
+        asset.add_dependency(dependency);
+    }
+    asset
+}
+
+fn names(assets:&[&Asset]) -> Vec<String> {
+    assets.iter().map(|asset| asset.get_name().to_string()).collect()
+}
+
+#[test]
+fn dependencies_load_first() {
+    let mut assets = vec![asset("level", &["tiles", "intro"]), asset("intro", &["common", "portrait"]), asset("tiles", &["terrain"]),
+        asset("common", &[]), asset("terrain", &[]), asset("portrait", &[]), asset("music", &[])];
+    sort_assets_by_dependencies(&mut assets).unwrap();
+    let order:Vec<&str> = assets.iter().map(|asset| asset.get_name()).collect();
+    assert_eq!(order, ["terrain", "tiles", "common", "portrait", "intro", "level", "music"]);
+
+    let manifest = AssetManifest::new(assets);
+    assert_eq!(names(&manifest.get_dependency_closure("intro").unwrap()), ["common", "portrait"]);
+    assert_eq!(names(&manifest.get_load_order_for("tiles").unwrap()), ["terrain", "tiles"]);
+    assert_eq!(manifest.get_load_order().unwrap().len(), 7);
+    assert!(manifest.get_load_order_for("missing").is_err());
+
+    let chunk = RawCAssetChunk { data: std::ptr::null_mut(), size: 0, manifest: Box::into_raw(Box::new(RawCAssetManifest::from_manifest(&manifest))) };
+    let intro = CString::new("intro").unwrap();
+    let load_order = get_asset_load_order(&chunk, intro.as_ptr());
+    let ordered = unsafe { std::slice::from_raw_parts((*load_order).assets, (*load_order).asset_count) };
+    let ordered:Vec<&str> = ordered.iter().map(|asset| unsafe { CStr::from_ptr((**asset).name).to_str().unwrap() }).collect();
+    assert_eq!(ordered, ["common", "portrait", "intro"]);
+    free_asset_load_order(load_order);
+    free_asset_manifest(chunk.manifest);
+}
+
+#[test]
+fn cycles_and_missing_targets_fail() {
+    let mut assets = vec![asset("a", &["b"]), asset("b", &["c"]), asset("c", &["a"])];
+    assert_eq!(sort_assets_by_dependencies(&mut assets).unwrap_err(), "Dependency Cycle: a -> b -> c -> a");
+
+    let mut assets = vec![asset("a", &["ghost"])];
+    assert!(sort_assets_by_dependencies(&mut assets).unwrap_err().contains("ghost"));
+}
+
+#[test]
+fn importers_and_atlases_record_edges() {
+    let obj = || Asset::from_data("crate", AssetType::Model("OBJ".to_string()), b"mtllib crate_materials.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n".to_vec());
+    let mtl = || Asset::from_data("crate_materials", AssetType::Other("MTL".to_string()), b"newmtl wood\nmap_Kd -s 2 2 1 textures/crate_wood.png\n".to_vec());
+    let mut assets = vec![obj(), mtl()];
+    assert!(import_meshes(&mut assets).unwrap_err().contains("crate_wood"));
+
+    let mut assets = vec![obj(), mtl(), asset("crate_wood", &[])];
+    import_meshes(&mut assets).unwrap();
+    assert_eq!(*assets[0].get_dependencies(), ["crate_materials"]);
+    assert_eq!(*assets[1].get_dependencies(), ["crate_wood"]);
+
+    // The Texture Was Packed Into An Atlas Page And Removed
+    assets.pop();
+    let sprite = SpriteRect { name: "crate_wood".to_string(), atlas: "props_0".to_string(), x: 0, y: 0, width: 8, height: 8, u0: 0.0, v0: 0.0, u1: 1.0, v1: 1.0 };
+    remap_dependencies_to_atlas(&mut assets, &[sprite]);
+    assert_eq!(*assets[1].get_dependencies(), ["props_0"]);
+}
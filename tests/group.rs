@@ -0,0 +1,109 @@
+mod common;
+
+use assetchunk::{assign_groups, free_asset_chunk, get_asset, group_ranges, load_asset_group, sort_assets_by_group, Asset, AssetManifest, AssetType};
+use std::collections::BTreeMap;
+use std::ffi::{CStr, CString};
+use common::temp_directory;
+
+fn asset(name:&str, data:&str) -> Asset {
+    Asset::from_data(name, AssetType::Other("TXT".to_string()), data.as_bytes().to_vec())
+}
+
+/**
+ * Group, Lay Out And "Pack" Assets The Way The CLI Does, Returning The Manifest And Chunk
+ */
+fn pack(mut assets:Vec<Asset>, groups:&BTreeMap<String, Vec<String>>) -> (AssetManifest, Vec<u8>) {
+    assign_groups(&mut assets, groups).unwrap();
+    sort_assets_by_group(&mut assets).unwrap();
+    let mut chunk = Vec::new();
+    for asset in assets.iter_mut() {
+        asset.set_chunk_location(chunk.len());
+        chunk.extend(asset.get_data().as_deref().unwrap());
+    }
+    let mut manifest = AssetManifest::new(assets);
+    manifest.set_groups(group_ranges(manifest.get_assets()).unwrap());
+    (manifest, chunk)
+}
+
+#[test]
+fn groups_are_laid_out_contiguously() {
+    let mut groups = BTreeMap::new();
+    groups.insert("town".to_string(), vec!["town_*".to_string(), "shopkeeper".to_string()]);
+    groups.insert("battle".to_string(), vec!["battle_*".to_string()]);
+    let assets = vec![asset("town_map", "TM"), asset("battle_music", "BM"), asset("title", "T"), asset("shopkeeper", "SK"), asset("battle_ui", "BU")];
+    let (manifest, chunk) = pack(assets, &groups);
+
+    assert_eq!(chunk, b"TBMBUTMSK");
+    let town = manifest.get_group("town").unwrap();
+    assert_eq!((town.offset, town.size), (5, 4));
+    assert_eq!(town.assets, ["town_map", "shopkeeper"]);
+    assert_eq!(manifest.get_group("battle").unwrap().assets, ["battle_music", "battle_ui"]);
+
+    groups.insert("shops".to_string(), vec!["shop*".to_string()]);
+    let mut assets = vec![asset("shopkeeper", "SK")];
+    assert!(assign_groups(&mut assets, &groups).unwrap_err().contains("shopkeeper"));
+}
+
+#[test]
+fn groups_are_laid_out_in_load_order() {
+    let mut groups = BTreeMap::new();
+    groups.insert("town".to_string(), vec!["town_*".to_string()]);
+    groups.insert("battle".to_string(), vec!["battle_*".to_string()]);
+    let mut town_map = asset("town_map", "TM");
+    town_map.add_dependency("town_tiles");
+    let mut battle_ui = asset("battle_ui", "BU");
+    battle_ui.add_dependency("town_map");
+    let mut title = asset("title", "T");
+    title.add_dependency("battle_ui");
+    let (manifest, chunk) = pack(vec![title, battle_ui, town_map, asset("town_tiles", "TT")], &groups);
+
+    // `town` Goes Before `battle` Despite The Name Order, And `title` Goes Last
+    assert_eq!(chunk, b"TTTMBUT");
+    assert_eq!(manifest.get_group("town").unwrap().assets, ["town_tiles", "town_map"]);
+    let order:Vec<&str> = manifest.get_assets().iter().map(|asset| asset.get_name()).collect();
+    assert_eq!(order, ["town_tiles", "town_map", "battle_ui", "title"]);
+
+    let mut town_map = asset("town_map", "TM");
+    town_map.add_dependency("battle_music");
+    let mut battle_ui = asset("battle_ui", "BU");
+    battle_ui.add_dependency("town_map");
+    let mut assets = vec![town_map, battle_ui, asset("battle_music", "BM")];
+    assign_groups(&mut assets, &groups).unwrap();
+    assert!(sort_assets_by_group(&mut assets).unwrap_err().contains("Dependency Cycle: group battle -> group town -> group battle"));
+}
+
+#[test]
+fn only_the_group_is_read() {
+    let directory = temp_directory("group");
+    let chunk_path = directory.join("world.chunk.asset");
+    let manifest_path = directory.join("world.manifest.asset");
+
+    let mut groups = BTreeMap::new();
+    groups.insert("town".to_string(), vec!["town_*".to_string()]);
+    let (manifest, chunk) = pack(vec![asset("title", "TITLE"), asset("town_map", "MAP"), asset("town_music", "MUSIC")], &groups);
+    std::fs::write(&chunk_path, chunk).unwrap();
+    manifest.write(manifest_path.to_str().unwrap());
+
+    let loaded = manifest.load_asset_group(chunk_path.to_str().unwrap(), "town").unwrap();
+    assert_eq!(loaded.data, b"MAPMUSIC");
+    assert_eq!(loaded.get_asset_data("town_music"), Some(&b"MUSIC"[..]));
+    assert_eq!(loaded.get_asset_data("title"), None);
+    assert!(manifest.load_asset_group(chunk_path.to_str().unwrap(), "castle").is_err());
+
+    let chunk_path = CString::new(chunk_path.to_str().unwrap()).unwrap();
+    let manifest_path = CString::new(manifest_path.to_str().unwrap()).unwrap();
+    let group = CString::new("town").unwrap();
+    let chunk = load_asset_group(chunk_path.as_ptr(), manifest_path.as_ptr(), group.as_ptr());
+    assert!(!chunk.is_null());
+    let raw_chunk = unsafe { &*chunk };
+    assert_eq!(raw_chunk.size, 8);
+    let raw_asset = unsafe { &*get_asset(raw_chunk, CString::new("town_music").unwrap().as_ptr()) };
+    assert_eq!(unsafe { std::slice::from_raw_parts(raw_asset.data as *const u8, raw_asset.size) }, b"MUSIC");
+    let raw_asset = unsafe { &*get_asset(raw_chunk, CString::new("town_map").unwrap().as_ptr()) }; // The Chunk Is Still The Caller's
+    assert_eq!(unsafe { std::slice::from_raw_parts(raw_asset.data as *const u8, raw_asset.size) }, b"MAP");
+    let manifest = unsafe { &*raw_chunk.manifest };
+    assert_eq!(unsafe { CStr::from_ptr((*manifest.groups).name) }.to_str().unwrap(), "town");
+    free_asset_chunk(chunk);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,73 @@
+use assetchunk::{asset_constant_name, assetchunk_get_asset_by_id, check_asset_ids, free_asset_chunk, get_asset_by_id, generate_c_ids, generate_rust_ids, get_asset_id, Asset, AssetId, AssetManifest, AssetType, RawCAsset, RawCAssetChunk, RawCAssetKind, RawCAssetManifest, RawCAssetView, SharedChunk, SharedChunkData};
+use std::mem::MaybeUninit;
+use std::ffi::CString;
+
+fn text(name:&str) -> Asset {
+    Asset::from_data(name, AssetType::Other("TXT".to_string()), name.as_bytes().to_vec())
+}
+
+#[test]
+fn ids_are_stable_hashes_of_names() {
+    assert_eq!(AssetId::from_name("a"), AssetId(0xe40c292c)); // FNV-1a
+    assert_eq!(text("tileset_terrain1").get_id(), AssetId::from_name("tileset_terrain1"));
+    assert_eq!(get_asset_id(CString::new("tileset_terrain1").unwrap().as_ptr()), AssetId::from_name("tileset_terrain1").0);
+    assert_eq!(AssetId(0x2e3d90b1).to_string(), "0x2e3d90b1");
+
+    assert!(check_asset_ids(&[text("hero"), text("title")]).is_ok());
+    assert!(check_asset_ids(&[text("hero"), text("hero")]).unwrap_err().contains("Share The Asset ID"));
+
+    // Manifests From Before IDs Get Them When Loaded
+    let json = serde_json::to_string(&AssetManifest::new(vec![text("hero")])).unwrap().replace(&format!("\"id\":{},", text("hero").get_id().0), "");
+    assert!(!json.contains("\"id\""));
+    let manifest = AssetManifest::try_from_json(&json).unwrap();
+    assert_eq!(manifest.get_asset_by_id(AssetId::from_name("hero")).unwrap().get_name(), "hero");
+    assert!(manifest.get_asset_by_id(AssetId::from_name("villain")).is_none());
+}
+
+#[test]
+fn constants_are_generated_for_rust_and_c() {
+    assert_eq!(asset_constant_name("tileset/terrain-1"), "ASSET_TILESET_TERRAIN_1");
+
+    let manifest = AssetManifest::new(vec![text("title"), text("tileset_terrain1")]);
+    let id = AssetId::from_name("tileset_terrain1");
+    let rust = generate_rust_ids(&manifest).unwrap();
+    assert!(rust.contains(&format!("pub const ASSET_TILESET_TERRAIN1:AssetId = AssetId({});", id)));
+    assert!(rust.find("ASSET_TILESET_TERRAIN1").unwrap() < rust.find("ASSET_TITLE").unwrap());
+    let c = generate_c_ids(&manifest).unwrap();
+    assert!(c.contains(&format!("#define ASSET_TILESET_TERRAIN1 ((uint32_t){}u)", id)));
+
+    let clashing = AssetManifest::new(vec![text("terrain-1"), text("terrain_1")]);
+    assert!(generate_c_ids(&clashing).unwrap_err().contains("ASSET_TERRAIN_1"));
+}
+
+#[test]
+fn shared_chunks_look_assets_up_by_id() {
+    let mut town = text("town");
+    town.set_chunk_location(5);
+    let chunk = SharedChunk::new(AssetManifest::new(vec![text("title"), town]), b"titletown".to_vec()).unwrap();
+    assert_eq!(chunk.get_asset_data_by_id(AssetId::from_name("town")), Some(&b"town"[..]));
+    assert!(chunk.get_asset_data_by_id(AssetId::INVALID).is_none());
+
+    let pointer:&SharedChunkData = &chunk;
+    let mut view = RawCAssetView { data: std::ptr::null(), size: 0, kind: RawCAssetKind::Image };
+    assert!(assetchunk_get_asset_by_id(pointer, AssetId::from_name("title").0, &mut view));
+    assert_eq!(unsafe { std::slice::from_raw_parts(view.data as *const u8, view.size) }, b"title");
+    assert!(!assetchunk_get_asset_by_id(pointer, AssetId::from_name("villain").0, &mut view));
+}
+
+#[test]
+fn c_chunks_look_assets_up_by_id() {
+    let mut town = text("town");
+    town.set_chunk_location(5);
+    let manifest = Box::into_raw(Box::new(RawCAssetManifest::from_manifest(&AssetManifest::new(vec![text("title"), town]))));
+    let data = Box::leak(b"titletown".to_vec().into_boxed_slice()).as_mut_ptr() as *mut std::ffi::c_void;
+    let chunk = Box::into_raw(Box::new(RawCAssetChunk { data, size: 9, manifest }));
+
+    let mut asset = MaybeUninit::<RawCAsset>::uninit();
+    assert!(get_asset_by_id(chunk, AssetId::from_name("town").0, asset.as_mut_ptr()));
+    let asset = unsafe { asset.assume_init() };
+    assert_eq!(unsafe { std::slice::from_raw_parts(asset.data as *const u8, asset.size) }, b"town");
+    let mut asset = MaybeUninit::<RawCAsset>::uninit();
+    assert!(!get_asset_by_id(chunk, AssetId::from_name("villain").0, asset.as_mut_ptr()));
+    free_asset_chunk(chunk);
+}
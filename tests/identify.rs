@@ -0,0 +1,117 @@
+mod common;
+
+use assetchunk::{get_image_info, identify, identify_by_content, Asset, AssetKind, AssetRegistry, AssetType, IdentificationSource, ImageInfo, RawCAsset, RawCImageInfo};
+use common::fixture;
+
+#[test]
+fn lua_script_is_identified_by_extension() {
+    let (path, data) = fixture("test/script1.lua");
+    let identification = identify(Some(&path), &data);
+    assert_eq!(identification.asset_type, AssetType::Script("LUA".to_string()));
+    assert_eq!(identification.source, IdentificationSource::Extension);
+}
+
+#[test]
+fn obj_mesh_is_identified_by_extension() {
+    let (path, data) = fixture("test/mesh.obj");
+    let identification = identify(Some(&path), &data);
+    assert_eq!(identification.asset_type, AssetType::Model("OBJ".to_string()));
+}
+
+#[test]
+fn lua_script_is_identified_by_content() {
+    let (path, data) = fixture("ctest/assets/event_script.lua");
+    assert_eq!(identify(None, &data).asset_type, AssetType::Script("LUA".to_string()));
+
+    let identification = identify(Some(&path), &data);
+    assert_eq!(identification.source, IdentificationSource::ExtensionAndContent);
+    assert_eq!(identification.confidence, 1.0);
+}
+
+#[test]
+fn png_falls_back_to_signature() {
+    let (_, data) = fixture("test/Tileset_Terrain1.png");
+    let identification = identify(None, &data);
+    assert_eq!(identification.asset_type, AssetType::Image("PNG".to_string()));
+    assert_eq!(identification.source, IdentificationSource::Signature);
+}
+
+#[test]
+fn text_heuristics() {
+    let glsl = b"// vertex shader\n#version 330 core\nvoid main() {}\n";
+    assert_eq!(identify_by_content(glsl).unwrap().0, AssetType::Shader("GLSL".to_string()));
+
+    let shebang = b"#!/usr/bin/env lua\nprint('hi')\n";
+    assert_eq!(identify_by_content(shebang).unwrap().0, AssetType::Script("LUA".to_string()));
+
+    let json = br#"{"name": "slime", "hp": 12}"#;
+    assert_eq!(identify_by_content(json).unwrap().0, AssetType::Other("JSON".to_string()));
+
+    let toml = b"[battle]\nmusic = \"boss\"\n";
+    assert_eq!(identify_by_content(toml).unwrap().0, AssetType::Other("TOML".to_string()));
+
+    let xml = b"<?xml version=\"1.0\"?><map></map>";
+    assert_eq!(identify_by_content(xml).unwrap().0, AssetType::Other("XML".to_string()));
+
+    let obj = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+    assert_eq!(identify_by_content(obj).unwrap().0, AssetType::Model("OBJ".to_string()));
+
+    assert!(identify_by_content(b"").is_none());
+}
+
+#[test]
+fn registered_kinds_are_identified_and_round_trip() {
+    let mut registry = AssetRegistry::new();
+    registry.register(AssetKind::new("Dialogue")
+        .with_extensions(&["dlg"])
+        .with_detector(|data| data.starts_with(b"DLG").then(|| "DLG".to_string()))
+        .with_validator(|data| if data.len() > 3 { Ok(()) } else { Err("Empty Dialogue".to_string()) }));
+
+    let identification = registry.identify(Some("intro.dlg"), b"DLG hello");
+    let dialogue = AssetType::Custom { kind: "Dialogue".to_string(), format: "DLG".to_string() };
+    assert_eq!(identification.asset_type, dialogue);
+    assert!(registry.validate(&dialogue, b"DLG").is_err());
+
+    // Unregistered Data Still Goes Through The Built-In Detector
+    assert_eq!(registry.identify(Some("boot.lua"), b"").asset_type, AssetType::Script("LUA".to_string()));
+
+    let json = serde_json::to_string(&dialogue).unwrap();
+    assert_eq!(serde_json::from_str::<AssetType>(&json).unwrap(), dialogue);
+    assert_eq!("Dialogue:dlg".parse::<AssetType>().unwrap(), dialogue);
+}
+
+#[test]
+fn image_headers_are_recorded_as_metadata() {
+    let (name, data) = fixture("test/Tileset_Terrain1.png");
+    let mut assets = vec![Asset::from_data(&name, AssetType::Other("UNIDENTIFIED".to_string()), data)];
+    AssetRegistry::new().identify_assets(&mut assets);
+    let info = assets[0].get_image_info().unwrap();
+    assert_eq!(assets[0].get_metadata().get("width"), Some(&info.width.to_string()));
+    assert_eq!(info.frame_count, 1);
+
+    let raw_asset = RawCAsset::from_asset(&assets[0]);
+    let mut raw_info = RawCImageInfo { width: 0, height: 0, bit_depth: 0, color_type: std::ptr::null(), frame_count: 0 };
+    assert!(get_image_info(&raw_asset, &mut raw_info));
+    assert_eq!((raw_info.width, raw_info.height, raw_info.frame_count), (info.width, info.height, 1));
+    assert!(!get_image_info(&RawCAsset::from_asset(&Asset::from_data("notes", AssetType::Other("TXT".to_string()), Vec::new())), &mut raw_info));
+
+    // 2x3 Bottom Up 24 Bit BMP
+    let mut bmp = b"BM".to_vec();
+    bmp.extend_from_slice(&[0; 12]);
+    bmp.extend_from_slice(&40u32.to_le_bytes());
+    bmp.extend_from_slice(&2i32.to_le_bytes());
+    bmp.extend_from_slice(&(-3i32).to_le_bytes());
+    bmp.extend_from_slice(&1u16.to_le_bytes());
+    bmp.extend_from_slice(&24u16.to_le_bytes());
+    assert_eq!(ImageInfo::from_data(&bmp), Some(ImageInfo { width: 2, height: 3, bit_depth: 24, color_type: "rgb".to_string(), frame_count: 1 }));
+
+    // 1x1 GIF With A 2 Color Global Table And Two Frames
+    let mut gif = b"GIF89a\x01\x00\x01\x00\x80\x00\x00".to_vec();
+    gif.extend_from_slice(&[0, 0, 0, 255, 255, 255]);
+    for _ in 0..2 {
+        gif.extend_from_slice(&[0x21, 0xF9, 4, 0, 10, 0, 0, 0]);
+        gif.extend_from_slice(&[0x2C, 0, 0, 0, 0, 1, 0, 1, 0, 0, 2, 2, 0x44, 0x01, 0]);
+    }
+    gif.push(0x3B);
+    assert_eq!(ImageInfo::from_data(&gif), Some(ImageInfo { width: 1, height: 1, bit_depth: 1, color_type: "indexed".to_string(), frame_count: 2 }));
+}
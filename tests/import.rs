@@ -0,0 +1,122 @@
+mod common;
+
+use assetchunk::{encode_png_rgba, Asset, AssetType, ImportPipeline, PackConfig, RgbaImage};
+use common::{fixture_asset, temp_directory};
+
+#[test]
+fn lua_line_endings_are_normalized() {
+    let mut asset = Asset::from_data("event_script", AssetType::Script("LUA".to_string()), b"\xef\xbb\xbfprint('a')\r\nprint('b')\rprint('c')\n".to_vec());
+    ImportPipeline::with_defaults().run(&mut asset, &PackConfig::default()).unwrap();
+    assert_eq!(asset.get_data().as_deref().unwrap(), b"print('a')\nprint('b')\nprint('c')\n");
+    assert_eq!(asset.get_size(), 33);
+    assert_eq!(asset.get_import_record().as_ref().unwrap().importers[0].name, "line_endings");
+}
+
+#[test]
+fn png_keeps_critical_chunks_only() {
+    let mut asset = fixture_asset("test/Tileset_Terrain1.png", AssetType::Image("PNG".to_string()));
+    let original = asset.get_data().clone().unwrap();
+    ImportPipeline::with_defaults().run(&mut asset, &PackConfig::default()).unwrap();
+    let stripped = asset.get_data().as_deref().unwrap();
+    assert!(stripped.len() <= original.len());
+    assert!(stripped.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert!(stripped.ends_with(b"IEND\xae\x42\x60\x82"));
+}
+
+#[test]
+fn wav_is_rewritten_to_canonical_pcm() {
+    // WAVE_FORMAT_EXTENSIBLE Header With A LIST Chunk Before The Samples
+    let mut wav = b"RIFF\0\0\0\0WAVE".to_vec();
+    wav.extend_from_slice(b"fmt \x28\0\0\0");
+    wav.extend_from_slice(&0xFFFEu16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&44100u32.to_le_bytes());
+    wav.extend_from_slice(&88200u32.to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(&22u16.to_le_bytes());
+    wav.extend_from_slice(&[16, 0, 4, 0, 0, 0]);
+    wav.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0x10, 0, 0x80, 0, 0, 0xaa, 0, 0x38, 0x9b, 0x71]);
+    wav.extend_from_slice(b"LIST\x04\0\0\0INFO");
+    wav.extend_from_slice(b"data\x04\0\0\0\x01\x02\x03\x04");
+
+    let mut asset = Asset::from_data("jump", AssetType::Audio("WAV".to_string()), wav);
+    ImportPipeline::with_defaults().run(&mut asset, &PackConfig::default()).unwrap();
+    let canonical = asset.get_data().as_deref().unwrap();
+    assert_eq!(canonical.len(), 48);
+    assert_eq!(&canonical[20..22], &1u16.to_le_bytes());
+    assert_eq!(&canonical[36..40], b"data");
+    assert_eq!(&canonical[44..], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn wav_that_is_not_pcm_is_left_as_is() {
+    // IEEE Float Samples, Then A Streamed File Whose data Chunk Length Was Never Filled In
+    let mut float_wav = b"RIFF\x28\0\0\0WAVEfmt \x10\0\0\0".to_vec();
+    float_wav.extend_from_slice(&3u16.to_le_bytes());
+    float_wav.extend_from_slice(&1u16.to_le_bytes());
+    float_wav.extend_from_slice(&44100u32.to_le_bytes());
+    float_wav.extend_from_slice(&176400u32.to_le_bytes());
+    float_wav.extend_from_slice(&4u16.to_le_bytes());
+    float_wav.extend_from_slice(&32u16.to_le_bytes());
+    let mut streamed_wav = float_wav.clone();
+    streamed_wav[20..22].copy_from_slice(&1u16.to_le_bytes());
+    float_wav.extend_from_slice(b"data\x04\0\0\0\0\0\x80\x3f");
+    streamed_wav.extend_from_slice(b"data\xff\xff\xff\xff\x01\x02\x03\x04");
+
+    for wav in [float_wav, streamed_wav] {
+        let mut asset = Asset::from_data("jump", AssetType::Audio("WAV".to_string()), wav.clone());
+        ImportPipeline::with_defaults().run(&mut asset, &PackConfig::default()).unwrap();
+        assert_eq!(asset.get_data().as_deref().unwrap(), wav);
+    }
+}
+
+#[test]
+fn disabled_importers_do_not_run() {
+    let config = PackConfig::from_toml("[importers.line_endings]\nenabled = false\n").unwrap();
+    let asset = fixture_asset("ctest/assets/event_script.lua", AssetType::Script("LUA".to_string()));
+    assert!(ImportPipeline::with_defaults().plan(&asset, &config).is_none());
+}
+
+#[test]
+fn raw_texture_is_opt_in_and_only_indexes_when_asked() {
+    let mut image = RgbaImage::new(4, 2);
+    image.pixels[0..4].copy_from_slice(&[255, 0, 0, 255]);
+    let png = encode_png_rgba(&image).unwrap();
+
+    let mut asset = Asset::from_data("tile", AssetType::Image("PNG".to_string()), png.clone());
+    ImportPipeline::with_defaults().run(&mut asset, &PackConfig::default()).unwrap();
+    assert_eq!(*asset.get_type(), AssetType::Image("PNG".to_string()));
+
+    let config = PackConfig::from_toml("[importers.raw_texture]\nenabled = true\nformat = \"indexed\"\n").unwrap();
+    let mut asset = Asset::from_data("tile", AssetType::Image("PNG".to_string()), png.clone());
+    ImportPipeline::with_defaults().run(&mut asset, &config).unwrap();
+    assert_eq!(*asset.get_type(), AssetType::Image("INDEXED8".to_string()));
+    assert_eq!(asset.get_metadata().get("palette_size").map(String::as_str), Some("2"));
+    assert_eq!(asset.get_data().as_deref().unwrap(), &[255, 0, 0, 255, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1]);
+
+    let config = PackConfig::from_toml("[importers.raw_texture]\nenabled = true\n").unwrap(); // RGBA8 Unless Told Otherwise
+    let mut asset = Asset::from_data("tile", AssetType::Image("PNG".to_string()), png);
+    ImportPipeline::with_defaults().run(&mut asset, &config).unwrap();
+    assert_eq!(*asset.get_type(), AssetType::Image("RGBA8".to_string()));
+    assert_eq!(asset.get_metadata().get("width").map(String::as_str), Some("4"));
+    assert_eq!(asset.get_metadata().get("pixel_format").map(String::as_str), Some("RGBA8"));
+    assert_eq!(asset.get_data().as_deref().unwrap(), &image.pixels[..]);
+}
+
+#[test]
+#[cfg(unix)] // Symlinks Make A Neighbour That Can't Be Read
+fn imported_files_use_the_config_kinds_and_skip_unreadable_neighbours() {
+    let directory = temp_directory("import_file");
+    std::fs::write(directory.join("intro.dlg"), b"Hello There").unwrap();
+    std::fs::write(directory.join("event_script.lua"), b"print('a')\n").unwrap();
+    std::os::unix::fs::symlink(directory.join("missing.png"), directory.join("broken.png")).unwrap();
+    let config = PackConfig::from_toml("[kinds]\nDialogue = [\"dlg\"]\n").unwrap();
+    let pipeline = ImportPipeline::with_defaults();
+
+    let dialogue = pipeline.import_file(&directory.join("intro.dlg"), &directory, &config).unwrap();
+    assert_eq!(dialogue.get_type().kind_name(), "Dialogue");
+    let script = pipeline.import_file(&directory.join("event_script.lua"), &directory, &config).unwrap();
+    assert_eq!(*script.get_type(), AssetType::Script("LUA".to_string()));
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use assetchunk::{create_asset_loader, destroy_asset_loader, free_asset_chunk, get_load_status, load_asset_chunk_async, load_asset_group_async, release_load_ticket, take_loaded_chunk, wait_for_load, Asset, AssetGroup, AssetLoader, AssetManifest, AssetType, LoadStatus, RawCAssetChunk, RawCLoadStatus};
+use std::ffi::{c_void, CString};
+use std::future::Future;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use common::temp_directory;
+
+/**
+ * Write A Chunk Of Two Text Assets, `town` In Its Own Group, Returning The Chunk And Manifest Paths
+ */
+fn write_chunk(directory:&std::path::Path) -> (String, String) {
+    let title = Asset::from_data("title", AssetType::Other("TXT".to_string()), b"title".to_vec());
+    let mut town = Asset::from_data("town", AssetType::Other("TXT".to_string()), b"town".to_vec());
+    town.set_chunk_location(5);
+    let mut manifest = AssetManifest::new(vec![title, town]);
+    manifest.set_groups(vec![AssetGroup { name: "town".to_string(), offset: 5, size: 4, assets: vec!["town".to_string()] }]);
+
+    let (chunk_path, manifest_path) = (directory.join("game.chunk.asset"), directory.join("game.manifest.asset"));
+    std::fs::write(&chunk_path, b"titletown").unwrap();
+    std::fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+    (chunk_path.to_str().unwrap().to_string(), manifest_path.to_str().unwrap().to_string())
+}
+
+/**
+ * Run A Future To Completion On This Thread
+ */
+fn block_on<F:Future>(future:F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self:Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[test]
+fn chunks_load_in_the_background() {
+    let directory = temp_directory("loader");
+    let (chunk_path, manifest_path) = write_chunk(&directory);
+    let loader = AssetLoader::new(2);
+
+    let ticket = loader.load_chunk(&chunk_path, &manifest_path);
+    let loaded = ticket.wait().unwrap();
+    assert_eq!(ticket.status(), LoadStatus::Loaded);
+    assert_eq!(loaded.get_asset_data("town"), Some(&b"town"[..]));
+    assert!(ticket.try_take().is_none()); // Already Taken
+
+    let loaded = block_on(loader.load_group(&chunk_path, &manifest_path, "town")).unwrap();
+    assert_eq!(loaded.data, b"town");
+    assert_eq!(loaded.get_asset_data("town"), Some(&b"town"[..]));
+
+    let (sender, receiver) = channel();
+    loader.load_chunk(&chunk_path, &directory.join("missing.manifest.asset").to_string_lossy()).on_complete(move |result| sender.send(result.is_err()).unwrap());
+    assert!(receiver.recv().unwrap());
+    let ticket = loader.load_group(&chunk_path, &manifest_path, "castle");
+    assert!(ticket.wait().is_err());
+    assert_eq!(ticket.status(), LoadStatus::Failed);
+
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[test]
+fn panicking_loads_fail_their_ticket() {
+    let directory = temp_directory("loader_panic");
+    let (chunk_path, manifest_path) = write_chunk(&directory);
+    let mut manifest:AssetManifest = serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+    manifest.set_groups(vec![AssetGroup { name: "huge".to_string(), offset: 0, size: usize::MAX, assets: Vec::new() }]);
+    std::fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+    let loader = AssetLoader::new(1);
+
+    let ticket = loader.load_group(&chunk_path, &manifest_path, "huge"); // Panics Allocating The Group
+    assert!(ticket.wait().unwrap_err().starts_with("Load Panicked: "));
+    assert_eq!(ticket.status(), LoadStatus::Failed);
+
+    let (release, released) = channel::<()>();
+    let (started, has_started) = channel::<()>();
+    loader.load_chunk(&chunk_path, &manifest_path).on_complete(move |_| {
+        started.send(()).unwrap();
+        released.recv().unwrap(); // Holds The Only Worker Until The Next Callback Is Registered
+    });
+    has_started.recv().unwrap();
+    loader.load_chunk(&chunk_path, &manifest_path).on_complete(|_| panic!("Callback Panicked"));
+    release.send(()).unwrap();
+    assert!(loader.load_chunk(&chunk_path, &manifest_path).wait().is_ok()); // The Only Worker Is Still Running
+
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[test]
+fn callbacks_run_on_the_worker_even_when_the_load_fails_straight_away() {
+    let directory = temp_directory("loader_callback_thread");
+    let (chunk_path, _) = write_chunk(&directory);
+    let missing = directory.join("missing.manifest.asset").to_string_lossy().to_string();
+    let loader = AssetLoader::new(2);
+
+    let (sender, receiver) = channel();
+    for _ in 0..50 {
+        let sender = sender.clone();
+        loader.load_chunk_with_callback(&chunk_path, &missing, Box::new(move |result| sender.send((result.is_err(), std::thread::current().id())).unwrap()));
+    }
+    for _ in 0..50 {
+        let (failed, thread) = receiver.recv().unwrap();
+        assert!(failed);
+        assert_ne!(thread, std::thread::current().id());
+    }
+
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+extern "C" fn loaded(chunk:*mut RawCAssetChunk, user_data:*mut c_void) {
+    let sender = unsafe { &*(user_data as *const Sender<usize>) };
+    sender.send(if chunk.is_null() { 0 } else { unsafe { (*chunk).size } }).unwrap();
+    free_asset_chunk(chunk);
+}
+
+#[test]
+fn c_api_loads_with_a_callback_or_by_polling() {
+    let directory = temp_directory("loader_c");
+    let (chunk_path, manifest_path) = write_chunk(&directory);
+    let (chunk_path, manifest_path) = (CString::new(chunk_path).unwrap(), CString::new(manifest_path).unwrap());
+    let loader = create_asset_loader(0);
+
+    let (sender, receiver) = channel::<usize>();
+    let group = CString::new("town").unwrap();
+    let ticket = load_asset_group_async(loader, chunk_path.as_ptr(), manifest_path.as_ptr(), group.as_ptr(), Some(loaded), &sender as *const Sender<usize> as *mut c_void);
+    assert_eq!(receiver.recv().unwrap(), 4);
+    assert_eq!(get_load_status(ticket), RawCLoadStatus::Loaded);
+    assert!(take_loaded_chunk(ticket).is_null()); // The Callback Got It
+    release_load_ticket(ticket);
+
+    let ticket = load_asset_chunk_async(loader, chunk_path.as_ptr(), manifest_path.as_ptr(), None, std::ptr::null_mut());
+    let chunk = wait_for_load(ticket);
+    assert!(!chunk.is_null());
+    assert_eq!(unsafe { (*chunk).size }, 9);
+    assert_eq!(unsafe { (*(*chunk).manifest).asset_count }, 2);
+    assert!(take_loaded_chunk(ticket).is_null());
+    release_load_ticket(ticket);
+    free_asset_chunk(chunk);
+    free_asset_chunk(std::ptr::null_mut());
+
+    destroy_asset_loader(loader);
+    let _ = std::fs::remove_dir_all(directory);
+}
@@ -0,0 +1,64 @@
+use assetchunk::{free_asset_manifest, free_mesh, get_mesh, import_meshes, Asset, AssetManifest, AssetType, Mesh, RawCAssetChunk, RawCAssetManifest};
+use std::ffi::CString;
+
+const QUAD:&str = "# Two Materials On A Quad Split Into A Triangle And A Polygon
+mtllib ../materials/props.mtl
+o quad
+v 0 0 0
+v 2 0 0
+v 2 1 0
+v 0 1 -1
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+vn 0 0 1
+usemtl wood
+f 1/1/1 2/2/1 3/3/1
+usemtl stone
+f -4/-4/-1 -2/-2/-1 -1/-1/-1
+";
+
+#[test]
+fn obj_is_indexed_and_interleaved() {
+    let mesh = Mesh::parse_obj(QUAD).unwrap();
+    assert_eq!(mesh.layout, Mesh::LAYOUT_POSITION | Mesh::LAYOUT_NORMAL | Mesh::LAYOUT_UV);
+    assert_eq!(mesh.vertex_stride(), 8);
+    assert_eq!(mesh.vertex_count(), 4); // Corners Shared Between The Triangles Are Reused
+    assert_eq!(mesh.indices, [0, 1, 2, 0, 2, 3]);
+    assert_eq!(&mesh.vertices[8..16], &[2.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0]);
+    assert_eq!((mesh.bounds_min, mesh.bounds_max), ([0.0, 0.0, -1.0], [2.0, 1.0, 0.0]));
+    assert_eq!(mesh.material_libraries, ["props"]);
+    assert_eq!(mesh.submeshes.iter().map(|submesh| (submesh.material.as_str(), submesh.index_start, submesh.index_count)).collect::<Vec<_>>(),
+        [("wood", 0, 3), ("stone", 3, 3)]);
+    assert_eq!(Mesh::from_bytes(&mesh.to_bytes()).unwrap(), mesh);
+}
+
+#[test]
+fn polygons_without_normals_are_fanned() {
+    let mesh = Mesh::parse_obj("v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nv -1 0 0\nf 1 2 3 4 5\n").unwrap();
+    assert_eq!(mesh.layout, Mesh::LAYOUT_POSITION);
+    assert_eq!(mesh.indices, [0, 1, 2, 0, 2, 3, 0, 3, 4]);
+    assert!(Mesh::parse_obj("v 0 0 0\nf 1 2 3\n").unwrap_err().contains("Line 2"));
+}
+
+#[test]
+fn material_libraries_must_be_packed() {
+    let obj = || Asset::from_data("quad", AssetType::Model("OBJ".to_string()), QUAD.as_bytes().to_vec());
+    let mut assets = vec![obj()];
+    assert!(import_meshes(&mut assets).unwrap_err().contains("props"));
+
+    let mut assets = vec![obj(), Asset::from_data("props", AssetType::Other("MTL".to_string()), b"newmtl wood\n".to_vec())];
+    assert_eq!(import_meshes(&mut assets).unwrap(), 1);
+    assert_eq!(*assets[0].get_type(), AssetType::Model("MESH".to_string()));
+    assert_eq!(assets[0].get_metadata().get("vertex_count").map(String::as_str), Some("4"));
+    assert_eq!(Mesh::from_bytes(assets[0].get_data().as_deref().unwrap()).unwrap().indices.len(), 6);
+
+    let mut data = assets[0].get_data().clone().unwrap();
+    let chunk = RawCAssetChunk { data: data.as_mut_ptr() as *mut _, size: data.len(), manifest: Box::into_raw(Box::new(RawCAssetManifest::from_manifest(&AssetManifest::new(vec![assets[0].clone()])))) };
+    let name = CString::new("quad").unwrap();
+    let mesh = get_mesh(&chunk, name.as_ptr());
+    assert_eq!(unsafe { ((*mesh).vertex_count, (*mesh).index_count, (*mesh).submesh_count) }, (4, 6, 2));
+    free_mesh(mesh);
+    free_asset_manifest(chunk.manifest);
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use assetchunk::{connect_asset_server, disconnect_asset_server, fetch_asset, free_fetched_asset, Asset, AssetClient, AssetManifest, AssetServer, AssetSource, AssetType, PackConfig};
+use std::ffi::{CStr, CString};
+use common::temp_directory;
+
+/**
+ * Start A Server On A Free Localhost Port, Returning Its Address
+ */
+fn serve(source:AssetSource) -> String {
+    let server = AssetServer::bind("127.0.0.1:0", source).unwrap();
+    let address = server.local_addr().unwrap().to_string();
+    std::thread::spawn(move || server.run());
+    address
+}
+
+#[test]
+fn client_fetches_assets_from_a_packed_chunk() {
+    let directory = temp_directory("net_chunk");
+    let mut hero = Asset::from_data("hero", AssetType::Other("TXT".to_string()), b"hero".to_vec());
+    hero.set_chunk_location(5);
+    hero.set_metadata("author", "sam");
+    let title = Asset::from_data("title", AssetType::Other("TXT".to_string()), b"title".to_vec());
+    std::fs::write(directory.join("game.chunk.asset"), b"titlehero").unwrap();
+    std::fs::write(directory.join("game.manifest.asset"), serde_json::to_string(&AssetManifest::new(vec![title, hero])).unwrap()).unwrap();
+
+    let source = AssetSource::chunk(directory.join("game.chunk.asset").to_str().unwrap(), directory.join("game.manifest.asset").to_str().unwrap()).unwrap();
+    let address = serve(source);
+
+    let mut client = AssetClient::connect(&address).unwrap();
+    let hero = client.get_asset("hero").unwrap().unwrap();
+    assert_eq!(hero.get_type(), &AssetType::Other("TXT".to_string()));
+    assert_eq!(hero.get_metadata().get("author").map(String::as_str), Some("sam"));
+    assert_eq!(hero.get_data().as_deref(), Some(&b"hero"[..]));
+    assert!(client.get_asset("villain").unwrap().is_none());
+    assert_eq!(client.list_assets().unwrap(), ["title", "hero"]);
+
+    // Through The C API
+    let address = CString::new(address).unwrap();
+    let client = connect_asset_server(address.as_ptr());
+    assert!(!client.is_null());
+    let name = CString::new("title").unwrap();
+    let fetched = fetch_asset(client, name.as_ptr());
+    assert!(!fetched.is_null());
+    let asset = unsafe { &*fetched };
+    assert_eq!(unsafe { CStr::from_ptr(asset.name) }.to_str().unwrap(), "title");
+    assert_eq!(unsafe { std::slice::from_raw_parts(asset.data as *const u8, asset.size) }, b"title");
+    free_fetched_asset(fetched);
+    let name = CString::new("villain").unwrap();
+    assert!(fetch_asset(client, name.as_ptr()).is_null());
+    disconnect_asset_server(client);
+
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[test]
+fn directory_source_serves_edits_immediately() {
+    let directory = temp_directory("net_directory");
+    std::fs::write(directory.join("intro.lua"), "print('hi')\r\n").unwrap();
+
+    let address = serve(AssetSource::directory(&directory, PackConfig::default()).unwrap());
+    let mut client = AssetClient::connect(&address).unwrap();
+    let intro = client.get_asset("intro").unwrap().unwrap();
+    assert_eq!(intro.get_data().as_deref(), Some(&b"print('hi')\n"[..])); // Imported Like The Packer Does
+
+    std::fs::write(directory.join("intro.lua"), "print('hello')\n").unwrap();
+    let intro = client.get_asset("intro").unwrap().unwrap();
+    assert_eq!(intro.get_data().as_deref(), Some(&b"print('hello')\n"[..]));
+    assert_eq!(client.list_assets().unwrap(), ["intro"]);
+
+    // Converted Like The Packer Does Too
+    std::fs::write(directory.join("crate.obj"), "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+    let mesh = client.get_asset("crate").unwrap().unwrap();
+    assert_eq!(mesh.get_type().format(), "MESH");
+    assert_eq!(mesh.get_metadata().get("vertex_count").map(String::as_str), Some("3"));
+
+    let _ = std::fs::remove_dir_all(directory);
+}
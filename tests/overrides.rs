@@ -0,0 +1,67 @@
+#![cfg(feature = "overrides")]
+
+mod common;
+
+use assetchunk::{get_asset, get_overridden_assets, load_asset_chunk, set_asset_override_dir, Asset, AssetManifest, AssetType};
+use std::ffi::{CStr, CString};
+use common::{fixture, temp_directory};
+
+#[test]
+fn loose_files_override_packed_assets() {
+    let directory = temp_directory("overrides");
+    let mods = directory.join("mods");
+    std::fs::create_dir_all(&mods).unwrap();
+    let chunk_path = directory.join("game.chunk.asset");
+    let manifest_path = directory.join("game.manifest.asset");
+
+    let mut hero = Asset::from_data("hero", AssetType::Image("PNG".to_string()), b"packed hero".to_vec());
+    let mut title = Asset::from_data("title", AssetType::Image("PNG".to_string()), b"packed title".to_vec());
+    assert_eq!(hero.get_virtual_path(), "hero.png");
+    let mut tiles = Asset::from_data("tiles", AssetType::Image("RGBA8".to_string()), b"packed tiles".to_vec());
+    tiles.set_source_extension(Some(".png".to_string())); // Converted From A PNG When Packed
+    tiles.set_metadata("pixel_format", "RGBA8");
+    assert_eq!(tiles.get_virtual_path(), "tiles.png");
+    hero.set_chunk_location(0);
+    title.set_chunk_location(11);
+    tiles.set_chunk_location(23);
+    std::fs::write(&chunk_path, b"packed heropacked titlepacked tiles").unwrap();
+    AssetManifest::new(vec![hero, title, tiles]).write(manifest_path.to_str().unwrap());
+    std::fs::write(mods.join("hero.png"), b"modded hero").unwrap();
+    let (_, png) = fixture("test/Tileset_Water_Frame1.png");
+    std::fs::write(mods.join("tiles.png"), &png).unwrap();
+
+    let chunk_path = CString::new(chunk_path.to_str().unwrap()).unwrap();
+    let manifest_path = CString::new(manifest_path.to_str().unwrap()).unwrap();
+    let chunk = load_asset_chunk(chunk_path.as_ptr(), manifest_path.as_ptr());
+    let read = |name:&str| {
+        let name = CString::new(name).unwrap();
+        let asset = unsafe { &*get_asset(chunk, name.as_ptr()) };
+        unsafe { std::slice::from_raw_parts(asset.data as *const u8, asset.size).to_vec() }
+    };
+
+    assert_eq!(read("hero"), b"packed hero");
+    let mods_path = CString::new(mods.to_str().unwrap()).unwrap();
+    set_asset_override_dir(mods_path.as_ptr());
+    assert_eq!(read("hero"), b"modded hero");
+    std::fs::write(mods.join("hero.png"), b"edited hero").unwrap();
+    assert_eq!(read("hero"), b"modded hero"); // Read Once And Cached
+    assert_eq!(read("title"), b"packed title");
+    std::fs::write(mods.join("title.png"), b"modded title").unwrap();
+    assert_eq!(read("title"), b"packed title"); // Missing Files Are Cached Too
+
+    // The Override Reports Its Own Type And Metadata, Not The Packed RGBA8 Ones
+    assert_eq!(read("tiles"), png);
+    let name = CString::new("tiles").unwrap();
+    let tiles = unsafe { &*get_asset(chunk, name.as_ptr()) };
+    assert_eq!(unsafe { CStr::from_ptr(tiles.format) }.to_str().unwrap(), "PNG");
+    assert_eq!(unsafe { CStr::from_ptr(tiles.extension) }.to_str().unwrap(), ".png");
+    let metadata = unsafe { std::slice::from_raw_parts(tiles.metadata, tiles.metadata_count) };
+    let keys:Vec<&str> = metadata.iter().map(|entry| unsafe { CStr::from_ptr(entry.key) }.to_str().unwrap()).collect();
+    assert!(keys.contains(&"width"));
+    assert!(!keys.contains(&"pixel_format"));
+    assert_eq!(get_overridden_assets(), ["hero.png", "tiles.png"]);
+
+    set_asset_override_dir(std::ptr::null());
+    assert_eq!(read("hero"), b"packed hero");
+    std::fs::remove_dir_all(&directory).unwrap();
+}
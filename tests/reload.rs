@@ -0,0 +1,84 @@
+#![cfg(feature = "hot-reload")]
+
+mod common;
+
+use assetchunk::{create_hot_reloader, destroy_hot_reloader, hot_reload_poll, HotReloader, PackConfig};
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use common::temp_directory;
+
+#[test]
+fn changed_files_are_reimported() {
+    let directory = temp_directory("reload");
+    std::fs::write(directory.join("intro.lua"), "print('hi')\n").unwrap();
+
+    let mut reloader = HotReloader::new(&directory, PackConfig::default()).unwrap();
+    let reloaded = Arc::new(Mutex::new(Vec::new()));
+    let seen = reloaded.clone();
+    reloader.on_reload(move |asset| seen.lock().unwrap().push((asset.get_name().to_string(), asset.get_data().clone().unwrap())));
+    assert!(reloader.poll().is_empty());
+
+    std::fs::write(directory.join("intro.lua"), "print('hello')\r\n").unwrap();
+    std::fs::create_dir_all(directory.join("town")).unwrap();
+    std::fs::write(directory.join("town").join("shop.lua"), "return {}").unwrap();
+    assert_eq!(reloader.poll(), ["intro", "shop"]);
+    assert_eq!(reloaded.lock().unwrap()[0], ("intro".to_string(), b"print('hello')\n".to_vec())); // Imported Like The Packer Does
+    assert!(reloader.poll().is_empty());
+
+    // Broken Files Are Reported And Skipped
+    std::fs::write(directory.join("bad.png"), b"not a png").unwrap();
+    let watch = reloader.watch(Duration::from_millis(5));
+    std::thread::sleep(Duration::from_millis(50));
+    watch.stop();
+    assert_eq!(reloaded.lock().unwrap().len(), 2);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn reloads_are_converted_like_packs() {
+    let directory = temp_directory("reload_convert");
+    let mut reloader = HotReloader::new(&directory, PackConfig::default()).unwrap();
+    let reloaded = Arc::new(Mutex::new(Vec::new()));
+    let seen = reloaded.clone();
+    reloader.on_reload(move |asset| seen.lock().unwrap().push((asset.get_name().to_string(), asset.get_type().format().to_string(), asset.get_dependencies().clone())));
+
+    std::fs::write(directory.join("crate.obj"), "mtllib crate_materials.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+    std::fs::write(directory.join("crate_materials.mtl"), "newmtl wood\n").unwrap();
+    std::fs::write(directory.join("ui.lua"), "require('widgets')\n").unwrap();
+    std::fs::write(directory.join("widgets.lua"), "return {}\n").unwrap();
+    reloader.poll();
+    let reloaded_crate = reloaded.lock().unwrap().iter().find(|(name, ..)| name == "crate").cloned().unwrap();
+    assert_eq!(reloaded_crate.1, "MESH");
+    assert_eq!(reloaded.lock().unwrap().iter().find(|(name, ..)| name == "ui").unwrap().2, ["widgets"]);
+
+    // Scripts That Don't Compile Aren't Reloaded
+    std::fs::write(directory.join("ui.lua"), "if then\n").unwrap();
+    assert!(reloader.poll().is_empty());
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+extern "C" fn record(name:*const c_char, data:*const c_void, size:usize, user_data:*mut c_void) {
+    let reloads = unsafe { &mut *(user_data as *mut Vec<(String, Vec<u8>)>) };
+    let data = unsafe { std::slice::from_raw_parts(data as *const u8, size) };
+    reloads.push((unsafe { CStr::from_ptr(name) }.to_str().unwrap().to_string(), data.to_vec()));
+}
+
+#[test]
+fn c_callback_gets_the_new_data() {
+    let directory = temp_directory("c_reload");
+    let mut reloads:Vec<(String, Vec<u8>)> = Vec::new();
+    let path = CString::new(directory.to_str().unwrap()).unwrap();
+    let reloader = create_hot_reloader(path.as_ptr(), std::ptr::null(), record, &mut reloads as *mut _ as *mut c_void);
+    assert!(!reloader.is_null());
+
+    std::fs::write(directory.join("credits.glsl"), "void main() {}").unwrap();
+    assert_eq!(hot_reload_poll(reloader), 1);
+    assert_eq!(reloads, [("credits".to_string(), b"void main() {}".to_vec())]);
+    destroy_hot_reloader(reloader);
+
+    let missing = CString::new(directory.join("missing").to_str().unwrap()).unwrap();
+    assert!(create_hot_reloader(missing.as_ptr(), std::ptr::null(), record, std::ptr::null_mut()).is_null());
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use assetchunk::{create_residency_manager, destroy_residency_manager, group_ranges, residency_get_asset, residency_pin, residency_resident_bytes, residency_set_budget, residency_unpin, Asset, AssetManifest, AssetType, RawCAssetKind, RawCAssetView, ResidencyManager};
+use std::ffi::CString;
+use common::temp_directory;
+
+/**
+ * Write A Chunk Of An Ungrouped `title` (5 Bytes) Then Groups `town` (4), `cave` (4) And `sea` (3),
+ * Returning The Chunk And Manifest Paths
+ */
+fn write_chunk(directory:&std::path::Path) -> (String, String) {
+    let mut data = Vec::new();
+    let assets:Vec<Asset> = [("title", None), ("town", Some("town")), ("cave", Some("cave")), ("sea", Some("sea"))].iter().map(|(name, group)| {
+        let mut asset = Asset::from_data(name, AssetType::Other("TXT".to_string()), name.as_bytes().to_vec());
+        asset.set_chunk_location(data.len());
+        asset.set_group(group.map(str::to_string));
+        data.extend(name.as_bytes());
+        asset
+    }).collect();
+    let mut manifest = AssetManifest::new(assets.clone());
+    manifest.set_groups(group_ranges(&assets).unwrap());
+
+    let (chunk_path, manifest_path) = (directory.join("game.chunk.asset"), directory.join("game.manifest.asset"));
+    std::fs::write(&chunk_path, data).unwrap();
+    std::fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+    (chunk_path.to_str().unwrap().to_string(), manifest_path.to_str().unwrap().to_string())
+}
+
+#[test]
+fn least_recently_used_groups_are_evicted_within_the_budget() {
+    let directory = temp_directory("residency");
+    let (chunk_path, manifest_path) = write_chunk(&directory);
+    let mut residency = ResidencyManager::new(&chunk_path, &manifest_path, 10).unwrap();
+
+    assert_eq!(residency.get_asset_data("title").unwrap(), b"title");
+    assert_eq!(residency.get_asset_data("town").unwrap(), b"town");
+    assert_eq!(residency.resident_bytes(), 9);
+    assert_eq!(residency.get_asset_data("cave").unwrap(), b"cave"); // Evicts `title`
+    assert_eq!(residency.resident_groups(), ["cave", "town"]);
+    assert!(!residency.is_resident("title"));
+
+    residency.get_asset_data("town").unwrap(); // `cave` Is Now The Least Recently Used
+    residency.pin("cave").unwrap();
+    residency.load_group("sea").unwrap(); // Evicts `town`, `cave` Is Pinned
+    assert_eq!(residency.resident_groups(), ["cave", "sea"]);
+
+    residency.set_budget(3); // Only The Pinned `cave` Is Left, Over Budget
+    assert_eq!(residency.resident_groups(), ["cave"]);
+    assert!(residency.get_asset_data("title").is_err());
+    assert!(!residency.is_resident("title"));
+
+    assert!(residency.unpin("cave"));
+    assert!(!residency.unpin("cave"));
+    residency.set_budget(10);
+    let town = residency.get_chunk("town").unwrap();
+    residency.set_budget(0);
+    assert_eq!(residency.resident_bytes(), 0);
+    assert_eq!(town.get_asset_data("town"), Some(&b"town"[..])); // The Handle Outlives The Eviction
+
+    assert!(residency.get_asset_data("castle").is_err());
+    assert!(residency.pin_group("castle").is_err());
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[test]
+fn c_api_pins_and_looks_up_assets() {
+    let directory = temp_directory("residency_c");
+    let (chunk_path, manifest_path) = write_chunk(&directory);
+    let (chunk_path, manifest_path) = (CString::new(chunk_path).unwrap(), CString::new(manifest_path).unwrap());
+    let manager = create_residency_manager(chunk_path.as_ptr(), manifest_path.as_ptr(), 8);
+    assert!(!manager.is_null());
+
+    let (town, cave, sea) = (CString::new("town").unwrap(), CString::new("cave").unwrap(), CString::new("sea").unwrap());
+    let mut view = RawCAssetView { data: std::ptr::null(), size: 0, kind: RawCAssetKind::Image };
+    assert!(residency_pin(manager, town.as_ptr()));
+    assert!(residency_get_asset(manager, cave.as_ptr(), &mut view));
+    assert_eq!(unsafe { std::slice::from_raw_parts(view.data as *const u8, view.size) }, b"cave");
+    assert_eq!(view.kind, RawCAssetKind::Other);
+    assert!(residency_get_asset(manager, sea.as_ptr(), &mut view)); // Evicts `cave`, Not The Pinned `town`
+    assert_eq!(residency_resident_bytes(manager), 7);
+
+    residency_set_budget(manager, 2);
+    assert_eq!(residency_resident_bytes(manager), 4);
+    assert!(residency_unpin(manager, town.as_ptr()));
+    residency_set_budget(manager, 2);
+    assert_eq!(residency_resident_bytes(manager), 0);
+    assert!(!residency_get_asset(manager, town.as_ptr(), &mut view)); // Doesn't Fit
+
+    destroy_residency_manager(manager);
+    let _ = std::fs::remove_dir_all(directory);
+}
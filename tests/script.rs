@@ -0,0 +1,67 @@
+mod common;
+
+use assetchunk::{check_lua, compile_scripts, scan_lua, scan_script_dependencies, Asset, AssetType, BytecodeMode, ScriptConfig};
+use common::fixture;
+
+fn script(name:&str, source:&str) -> Asset {
+    Asset::from_data(name, AssetType::Script("LUA".to_string()), source.as_bytes().to_vec())
+}
+
+#[test]
+fn syntax_errors_name_the_file_and_line() {
+    let event_script = fixture("ctest/assets/event_script.lua").1;
+    assert!(check_lua("event_script", &event_script).is_ok());
+
+    let error = check_lua("broken", b"local a = 1\nif a then\n  print(a)\n").unwrap_err();
+    assert!(error.starts_with("broken.lua:4:"), "{}", error);
+}
+
+#[test]
+fn every_broken_script_is_reported() {
+    let mut assets = vec![script("ok", "return 1"), script("first", "local = 2"), script("second", "return )")];
+    let error = compile_scripts(&mut assets, &ScriptConfig::default()).unwrap_err();
+    assert!(error.contains("first.lua:1:") && error.contains("second.lua:1:"), "{}", error);
+}
+
+#[test]
+fn bytecode_replaces_or_sits_alongside_the_source() {
+    let mut assets = vec![script("dialogue", "return { greeting = 'hello' }")];
+    let config = ScriptConfig { bytecode: BytecodeMode::Replace, strip_debug: true, ..Default::default() };
+    compile_scripts(&mut assets, &config).unwrap();
+    assert_eq!(*assets[0].get_type(), AssetType::Script("LUAC".to_string()));
+    assert!(assets[0].get_data().as_deref().unwrap().starts_with(b"\x1bLua"));
+
+    let mut assets = vec![script("dialogue", "return { greeting = 'hello' }")];
+    let config = ScriptConfig { bytecode: BytecodeMode::Alongside, strip_debug: false, ..Default::default() };
+    assert_eq!(compile_scripts(&mut assets, &config).unwrap(), 1);
+    assert_eq!(assets.len(), 2);
+    assert_eq!(assets[0].get_metadata().get("bytecode").map(String::as_str), Some("dialogue_luac"));
+    assert_eq!(assets[1].get_metadata().get("source").map(String::as_str), Some("dialogue"));
+}
+
+#[test]
+fn requires_and_literals_are_found_outside_comments() {
+    let references = scan_lua("local common = require \"events.common\"\n-- require 'commented'\n--[[ require('long')\n]]\nlocal ui = require[[ui]]\nloader:require('not_a_require')\nshow(\"portrait.png\", [==[\nbanner]==])\n");
+    assert_eq!(references.requires, [("events.common".to_string(), 1), ("ui".to_string(), 5)]);
+    assert_eq!(references.strings, [("not_a_require".to_string(), 6), ("portrait.png".to_string(), 7), ("banner".to_string(), 7)]);
+}
+
+#[test]
+fn missing_script_references_fail_the_pack() {
+    let image = || Asset::from_data("portrait", AssetType::Image("PNG".to_string()), Vec::new());
+    let source = "local common = require('events.common')\nlocal json = require('json')\nshow('portrait.png')\nplay('door_open')\n";
+    let config = ScriptConfig { external_modules: vec!["json".to_string()], ..Default::default() };
+
+    let mut assets = vec![script("cutscene", source), script("common", "return {}"), image()];
+    assert_eq!(scan_script_dependencies(&mut assets, &config).unwrap(), 2);
+    assert_eq!(*assets[0].get_dependencies(), ["common", "portrait"]);
+
+    let mut assets = vec![script("cutscene", source), image()];
+    let error = scan_script_dependencies(&mut assets, &ScriptConfig::default()).unwrap_err();
+    assert!(error.contains("cutscene.lua:1:") && error.contains("cutscene.lua:2:"), "{}", error);
+
+    // Literals That Only Look Like Asset Files Aren't Dependencies
+    let mut assets = vec![script("save", "write('slot1.json')\nshow('portrait.png')\n"), image()];
+    assert_eq!(scan_script_dependencies(&mut assets, &ScriptConfig::default()).unwrap(), 1);
+    assert_eq!(*assets[0].get_dependencies(), ["portrait"]);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use assetchunk::{assetchunk_get_asset, assetchunk_open, assetchunk_release, assetchunk_retain, Asset, AssetManifest, AssetType, RawCAssetKind, RawCAssetView, SharedChunk, SharedChunkData};
+use std::ffi::CString;
+use common::temp_directory;
+
+const ASSETS:usize = 200;
+const THREADS:usize = 16;
+const LOOKUPS:usize = 5000;
+
+/**
+ * A Chunk Where Asset `asset_N` Holds The Text `data N`
+ */
+fn numbered_chunk() -> (AssetManifest, Vec<u8>) {
+    let mut data = Vec::new();
+    let assets = (0..ASSETS).map(|index| {
+        let text = format!("data {}", index);
+        let mut asset = Asset::from_data(&format!("asset_{}", index), AssetType::Other("TXT".to_string()), text.as_bytes().to_vec());
+        asset.set_chunk_location(data.len());
+        data.extend(text.as_bytes());
+        asset
+    }).collect();
+    (AssetManifest::new(assets), data)
+}
+
+#[test]
+fn handles_are_shared_across_threads() {
+    fn assert_send_sync<T:Send + Sync>() {}
+    assert_send_sync::<SharedChunk>();
+
+    let (manifest, data) = numbered_chunk();
+    let chunk = SharedChunk::new(manifest, data).unwrap();
+    let threads:Vec<_> = (0..THREADS).map(|thread| {
+        let chunk = chunk.clone();
+        std::thread::spawn(move || {
+            for lookup in 0..LOOKUPS {
+                let index = (thread * 31 + lookup * 7) % ASSETS;
+                assert_eq!(chunk.get_asset_data(&format!("asset_{}", index)).unwrap(), format!("data {}", index).as_bytes());
+            }
+            assert!(chunk.get_asset("missing").is_none());
+        })
+    }).collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(chunk.handle_count(), 1);
+
+    let (manifest, _) = numbered_chunk();
+    assert!(SharedChunk::new(manifest, b"short".to_vec()).is_err());
+}
+
+#[test]
+fn c_handles_are_retained_and_released_across_threads() {
+    let directory = temp_directory("shared");
+    let (manifest, data) = numbered_chunk();
+    std::fs::write(directory.join("game.chunk.asset"), data).unwrap();
+    std::fs::write(directory.join("game.manifest.asset"), serde_json::to_string(&manifest).unwrap()).unwrap();
+    let chunk_path = CString::new(directory.join("game.chunk.asset").to_str().unwrap()).unwrap();
+    let manifest_path = CString::new(directory.join("game.manifest.asset").to_str().unwrap()).unwrap();
+
+    let chunk = assetchunk_open(chunk_path.as_ptr(), manifest_path.as_ptr());
+    assert!(!chunk.is_null());
+    let threads:Vec<_> = (0..THREADS).map(|thread| {
+        let handle = assetchunk_retain(chunk) as usize; // Each Thread Owns A Reference
+        std::thread::spawn(move || {
+            let chunk = handle as *const SharedChunkData;
+            let mut view = RawCAssetView { data: std::ptr::null(), size: 0, kind: RawCAssetKind::Image };
+            for lookup in 0..LOOKUPS {
+                let index = (thread * 31 + lookup * 7) % ASSETS;
+                let name = CString::new(format!("asset_{}", index)).unwrap();
+                assert!(assetchunk_get_asset(chunk, name.as_ptr(), &mut view));
+                assert_eq!(view.kind, RawCAssetKind::Other);
+                assert_eq!(unsafe { std::slice::from_raw_parts(view.data as *const u8, view.size) }, format!("data {}", index).as_bytes());
+            }
+            let name = CString::new("missing").unwrap();
+            assert!(!assetchunk_get_asset(chunk, name.as_ptr(), &mut view));
+            assetchunk_release(chunk);
+        })
+    }).collect();
+    assetchunk_release(chunk); // The Threads Keep It Alive
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let missing = CString::new(directory.join("missing.chunk.asset").to_str().unwrap()).unwrap();
+    assert!(assetchunk_open(missing.as_ptr(), manifest_path.as_ptr()).is_null());
+    let _ = std::fs::remove_dir_all(directory);
+}
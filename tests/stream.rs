@@ -0,0 +1,58 @@
+mod common;
+
+use assetchunk::{close_asset_stream, open_asset_stream_file, Asset, AssetManifest, AssetStream, AssetType, ASSET_SEEK_END, ASSET_SEEK_SET};
+use std::ffi::{c_void, CString};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use common::temp_directory;
+
+#[test]
+fn stream_is_confined_to_the_asset() {
+    let chunk:Vec<u8> = (0..100).collect();
+    let mut stream = AssetStream::new(Cursor::new(chunk), 10, 20);
+
+    let mut buffer = [0; 8];
+    assert_eq!(stream.read(&mut buffer).unwrap(), 8);
+    assert_eq!(buffer, [10, 11, 12, 13, 14, 15, 16, 17]);
+
+    assert_eq!(stream.seek(SeekFrom::End(-4)).unwrap(), 16);
+    let mut rest = Vec::new();
+    stream.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, [26, 27, 28, 29]);
+
+    assert!(stream.seek(SeekFrom::Current(-21)).is_err());
+    assert_eq!(stream.seek(SeekFrom::Start(2)).unwrap(), 2);
+    assert_eq!(stream.read(&mut buffer[..1]).unwrap(), 1);
+    assert_eq!(buffer[0], 12);
+}
+
+#[test]
+fn c_stream_reads_from_the_chunk_file() {
+    let directory = temp_directory("stream");
+    let chunk_path = directory.join("music.chunk.asset");
+    let manifest_path = directory.join("music.manifest.asset");
+
+    let mut intro = Asset::from_data("intro", AssetType::Audio("OGG".to_string()), b"intro".to_vec());
+    let mut theme = Asset::from_data("theme", AssetType::Audio("OGG".to_string()), b"main theme".to_vec());
+    intro.set_chunk_location(0);
+    theme.set_chunk_location(5);
+    std::fs::write(&chunk_path, b"intromain theme").unwrap();
+    AssetManifest::new(vec![intro, theme]).write(manifest_path.to_str().unwrap());
+
+    let chunk_path = CString::new(chunk_path.to_str().unwrap()).unwrap();
+    let manifest_path = CString::new(manifest_path.to_str().unwrap()).unwrap();
+    let name = CString::new("theme").unwrap();
+    let stream = open_asset_stream_file(chunk_path.as_ptr(), manifest_path.as_ptr(), name.as_ptr());
+    assert!(!stream.is_null());
+
+    let handle = unsafe { &*stream };
+    assert_eq!(handle.size, 10);
+    let mut buffer = [0u8; 16];
+    assert_eq!((handle.seek)(stream, 5, ASSET_SEEK_SET), 0);
+    assert_eq!((handle.read)(stream, buffer.as_mut_ptr() as *mut c_void, buffer.len()), 5);
+    assert_eq!(&buffer[..5], b"theme");
+    assert_eq!((handle.tell)(stream), 10);
+    assert_eq!((handle.seek)(stream, -11, ASSET_SEEK_END), -1);
+
+    close_asset_stream(stream);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,127 @@
+use assetchunk::{free_asset_manifest, free_tilemap, get_tilemap, identify, import_tilemaps, Asset, AssetManifest, AssetType, RawCAssetChunk, RawCAssetManifest, Tilemap};
+use std::ffi::{CStr, CString};
+
+const TMX:&str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" width="2" height="2" tilewidth="16" tileheight="16" infinite="0">
+ <properties>
+  <property name="music" value="overworld"/>
+ </properties>
+ <tileset firstgid="1" source="../tilesets/terrain.tsx"/>
+ <tileset firstgid="65" name="water" tilewidth="16" tileheight="16" tilecount="16" columns="4">
+  <image source="../test/Tileset_Water_All.png" width="64" height="64"/>
+ </tileset>
+ <layer id="1" name="ground" width="2" height="2">
+  <data encoding="csv">
+1,2,
+65,66
+</data>
+ </layer>
+ <group name="details">
+  <layer id="2" name="decor" width="2" height="2">
+   <data encoding="base64" compression="zlib">eJxjYGBgYAJiZiBmZGBoAAAAxACH</data>
+  </layer>
+ </group>
+ <objectgroup id="3" name="spawns">
+  <object id="1" name="player" type="Spawn" x="8" y="24.5">
+   <properties>
+    <property name="facing" value="left"/>
+   </properties>
+  </object>
+ </objectgroup>
+</map>
+"#;
+
+const TSX:&str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tileset version="1.10" name="terrain" tilewidth="16" tileheight="16" tilecount="64" columns="8">
+ <image source="../test/Tileset_Terrain1.png" width="128" height="128"/>
+</tileset>
+"#;
+
+const TMJ:&str = r#"{
+ "width": 2, "height": 1, "tilewidth": 16, "tileheight": 16, "infinite": false,
+ "tilesets": [{ "firstgid": 1, "name": "terrain", "image": "Tileset_Terrain1.png", "tilewidth": 16, "tileheight": 16, "tilecount": 64, "columns": 8 }],
+ "layers": [
+  { "type": "tilelayer", "name": "ground", "width": 2, "height": 1, "data": "AQAAAAIAAAA=", "encoding": "base64" },
+  { "type": "objectgroup", "name": "triggers", "objects": [{ "id": 4, "name": "door", "class": "Door", "x": 0, "y": 0, "width": 16, "height": 32,
+    "properties": [{ "name": "target", "type": "string", "value": "house" }, { "name": "locked", "type": "bool", "value": true }] }] }
+ ]
+}"#;
+
+fn image(name:&str) -> Asset {
+    Asset::from_data(name, AssetType::Image("PNG".to_string()), Vec::new())
+}
+
+fn typed(name:&str, file_name:&str, text:&str) -> Asset {
+    let asset_type = identify(Some(file_name), text.as_bytes()).asset_type;
+    Asset::from_data(name, asset_type, text.as_bytes().to_vec())
+}
+
+#[test]
+fn tmx_is_packed_and_read_back() {
+    let mut assets = vec![
+        typed("forest", "forest.tmx", TMX),
+        typed("terrain", "terrain.tsx", TSX),
+        image("Tileset_Terrain1"),
+        image("Tileset_Water_All"),
+    ];
+    assert_eq!(assets[0].get_type().kind_name(), "Tilemap");
+    assert_eq!(assets[1].get_type().kind_name(), "Tileset");
+    assert_eq!(import_tilemaps(&mut assets).unwrap(), 1);
+    assert_eq!(assets[0].get_type().format(), "BIN");
+
+    let map = Tilemap::from_bytes(assets[0].get_data().as_deref().unwrap()).unwrap();
+    assert_eq!((map.width, map.height, map.tile_width), (2, 2, 16));
+    assert_eq!(map.properties["music"], "overworld");
+    assert_eq!(map.tilesets[0].image, "Tileset_Terrain1");
+    assert_eq!(map.tilesets[1].first_gid, 65);
+    assert_eq!(map.get_layer("ground").unwrap().tiles, [1, 2, 65, 66]);
+    assert_eq!(map.get_layer("decor").unwrap().tiles, [0, 2, 3, 0x8000_0001]);
+    assert_eq!(map.tileset_for(66).unwrap().name, "water");
+    assert_eq!(map.tileset_for(0x8000_0001).unwrap().name, "terrain");
+
+    let player = &map.get_object_layer("spawns").unwrap().objects[0];
+    assert_eq!((player.class.as_str(), player.y), ("Spawn", 24.5));
+    assert_eq!(player.properties["facing"], "left");
+}
+
+#[test]
+fn tmj_is_packed_and_read_back() {
+    let mut assets = vec![typed("house", "house.tmj", TMJ), image("Tileset_Terrain1")];
+    import_tilemaps(&mut assets).unwrap();
+
+    let map = Tilemap::from_bytes(assets[0].get_data().as_deref().unwrap()).unwrap();
+    assert_eq!(map.get_layer("ground").unwrap().tiles, [1, 2]);
+    let door = &map.get_object_layer("triggers").unwrap().objects[0];
+    assert_eq!((door.id, door.class.as_str(), door.height), (4, "Door", 32.0));
+    assert_eq!(door.properties["locked"], "true");
+    assert_eq!(Tilemap::from_bytes(&map.to_bytes()).unwrap(), map);
+
+    let mut data = assets[0].get_data().clone().unwrap();
+    let chunk = RawCAssetChunk { data: data.as_mut_ptr() as *mut _, size: data.len(), manifest: Box::into_raw(Box::new(RawCAssetManifest::from_manifest(&AssetManifest::new(vec![assets[0].clone()])))) };
+    let name = CString::new("house").unwrap();
+    let tilemap = get_tilemap(&chunk, name.as_ptr());
+    let layers = unsafe { std::slice::from_raw_parts((*tilemap).layers, (*tilemap).layer_count) };
+    assert_eq!(unsafe { std::slice::from_raw_parts(layers[0].tiles, 2) }, [1, 2]);
+    assert_eq!(unsafe { CStr::from_ptr((*(*tilemap).tilesets).image) }.to_str().unwrap(), "Tileset_Terrain1");
+    free_tilemap(tilemap);
+    free_asset_manifest(chunk.manifest);
+}
+
+#[test]
+fn missing_tilesets_fail_the_import() {
+    let mut assets = vec![typed("forest", "forest.tmx", TMX), typed("terrain", "terrain.tsx", TSX), image("Tileset_Terrain1")];
+    let error = import_tilemaps(&mut assets).unwrap_err();
+    assert!(error.contains("Tileset_Water_All"), "{}", error);
+
+    let mut assets = vec![typed("forest", "forest.tmx", TMX), image("Tileset_Terrain1"), image("Tileset_Water_All")];
+    let error = import_tilemaps(&mut assets).unwrap_err();
+    assert!(error.contains("terrain.tsx"), "{}", error);
+}
+
+#[test]
+fn image_collection_tilesets_are_rejected() {
+    let tmj = TMJ.replace(r#""image": "Tileset_Terrain1.png", "#, "");
+    let mut assets = vec![typed("house", "house.tmj", &tmj), image("Tileset_Terrain1")];
+    let error = import_tilemaps(&mut assets).unwrap_err();
+    assert!(error.contains("Image Collection"), "{}", error);
+}
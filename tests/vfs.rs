@@ -0,0 +1,77 @@
+mod common;
+
+use assetchunk::{create_asset_vfs, destroy_asset_vfs, vfs_lookup, vfs_mount, vfs_unmount, Asset, AssetManifest, AssetType, AssetVfs, RawCAssetKind, RawCAssetView};
+use std::ffi::CString;
+use common::temp_directory;
+
+/**
+ * A Manifest And Chunk Holding Text Assets Back To Back
+ */
+fn chunk(assets:&[(&str, &str)]) -> (AssetManifest, Vec<u8>) {
+    let mut data = Vec::new();
+    let assets = assets.iter().map(|(name, text)| {
+        let mut asset = Asset::from_data(name, AssetType::Other("TXT".to_string()), text.as_bytes().to_vec());
+        asset.set_chunk_location(data.len());
+        data.extend(text.as_bytes());
+        asset
+    }).collect();
+    (AssetManifest::new(assets), data)
+}
+
+#[test]
+fn highest_priority_mount_wins() {
+    let mut vfs = AssetVfs::new();
+    let (manifest, data) = chunk(&[("hero", "base hero"), ("title", "base title")]);
+    let base = vfs.mount_data("base", manifest, data, 0).unwrap();
+    let (manifest, data) = chunk(&[("hero", "dlc hero"), ("castle", "dlc castle")]);
+    vfs.mount_data("dlc", manifest, data, 10).unwrap();
+    let (manifest, data) = chunk(&[("hero", "patched hero")]);
+    let patch = vfs.mount_data("patch", manifest, data, 10).unwrap(); // Same Priority, Mounted Later
+
+    assert_eq!(vfs.lookup("hero").unwrap().data, b"patched hero");
+    assert_eq!(vfs.lookup("title").unwrap().mount.name, "base");
+    assert_eq!(vfs.asset_names(), ["castle", "hero", "title"]);
+
+    assert!(vfs.unmount(patch));
+    assert!(!vfs.unmount(patch));
+    assert_eq!(vfs.lookup("hero").unwrap().data, b"dlc hero");
+    vfs.unmount(base);
+    assert!(vfs.lookup("title").is_none());
+
+    let (manifest, _) = chunk(&[("hero", "hero")]);
+    assert!(vfs.mount_data("truncated", manifest, b"he".to_vec(), 0).is_err());
+}
+
+#[test]
+fn c_vfs_mounts_files() {
+    let directory = temp_directory("vfs");
+    let mut paths = Vec::new();
+    for (name, assets) in [("base", &[("hero", "base hero"), ("title", "base title")][..]), ("mod", &[("hero", "modded hero")][..])] {
+        let (manifest, data) = chunk(assets);
+        let chunk_path = directory.join(format!("{}.chunk.asset", name));
+        let manifest_path = directory.join(format!("{}.manifest.asset", name));
+        std::fs::write(&chunk_path, data).unwrap();
+        manifest.write(manifest_path.to_str().unwrap());
+        paths.push((CString::new(chunk_path.to_str().unwrap()).unwrap(), CString::new(manifest_path.to_str().unwrap()).unwrap()));
+    }
+
+    let vfs = create_asset_vfs();
+    assert!(vfs_mount(vfs, paths[0].0.as_ptr(), paths[0].1.as_ptr(), 0) >= 0);
+    let modded = vfs_mount(vfs, paths[1].0.as_ptr(), paths[1].1.as_ptr(), 5);
+    let missing = CString::new(directory.join("missing.chunk.asset").to_str().unwrap()).unwrap();
+    assert_eq!(vfs_mount(vfs, missing.as_ptr(), paths[0].1.as_ptr(), 0), -1);
+
+    let hero = CString::new("hero").unwrap();
+    let mut view = RawCAssetView { data: std::ptr::null(), size: 0, kind: RawCAssetKind::Image };
+    let read = |view:&RawCAssetView| unsafe { std::slice::from_raw_parts(view.data as *const u8, view.size).to_vec() };
+    assert!(vfs_lookup(vfs, hero.as_ptr(), &mut view));
+    assert_eq!(read(&view), b"modded hero");
+    assert!(!vfs_unmount(vfs, modded + (1 << 32))); // Not Truncated To `modded`
+    assert!(vfs_unmount(vfs, modded));
+    assert!(vfs_lookup(vfs, hero.as_ptr(), &mut view));
+    assert_eq!(read(&view), b"base hero");
+    assert!(!vfs_lookup(vfs, CString::new("castle").unwrap().as_ptr(), &mut view));
+
+    destroy_asset_vfs(vfs);
+    std::fs::remove_dir_all(&directory).unwrap();
+}